@@ -28,11 +28,11 @@ pub fn mutate_example() {
         .vertices(Vertex::person())
         .mutate(|graph, vertex_id, _| {
             // Note: updating requires cloning and replacing due to Rust's ownership model
-            if let Some(mut vertex) = graph.vertex_mut(vertex_id) {
-                if let Some(mut person) = vertex.project_mut::<PersonMut<_, _>>() {
-                    // Increment the person age
-                    person.set_age(person.age() + 1);
-                }
+            if let Some(mut vertex) = graph.vertex_mut(vertex_id)
+                && let Some(mut person) = vertex.project_mut::<PersonMut<_, _>>()
+            {
+                // Increment the person age
+                person.set_age(person.age() + 1);
             }
         });
 
@@ -59,10 +59,10 @@ pub fn mutate_example() {
             graph.add_edge(person_id, new_project_id, Edge::Created);
 
             // Get person name for logging
-            if let Some(vertex) = graph.vertex(person_id) {
-                if let Some(person) = vertex.project::<Person<_>>() {
-                    println!("  Added edge: {} -> GraphDB", person.name());
-                }
+            if let Some(vertex) = graph.vertex(person_id)
+                && let Some(person) = vertex.project::<Person<_>>()
+            {
+                println!("  Added edge: {} -> GraphDB", person.name());
             }
         });
 
@@ -0,0 +1,95 @@
+use graph_api_lib::Direction;
+pub(crate) use graph_api_lib::query::Literal;
+
+/// A parsed query, with labels and properties still referred to by name.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Query {
+    pub(crate) paths: Vec<Path>,
+    pub(crate) condition: Option<Expression>,
+    pub(crate) items: Vec<ReturnItem>,
+    pub(crate) order: Vec<OrderItem>,
+    pub(crate) skip: Option<usize>,
+    pub(crate) limit: Option<usize>,
+}
+
+/// A chain of nodes joined by relationships, such as `(a:Person)-[:Knows]->(b)`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Path {
+    pub(crate) start: NodePattern,
+    pub(crate) hops: Vec<(RelationshipPattern, NodePattern)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NodePattern {
+    pub(crate) variable: Option<String>,
+    pub(crate) label: Option<String>,
+    pub(crate) properties: Vec<(String, Literal)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RelationshipPattern {
+    pub(crate) variable: Option<String>,
+    pub(crate) label: Option<String>,
+    pub(crate) properties: Vec<(String, Literal)>,
+    pub(crate) direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    /// The comparison with its operands swapped, so that `1 < a.age` becomes `a.age > 1`.
+    pub(crate) fn flip(self) -> Self {
+        match self {
+            Comparison::Lt => Comparison::Gt,
+            Comparison::Le => Comparison::Ge,
+            Comparison::Gt => Comparison::Lt,
+            Comparison::Ge => Comparison::Le,
+            comparison => comparison,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Operand {
+    Literal(Literal),
+    /// `variable.property`
+    Property(String, String),
+    /// `labels(variable)` or `type(variable)`
+    Label(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expression {
+    Compare(Operand, Comparison, Operand),
+    Operand(Operand),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ReturnExpression {
+    Operand(Operand),
+    /// `count(*)` or `count(variable)`
+    Count,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ReturnItem {
+    pub(crate) expression: ReturnExpression,
+    pub(crate) alias: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OrderItem {
+    pub(crate) operand: Operand,
+    pub(crate) descending: bool,
+}
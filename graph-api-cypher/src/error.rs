@@ -0,0 +1,33 @@
+use graph_api_lib::query::QueryError;
+use thiserror::Error;
+
+/// Errors that can occur when parsing or executing a query.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The query is not valid Cypher.
+    #[error("syntax error at position {position}: {message}")]
+    Syntax { position: usize, message: String },
+
+    /// A node or relationship label that the graph does not have.
+    #[error("unknown label {0}")]
+    UnknownLabel(String),
+
+    /// A variable that is not introduced by the MATCH clause.
+    #[error("unknown variable {0}")]
+    UnknownVariable(String),
+
+    /// Valid Cypher that is outside of the supported subset.
+    #[error("{0} is not supported")]
+    Unsupported(String),
+}
+
+impl From<QueryError> for Error {
+    fn from(error: QueryError) -> Self {
+        match error {
+            QueryError::UnknownLabel(label) => Error::UnknownLabel(label),
+            QueryError::UnknownVariable(variable) => Error::UnknownVariable(variable),
+            error => Error::Unsupported(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Read-only [openCypher](https://opencypher.org/) queries for any [`Graph`](graph_api_lib::Graph).
+//!
+//! Queries are parsed once and can then be run against any graph whose vertices and edges
+//! derive `VertexExt` and `EdgeExt`. Labels are matched by [`Label::name`](graph_api_lib::Label::name)
+//! and properties by field name, so queries can be written by people who have never seen the
+//! Rust model.
+//!
+//! ```rust
+//! use graph_api_cypher::Query;
+//! use graph_api_lib::plan::Capabilities;
+//! use graph_api_simplegraph::SimpleGraph;
+//! use graph_api_test::populate_graph;
+//!
+//! let mut graph = SimpleGraph::new();
+//! populate_graph(&mut graph);
+//!
+//! let query = Query::parse(
+//!     "MATCH (a:Person)-[k:Knows]->(b:Person) WHERE a.name = 'Bryn' RETURN b.name, k.since",
+//! )
+//! .unwrap();
+//! let rows = query.execute(&graph, &Capabilities::all()).unwrap();
+//! assert_eq!(rows.columns(), vec!["b.name", "k.since"]);
+//! for row in &rows {
+//!     println!("{:?}", row);
+//! }
+//! ```
+//!
+//! The supported subset is:
+//! * `MATCH` with one or more comma separated patterns of nodes and directed relationships,
+//!   each with an optional variable, label and inline properties. Every pattern after the first
+//!   must start from the node the previous one ended at.
+//! * `WHERE` with comparisons, `AND`, `OR` and `NOT`.
+//! * `RETURN` of properties, `labels(n)`, `type(r)` and `count(*)`, with optional `AS` aliases.
+//! * `ORDER BY`, `SKIP` and `LIMIT`.
+//!
+//! Anything else is rejected with [`Error::Unsupported`].
+
+mod ast;
+mod error;
+mod lexer;
+mod parser;
+mod query;
+
+pub use error::Error;
+pub use query::{Query, Rows};
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, Query};
+    use graph_api_lib::plan::Capabilities;
+    use graph_api_lib::{Graph, OwnedValue};
+    use graph_api_simplegraph::SimpleGraph;
+    use graph_api_test::{Edge, Vertex, populate_graph};
+    use petgraph::stable_graph::StableGraph;
+
+    fn rows<G>(graph: &G, capabilities: &Capabilities, text: &str) -> Vec<Vec<String>>
+    where
+        G: Graph,
+    {
+        let query = Query::parse(text).expect("query must parse");
+        let rows = query.execute(graph, capabilities).expect("query must run");
+        let result = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| match value {
+                        Some(OwnedValue::String(value)) => value.clone(),
+                        Some(value) => format!("{:?}", value.value()),
+                        None => "null".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result.len(), rows.len());
+        result
+    }
+
+    fn queries<G>(graph: &mut G, capabilities: &Capabilities)
+    where
+        G: Graph<Vertex = Vertex, Edge = Edge>,
+    {
+        populate_graph(graph);
+
+        // Indexed properties
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (p:Person {name: 'Bryn'})-[:Knows]->(f) RETURN f.name, f.age"
+            ),
+            vec![vec!["Julia".to_string(), "U64(48)".to_string()]]
+        );
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (p:Person) WHERE 46 < p.age RETURN p.username"
+            ),
+            vec![vec!["julia".to_string()]]
+        );
+
+        // Non-indexed properties
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (a)-[k:Knows]->(b) WHERE k.since = 1999 AND a.age < 46 RETURN b.name AS friend"
+            ),
+            vec![vec!["Julia".to_string()]]
+        );
+
+        // Labels, including continuing from the end of a previous pattern
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (p:Person)-[c:Created]->(project), (project)-[l]->(language) RETURN type(c), labels(language)"
+            ),
+            vec![vec!["Created".to_string(), "Rust".to_string()]]
+        );
+
+        // Ordering and paging, missing properties sort last
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (v) RETURN v.name ORDER BY v.name DESC SKIP 2 LIMIT 2"
+            ),
+            vec![vec!["Julia".to_string()], vec!["Bryn".to_string()]]
+        );
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (v) WHERE NOT v.name = 'Julia' RETURN v.age ORDER BY v.age LIMIT 2"
+            ),
+            vec![vec!["U64(45)".to_string()], vec!["null".to_string()]]
+        );
+
+        // Variables that appear twice must bind the same element
+        assert_eq!(
+            rows(
+                graph,
+                capabilities,
+                "MATCH (a:Person)-[:Knows]->(b)-[:Knows]->(a) RETURN count(*)"
+            ),
+            vec![vec!["USize(2)".to_string()]]
+        );
+    }
+
+    #[test]
+    fn simple_graph() {
+        queries(&mut SimpleGraph::new(), &Capabilities::all());
+    }
+
+    #[test]
+    fn petgraph() {
+        queries(&mut StableGraph::new(), &Capabilities::none());
+    }
+
+    #[test]
+    fn errors() {
+        let graph = SimpleGraph::<Vertex, Edge>::new();
+        let execute = |text: &str| {
+            Query::parse(text)
+                .and_then(|query| query.execute(&graph, &Capabilities::all()).map(|_| ()))
+        };
+
+        assert_eq!(
+            execute("MATCH (p:Robot) RETURN p.name"),
+            Err(Error::UnknownLabel("Robot".to_string()))
+        );
+        assert_eq!(
+            execute("MATCH (p:Person) RETURN q.name"),
+            Err(Error::UnknownVariable("q".to_string()))
+        );
+        assert!(matches!(
+            execute("MATCH (a)-[:Knows]-(b) RETURN a.name"),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            execute("MATCH (a)-[:Knows]->(b), (c)-[:Knows]->(d) RETURN a.name"),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            execute("MATCH (a) RETURN a.name, count(*)"),
+            Err(Error::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,476 @@
+use crate::Error;
+use crate::ast::{
+    Comparison, Expression, Literal, NodePattern, Operand, OrderItem, Path, Query,
+    RelationshipPattern, ReturnExpression, ReturnItem,
+};
+use crate::lexer::{Token, tokenize};
+use graph_api_lib::Direction;
+
+/// Parses the text of a query.
+pub(crate) fn parse(text: &str) -> Result<Query, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+        end: text.len(),
+    };
+    parser.query()
+}
+
+/// The variable, label and properties of a node or relationship pattern.
+type ElementPattern = (Option<String>, Option<String>, Vec<(String, Literal)>);
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map(|(_, offset)| *offset)
+            .unwrap_or(self.end)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, Error> {
+        Err(Error::Syntax {
+            position: self.offset(),
+            message: message.into(),
+        })
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token, description: &str) -> Result<(), Error> {
+        if self.eat(&token) {
+            Ok(())
+        } else {
+            self.error(format!("expected {}", description))
+        }
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(ident)) if ident.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.at_keyword(keyword) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            self.error(format!("expected {}", keyword))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.position += 1;
+                Ok(ident)
+            }
+            _ => self.error("expected an identifier"),
+        }
+    }
+
+    fn count(&mut self) -> Result<usize, Error> {
+        match self.peek() {
+            Some(Token::Int(value)) if *value >= 0 => {
+                let value = *value as usize;
+                self.position += 1;
+                Ok(value)
+            }
+            _ => self.error("expected a non-negative integer"),
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, Error> {
+        let mut paths = Vec::new();
+        self.expect_keyword("MATCH")?;
+        loop {
+            paths.push(self.path()?);
+            if !self.eat(&Token::Comma) && !self.eat_keyword("MATCH") {
+                break;
+            }
+        }
+
+        let condition = if self.eat_keyword("WHERE") {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        self.expect_keyword("RETURN")?;
+        if self.at_keyword("DISTINCT") {
+            return Err(Error::Unsupported("RETURN DISTINCT".to_string()));
+        }
+        let mut items = vec![self.return_item()?];
+        while self.eat(&Token::Comma) {
+            items.push(self.return_item()?);
+        }
+
+        let mut order = Vec::new();
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            loop {
+                order.push(self.order_item(&items)?);
+                if !self.eat(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+
+        let skip = if self.eat_keyword("SKIP") {
+            Some(self.count()?)
+        } else {
+            None
+        };
+        let limit = if self.eat_keyword("LIMIT") {
+            Some(self.count()?)
+        } else {
+            None
+        };
+
+        self.eat(&Token::Semicolon);
+        if self.peek().is_some() {
+            return self.error("expected end of query");
+        }
+
+        Ok(Query {
+            paths,
+            condition,
+            items,
+            order,
+            skip,
+            limit,
+        })
+    }
+
+    fn path(&mut self) -> Result<Path, Error> {
+        let start = self.node()?;
+        let mut hops = Vec::new();
+        while matches!(self.peek(), Some(Token::Minus | Token::Lt)) {
+            let relationship = self.relationship()?;
+            hops.push((relationship, self.node()?));
+        }
+        Ok(Path { start, hops })
+    }
+
+    fn node(&mut self) -> Result<NodePattern, Error> {
+        self.expect(Token::LParen, "(")?;
+        let (variable, label, properties) = self.element()?;
+        self.expect(Token::RParen, ")")?;
+        Ok(NodePattern {
+            variable,
+            label,
+            properties,
+        })
+    }
+
+    fn relationship(&mut self) -> Result<RelationshipPattern, Error> {
+        let incoming = self.eat(&Token::Lt);
+        self.expect(Token::Minus, "-")?;
+        let (variable, label, properties) = if self.eat(&Token::LBracket) {
+            if self.eat(&Token::Star) {
+                return Err(Error::Unsupported(
+                    "variable length relationships".to_string(),
+                ));
+            }
+            let element = self.element()?;
+            self.expect(Token::RBracket, "]")?;
+            element
+        } else {
+            (None, None, Vec::new())
+        };
+        self.expect(Token::Minus, "-")?;
+        let outgoing = self.eat(&Token::Gt);
+        let direction = match (incoming, outgoing) {
+            (false, true) => Direction::Outgoing,
+            (true, false) => Direction::Incoming,
+            (true, true) => return self.error("relationship can't point both ways"),
+            (false, false) => Direction::All,
+        };
+        Ok(RelationshipPattern {
+            variable,
+            label,
+            properties,
+            direction,
+        })
+    }
+
+    /// The inside of a node or relationship pattern, `variable:Label {property: literal}`.
+    fn element(&mut self) -> Result<ElementPattern, Error> {
+        let variable = match self.peek() {
+            Some(Token::Ident(_)) => Some(self.ident()?),
+            _ => None,
+        };
+        let label = if self.eat(&Token::Colon) {
+            Some(self.ident()?)
+        } else {
+            None
+        };
+        let mut properties = Vec::new();
+        if self.eat(&Token::LBrace) {
+            loop {
+                let property = self.ident()?;
+                self.expect(Token::Colon, ":")?;
+                properties.push((property, self.literal()?));
+                if !self.eat(&Token::Comma) {
+                    break;
+                }
+            }
+            self.expect(Token::RBrace, "}")?;
+        }
+        Ok((variable, label, properties))
+    }
+
+    fn literal(&mut self) -> Result<Literal, Error> {
+        let negative = self.eat(&Token::Minus);
+        let literal = match (self.peek(), negative) {
+            (Some(Token::Int(value)), _) => Literal::Int(if negative { -*value } else { *value }),
+            (Some(Token::Float(value)), _) => {
+                Literal::Float(if negative { -*value } else { *value })
+            }
+            (Some(Token::Str(value)), false) => Literal::Str(value.clone()),
+            (Some(Token::Ident(ident)), false) if ident.eq_ignore_ascii_case("true") => {
+                Literal::Bool(true)
+            }
+            (Some(Token::Ident(ident)), false) if ident.eq_ignore_ascii_case("false") => {
+                Literal::Bool(false)
+            }
+            (Some(Token::Ident(ident)), false) if ident.eq_ignore_ascii_case("null") => {
+                return Err(Error::Unsupported("null".to_string()));
+            }
+            _ => return self.error("expected a literal"),
+        };
+        self.position += 1;
+        Ok(literal)
+    }
+
+    fn expression(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.conjunction()?;
+        while self.eat_keyword("OR") {
+            expression = Expression::Or(Box::new(expression), Box::new(self.conjunction()?));
+        }
+        Ok(expression)
+    }
+
+    fn conjunction(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.negation()?;
+        while self.eat_keyword("AND") {
+            expression = Expression::And(Box::new(expression), Box::new(self.negation()?));
+        }
+        Ok(expression)
+    }
+
+    fn negation(&mut self) -> Result<Expression, Error> {
+        if self.eat_keyword("NOT") {
+            return Ok(Expression::Not(Box::new(self.negation()?)));
+        }
+        if self.eat(&Token::LParen) {
+            let expression = self.expression()?;
+            self.expect(Token::RParen, ")")?;
+            return Ok(expression);
+        }
+        let left = self.operand()?;
+        let comparison = match self.peek() {
+            Some(Token::Eq) => Comparison::Eq,
+            Some(Token::Ne) => Comparison::Ne,
+            Some(Token::Lt) => Comparison::Lt,
+            Some(Token::Le) => Comparison::Le,
+            Some(Token::Gt) => Comparison::Gt,
+            Some(Token::Ge) => Comparison::Ge,
+            _ => return Ok(Expression::Operand(left)),
+        };
+        self.position += 1;
+        Ok(Expression::Compare(left, comparison, self.operand()?))
+    }
+
+    fn operand(&mut self) -> Result<Operand, Error> {
+        match self.peek() {
+            Some(Token::Ident(ident))
+                if !["true", "false", "null"]
+                    .iter()
+                    .any(|literal| ident.eq_ignore_ascii_case(literal)) =>
+            {
+                let ident = self.ident()?;
+                if self.eat(&Token::Dot) {
+                    return Ok(Operand::Property(ident, self.ident()?));
+                }
+                if self.eat(&Token::LParen) {
+                    let function = ident;
+                    let variable = self.ident()?;
+                    self.expect(Token::RParen, ")")?;
+                    if function.eq_ignore_ascii_case("labels")
+                        || function.eq_ignore_ascii_case("type")
+                    {
+                        return Ok(Operand::Label(variable));
+                    }
+                    return Err(Error::Unsupported(format!("function {}", function)));
+                }
+                Err(Error::Unsupported(format!(
+                    "using {} as a value, use a property of it instead",
+                    ident
+                )))
+            }
+            _ => Ok(Operand::Literal(self.literal()?)),
+        }
+    }
+
+    fn return_item(&mut self) -> Result<ReturnItem, Error> {
+        let start = self.position;
+        let expression = if self.at_keyword("count")
+            && self.tokens.get(self.position + 1).map(|(token, _)| token) == Some(&Token::LParen)
+        {
+            self.position += 2;
+            if !self.eat(&Token::Star) {
+                self.ident()?;
+            }
+            self.expect(Token::RParen, ")")?;
+            ReturnExpression::Count
+        } else {
+            ReturnExpression::Operand(self.operand()?)
+        };
+        let alias = if self.eat_keyword("AS") {
+            self.ident()?
+        } else {
+            self.tokens[start..self.position]
+                .iter()
+                .map(|(token, _)| match token {
+                    Token::Ident(ident) => ident.clone(),
+                    Token::Dot => ".".to_string(),
+                    Token::LParen => "(".to_string(),
+                    Token::RParen => ")".to_string(),
+                    Token::Star => "*".to_string(),
+                    Token::Minus => "-".to_string(),
+                    Token::Int(value) => value.to_string(),
+                    Token::Float(value) => value.to_string(),
+                    Token::Str(value) => format!("'{}'", value),
+                    token => format!("{:?}", token),
+                })
+                .collect()
+        };
+        Ok(ReturnItem { expression, alias })
+    }
+
+    fn order_item(&mut self, items: &[ReturnItem]) -> Result<OrderItem, Error> {
+        let aliased = match self.peek() {
+            Some(Token::Ident(ident)) => items.iter().find(|item| item.alias == *ident),
+            _ => None,
+        };
+        let operand = match aliased {
+            Some(item) => {
+                let ReturnExpression::Operand(operand) = &item.expression else {
+                    return Err(Error::Unsupported("ordering by count".to_string()));
+                };
+                let operand = operand.clone();
+                self.position += 1;
+                operand
+            }
+            None => self.operand()?,
+        };
+        let descending = if self.eat_keyword("DESC") || self.eat_keyword("DESCENDING") {
+            true
+        } else {
+            let _ = self.eat_keyword("ASC") || self.eat_keyword("ASCENDING");
+            false
+        };
+        Ok(OrderItem {
+            operand,
+            descending,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use crate::ast::{Comparison, Expression, Literal, Operand, ReturnExpression};
+    use crate::parser::parse;
+    use graph_api_lib::Direction;
+
+    #[test]
+    fn parse_query() {
+        let query = parse(
+            "MATCH (a:Person {name: 'Bryn'})-[k:Knows]->(b), (b)<-[:Created]-(p) \
+             WHERE b.age >= 40 AND NOT p.name = \"x\" \
+             RETURN b.name AS name, labels(p), count(*) ORDER BY name DESC SKIP 1 LIMIT 10;",
+        )
+        .expect("query must parse");
+
+        assert_eq!(query.paths.len(), 2);
+        let path = &query.paths[0];
+        assert_eq!(path.start.variable.as_deref(), Some("a"));
+        assert_eq!(path.start.label.as_deref(), Some("Person"));
+        assert_eq!(
+            path.start.properties,
+            vec![("name".to_string(), Literal::Str("Bryn".to_string()))]
+        );
+        assert_eq!(path.hops[0].0.variable.as_deref(), Some("k"));
+        assert_eq!(path.hops[0].0.direction, Direction::Outgoing);
+        assert_eq!(query.paths[1].hops[0].0.direction, Direction::Incoming);
+
+        let Some(Expression::And(left, _)) = &query.condition else {
+            panic!("expected a conjunction");
+        };
+        assert_eq!(
+            **left,
+            Expression::Compare(
+                Operand::Property("b".to_string(), "age".to_string()),
+                Comparison::Ge,
+                Operand::Literal(Literal::Int(40))
+            )
+        );
+
+        assert_eq!(query.items[0].alias, "name");
+        assert_eq!(query.items[1].alias, "labels(p)");
+        assert_eq!(query.items[2].expression, ReturnExpression::Count);
+        assert_eq!(
+            query.order[0].operand,
+            Operand::Property("b".to_string(), "name".to_string())
+        );
+        assert!(query.order[0].descending);
+        assert_eq!(query.skip, Some(1));
+        assert_eq!(query.limit, Some(10));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            parse("MATCH (a RETURN a.name"),
+            Err(Error::Syntax { position: 9, .. })
+        ));
+        assert!(matches!(
+            parse("MATCH (a)-[*]->(b) RETURN a.name"),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            parse("MATCH (a) RETURN a"),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            parse("MATCH (a) RETURN a.name 'extra'"),
+            Err(Error::Syntax { .. })
+        ));
+    }
+}
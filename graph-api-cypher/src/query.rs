@@ -0,0 +1,378 @@
+use crate::Error;
+use crate::ast::{self, Comparison, Expression, Literal, NodePattern, ReturnExpression};
+use crate::parser::parse;
+use graph_api_lib::plan::Capabilities;
+use graph_api_lib::query::{self, Condition, Operand, Order, Output, Predicate, Step, Terminal};
+use graph_api_lib::{Direction, Graph, OwnedValue, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A parsed query that can be executed against any graph.
+///
+/// Queries are compiled to a [`query::Query`] when they are parsed. Labels are matched against
+/// [`Label::name`](graph_api_lib::Label::name) and properties are read with
+/// [`Element::read`](graph_api_lib::Element::read), so the same query can run on any graph
+/// whose elements derive `VertexExt` and `EdgeExt`.
+#[derive(Debug, Clone)]
+pub struct Query {
+    query: query::Query,
+    columns: Vec<String>,
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Query::parse(text)
+    }
+}
+
+impl Query {
+    /// Parses a query.
+    ///
+    /// Fails if the query isn't in the supported subset or refers to variables that are not
+    /// bound by the `MATCH` clause.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        parse(text).and_then(|query| compile(&query))
+    }
+
+    /// Runs the query against a graph.
+    ///
+    /// The capabilities describe the searches the graph supports. Label and property
+    /// constraints on the first node of the pattern are turned into a label or index lookup
+    /// when the graph supports it, otherwise the graph is scanned.
+    ///
+    /// Fails if the query refers to labels the graph doesn't have.
+    pub fn execute<G>(&self, graph: &G, capabilities: &Capabilities) -> Result<Rows, Error>
+    where
+        G: Graph,
+    {
+        let rows = match self.query.execute(graph, capabilities)? {
+            Output::Rows(rows) => {
+                let mut owned = Vec::with_capacity(rows.len());
+                rows.for_each(|row| {
+                    owned.push(
+                        row.iter()
+                            .cloned()
+                            .map(|value| value.map(OwnedValue::from))
+                            .collect(),
+                    )
+                });
+                owned
+            }
+            Output::Count(count) => vec![vec![Some(OwnedValue::from(Value::USize(count)))]],
+            Output::Vertices(_) | Output::Edges(_) => {
+                unreachable!("queries are compiled to return rows or a count")
+            }
+        };
+        Ok(Rows {
+            columns: self.columns.clone(),
+            rows,
+        })
+    }
+}
+
+/// The rows returned by a query.
+///
+/// Values are copied out of the graph, so rows can be kept after the graph is changed. A
+/// value is `None` if the property is missing, which Cypher calls `null`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rows {
+    columns: Vec<String>,
+    rows: Vec<Vec<Option<OwnedValue>>>,
+}
+
+impl Rows {
+    /// The names of the columns, from the `RETURN` clause.
+    pub fn columns(&self) -> Vec<&str> {
+        self.columns.iter().map(String::as_str).collect()
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Iterates over the values of each row, in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Vec<Option<OwnedValue>>> {
+        self.rows.iter()
+    }
+}
+
+impl IntoIterator for Rows {
+    type Item = Vec<Option<OwnedValue>>;
+    type IntoIter = std::vec::IntoIter<Vec<Option<OwnedValue>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Rows {
+    type Item = &'a Vec<Option<OwnedValue>>;
+    type IntoIter = std::slice::Iter<'a, Vec<Option<OwnedValue>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+/// The variables bound by the `MATCH` clause, and whether each is a node.
+#[derive(Default)]
+struct Scope<'q> {
+    variables: HashMap<&'q str, bool>,
+}
+
+impl<'q> Scope<'q> {
+    /// Names the element the pattern is on. A variable can't be both a node and a
+    /// relationship.
+    fn bind(
+        &mut self,
+        variable: Option<&'q str>,
+        node: bool,
+        steps: &mut Vec<Step>,
+    ) -> Result<(), Error> {
+        let Some(variable) = variable else {
+            return Ok(());
+        };
+        match self.variables.get(variable) {
+            Some(true) if !node => Err(Error::Unsupported(format!(
+                "using node {} as a relationship",
+                variable
+            ))),
+            Some(false) if node => Err(Error::Unsupported(format!(
+                "using relationship {} as a node",
+                variable
+            ))),
+            _ => {
+                self.variables.insert(variable, node);
+                steps.push(Step::As(variable.to_string()));
+                Ok(())
+            }
+        }
+    }
+
+    fn node(&mut self, node: &'q NodePattern, steps: &mut Vec<Step>) -> Result<(), Error> {
+        steps.extend(node.label.clone().map(Step::FilterLabel));
+        steps.extend(equals(&node.properties));
+        self.bind(node.variable.as_deref(), true, steps)
+    }
+
+    fn variable(&self, variable: &str) -> Result<String, Error> {
+        match self.variables.contains_key(variable) {
+            true => Ok(variable.to_string()),
+            false => Err(Error::UnknownVariable(variable.to_string())),
+        }
+    }
+
+    fn operand(&self, operand: &ast::Operand) -> Result<Operand, Error> {
+        Ok(match operand {
+            ast::Operand::Literal(literal) => Operand::Literal(literal.clone()),
+            ast::Operand::Property(variable, property) => Operand::Property {
+                variable: self.variable(variable)?,
+                property: property.clone(),
+            },
+            ast::Operand::Label(variable) => Operand::Label(self.variable(variable)?),
+        })
+    }
+
+    fn condition(&self, expression: &Expression) -> Result<Condition, Error> {
+        Ok(match expression {
+            Expression::Compare(left, comparison, right) => Condition::Compare(
+                self.operand(left)?,
+                query_comparison(*comparison),
+                self.operand(right)?,
+            ),
+            Expression::Operand(operand) => Condition::Is(self.operand(operand)?),
+            Expression::And(left, right) => {
+                Condition::And(vec![self.condition(left)?, self.condition(right)?])
+            }
+            Expression::Or(left, right) => {
+                Condition::Or(vec![self.condition(left)?, self.condition(right)?])
+            }
+            Expression::Not(condition) => Condition::Not(Box::new(self.condition(condition)?)),
+        })
+    }
+}
+
+fn query_comparison(comparison: Comparison) -> query::Comparison {
+    match comparison {
+        Comparison::Eq => query::Comparison::Eq,
+        Comparison::Ne => query::Comparison::Ne,
+        Comparison::Lt => query::Comparison::Lt,
+        Comparison::Le => query::Comparison::Le,
+        Comparison::Gt => query::Comparison::Gt,
+        Comparison::Ge => query::Comparison::Ge,
+    }
+}
+
+fn predicate(comparison: Comparison, literal: Literal) -> Predicate {
+    match comparison {
+        Comparison::Eq => Predicate::Eq(literal),
+        Comparison::Ne => Predicate::Ne(literal),
+        Comparison::Lt => Predicate::Lt(literal),
+        Comparison::Le => Predicate::Le(literal),
+        Comparison::Gt => Predicate::Gt(literal),
+        Comparison::Ge => Predicate::Ge(literal),
+    }
+}
+
+/// Inline properties, which must equal their values.
+fn equals(properties: &[(String, Literal)]) -> impl Iterator<Item = Step> + '_ {
+    properties.iter().map(|(property, literal)| Step::Filter {
+        property: property.clone(),
+        predicate: Predicate::Eq(literal.clone()),
+    })
+}
+
+fn conjuncts<'a>(expression: &'a Expression, found: &mut Vec<&'a Expression>) {
+    match expression {
+        Expression::And(left, right) => {
+            conjuncts(left, found);
+            conjuncts(right, found);
+        }
+        expression => found.push(expression),
+    }
+}
+
+/// A comparison of a property of `variable` with a literal, as a filter on that element.
+fn filter(expression: &Expression, variable: &str) -> Option<Step> {
+    let (name, property, comparison, literal) = match expression {
+        Expression::Compare(
+            ast::Operand::Property(name, property),
+            comparison,
+            ast::Operand::Literal(literal),
+        ) => (name, property, *comparison, literal),
+        Expression::Compare(
+            ast::Operand::Literal(literal),
+            comparison,
+            ast::Operand::Property(name, property),
+        ) => (name, property, comparison.flip(), literal),
+        _ => return None,
+    };
+    (name == variable).then(|| Step::Filter {
+        property: property.clone(),
+        predicate: predicate(comparison, literal.clone()),
+    })
+}
+
+/// Compiles a parsed query. The patterns become a path with an `As` step for each variable,
+/// the `WHERE` clause a condition at the end of the path, and the `RETURN` clause the columns.
+fn compile(query: &ast::Query) -> Result<Query, Error> {
+    let mut scope = Scope::default();
+    let mut steps = Vec::new();
+
+    // Comparisons of the first node with a literal are checked right after it, where they
+    // can become a lookup
+    let mut condition = Vec::new();
+    if let Some(expression) = &query.condition {
+        conjuncts(expression, &mut condition);
+    }
+    let mut start_filters = Vec::new();
+    if let Some(variable) = query
+        .paths
+        .first()
+        .and_then(|path| path.start.variable.as_deref())
+    {
+        condition.retain(|expression| match filter(expression, variable) {
+            Some(step) => {
+                start_filters.push(step);
+                false
+            }
+            None => true,
+        });
+    }
+
+    let mut current = None;
+    for (position, path) in query.paths.iter().enumerate() {
+        if position > 0
+            && (path.start.variable.is_none() || path.start.variable.as_deref() != current)
+        {
+            return Err(Error::Unsupported(
+                "a pattern that doesn't start where the previous pattern ended".to_string(),
+            ));
+        }
+        scope.node(&path.start, &mut steps)?;
+        if position == 0 {
+            steps.append(&mut start_filters);
+        }
+        current = path.start.variable.as_deref();
+
+        for (relationship, next) in &path.hops {
+            if relationship.direction == Direction::All {
+                return Err(Error::Unsupported(
+                    "relationships without a direction".to_string(),
+                ));
+            }
+            steps.push(Step::Edges {
+                label: relationship.label.clone(),
+                direction: relationship.direction,
+            });
+            steps.extend(equals(&relationship.properties));
+            scope.bind(relationship.variable.as_deref(), false, &mut steps)?;
+            steps.push(match relationship.direction {
+                Direction::Incoming => Step::Tail,
+                _ => Step::Head,
+            });
+            scope.node(next, &mut steps)?;
+            current = next.variable.as_deref();
+        }
+    }
+
+    if !condition.is_empty() {
+        let conditions = condition
+            .into_iter()
+            .map(|expression| scope.condition(expression))
+            .collect::<Result<Vec<_>, Error>>()?;
+        steps.push(Step::Where(Condition::And(conditions)));
+    }
+
+    let order = query
+        .order
+        .iter()
+        .map(|item| {
+            Ok(Order {
+                operand: scope.operand(&item.operand)?,
+                descending: item.descending,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let mut columns = Vec::new();
+    let mut count = false;
+    for item in &query.items {
+        match &item.expression {
+            ReturnExpression::Operand(operand) => {
+                columns.push((item.alias.clone(), scope.operand(operand)?))
+            }
+            ReturnExpression::Count => count = true,
+        }
+    }
+    let terminal = match (count, columns.is_empty()) {
+        (true, true) => Terminal::Count,
+        (true, false) => {
+            return Err(Error::Unsupported(
+                "count alongside other return items".to_string(),
+            ));
+        }
+        (false, _) => Terminal::Select {
+            columns,
+            order,
+            skip: query.skip.unwrap_or(0),
+            limit: query.limit,
+        },
+    };
+
+    Ok(Query {
+        query: query::Query {
+            start: query::Start::Scan,
+            steps,
+            terminal,
+        },
+        columns: query.items.iter().map(|item| item.alias.clone()).collect(),
+    })
+}
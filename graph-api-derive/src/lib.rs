@@ -14,7 +14,7 @@ pub fn vertex_ext(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(EdgeExt, attributes(index))]
+#[proc_macro_derive(EdgeExt, attributes(index, edge))]
 pub fn edge_ext(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match model::Model::try_from(DeriveType::Edge(&input)) {
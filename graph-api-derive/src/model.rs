@@ -2,7 +2,9 @@ use case::CaseExt;
 use quote::format_ident;
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Fields, Ident, Lifetime, Type, Visibility, parse_quote};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Ident, Lifetime, Path, Type, Visibility, parse_quote,
+};
 
 #[cfg_attr(test, derive(Debug))]
 pub(crate) struct Model {
@@ -27,6 +29,19 @@ pub(crate) struct Variant {
     pub(crate) element_type: Ident,
     pub(crate) projection_module: Ident,
     pub(crate) search_ident: Ident,
+    pub(crate) endpoints: Vec<Endpoint>,
+    /// The fields of the variant as parameters, named `param0` onwards for unnamed fields.
+    pub(crate) parameters: Vec<(Ident, Type)>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct Endpoint {
+    pub(crate) from: Ident,
+    pub(crate) to: Ident,
+    /// The vertex label variant that `from` names, checked by the compiler.
+    pub(crate) from_label: Path,
+    /// The vertex label variant that `to` names, checked by the compiler.
+    pub(crate) to_label: Path,
 }
 
 #[cfg_attr(test, derive(Debug))]
@@ -139,6 +154,42 @@ impl TryFrom<DeriveType<'_>> for Model {
                             DeriveType::Vertex(_) => {format_ident!("VertexSearch")}
                             DeriveType::Edge(_) => {format_ident!("EdgeSearch")}
                         },
+                        endpoints: match value {
+                            DeriveType::Vertex(_) => vec![],
+                            DeriveType::Edge(_) => variant
+                                .attrs
+                                .iter()
+                                .filter(|attr| attr.path().is_ident("edge"))
+                                .filter_map(|attr| match parse_endpoint(attr) {
+                                    Ok(endpoint) => Some(endpoint),
+                                    Err(e) => {
+                                        errors.push(e);
+                                        None
+                                    }
+                                })
+                                .collect(),
+                        },
+                        parameters: match &variant.fields {
+                            Fields::Named(fields) => fields
+                                .named
+                                .iter()
+                                .map(|field| {
+                                    (
+                                        field.ident.clone().expect("named field has ident"),
+                                        field.ty.clone(),
+                                    )
+                                })
+                                .collect(),
+                            Fields::Unnamed(fields) => fields
+                                .unnamed
+                                .iter()
+                                .enumerate()
+                                .map(|(position, field)| {
+                                    (format_ident!("param{}", position), field.ty.clone())
+                                })
+                                .collect(),
+                            Fields::Unit => vec![],
+                        },
                         fields: match &variant.fields {
                             Fields::Named(fields) => fields
                                 .named
@@ -268,6 +319,54 @@ impl TryFrom<DeriveType<'_>> for Model {
     }
 }
 
+/// Parses `#[edge(from = .., to = ..)]`. Each side is a path to a vertex label variant, such as
+/// `VertexLabel::Person`, as the edge enum doesn't know which vertex enum it will be used with.
+fn parse_endpoint(attr: &Attribute) -> Result<Endpoint, syn::Error> {
+    let mut from = None;
+    let mut to = None;
+    attr.parse_nested_meta(|m| {
+        if m.path.is_ident("from") {
+            from = Some(m.value()?.parse::<Path>()?);
+        } else if m.path.is_ident("to") {
+            to = Some(m.value()?.parse::<Path>()?);
+        } else {
+            return Err(m.error("unknown edge attribute, expected from or to"));
+        }
+        Ok(())
+    })?;
+    match (from, to) {
+        (Some(from), Some(to)) => Ok(Endpoint {
+            from: label_name(&from)?,
+            to: label_name(&to)?,
+            from_label: label_path(from)?,
+            to_label: label_path(to)?,
+        }),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "edge endpoints must specify both from and to",
+        )),
+    }
+}
+
+/// The name of the vertex label that an endpoint refers to.
+fn label_name(path: &Path) -> Result<Ident, syn::Error> {
+    path.segments
+        .last()
+        .map(|segment| segment.ident.clone())
+        .ok_or_else(|| syn::Error::new_spanned(path, "expected a vertex label"))
+}
+
+/// The path to the vertex label variant that an endpoint refers to.
+fn label_path(path: Path) -> Result<Path, syn::Error> {
+    match path.get_ident() {
+        Some(ident) => Err(syn::Error::new_spanned(
+            &path,
+            format!("expected a path to a vertex label variant, such as `VertexLabel::{ident}`"),
+        )),
+        None => Ok(path),
+    }
+}
+
 pub(crate) fn ref_type(ty: &Type, lifetime: Option<Lifetime>) -> Type {
     if *ty == parse_quote!(String) {
         return match lifetime {
@@ -305,6 +404,26 @@ pub(crate) fn ref_type(ty: &Type, lifetime: Option<Lifetime>) -> Type {
     ty.clone()
 }
 
+/// Returns true if the field type converts to a `Value`, so that it can be read by name.
+pub(crate) fn value_type(ty: &Type) -> bool {
+    *ty == parse_quote!(String)
+        || *ty == parse_quote!(bool)
+        || *ty == parse_quote!(usize)
+        || *ty == parse_quote!(u8)
+        || *ty == parse_quote!(i8)
+        || *ty == parse_quote!(u16)
+        || *ty == parse_quote!(i16)
+        || *ty == parse_quote!(u32)
+        || *ty == parse_quote!(i32)
+        || *ty == parse_quote!(f32)
+        || *ty == parse_quote!(u64)
+        || *ty == parse_quote!(i64)
+        || *ty == parse_quote!(f64)
+        || *ty == parse_quote!(u128)
+        || *ty == parse_quote!(i128)
+        || *ty == parse_quote!(Uuid)
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::{DeriveType, Model};
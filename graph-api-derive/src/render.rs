@@ -1,4 +1,4 @@
-use crate::model::{EnumVariants, Field, Model, Variant, VariantType, ref_type};
+use crate::model::{EnumVariants, Field, Model, Variant, VariantType, ref_type, value_type};
 use case::CaseExt;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
@@ -13,6 +13,7 @@ impl Model {
         let impl_index = self.as_impl_index();
         let impl_element = self.as_impl_element();
         let impl_selectors = self.as_selectors();
+        let impl_joins: Vec<TokenStream> = self.variants.iter().map(Variant::joins).collect();
 
         let projections: Vec<TokenStream> = self
             .variants
@@ -44,12 +45,13 @@ impl Model {
             #impl_index
             #impl_element
             #impl_selectors
+            #(#impl_joins)*
 
             #(#projections)*
 
             #vis trait #trait_name<'graph, Mutability, Graph, Walker>
             where
-                Walker: graph_api_lib::EdgeWalker<'graph, Graph = Graph>,
+                Walker: graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph>,
                 Graph: graph_api_lib::Graph<Edge = #element_ident>,
             {
                 #(#nav_api)*
@@ -58,7 +60,7 @@ impl Model {
             impl<'graph, Mutability, Graph, Walker> #trait_name<'graph, Mutability, Graph, Walker>
                 for graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
             where
-                Walker: graph_api_lib::EdgeWalker<'graph, Graph = Graph>,
+                Walker: graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph>,
                 Graph: graph_api_lib::Graph<Edge = #element_ident>,
             {
                 #(#nav_impl)*
@@ -110,7 +112,7 @@ impl Model {
 
             #vis trait #trait_name<'graph, Mutability, Graph, Walker>
             where
-                Walker: graph_api_lib::VertexWalker<'graph, Graph = Graph>,
+                Walker: graph_api_lib::RebindVertexWalker<'graph, Graph = Graph>,
                 Graph: graph_api_lib::Graph<Vertex = #element_ident>,
             {
                 #(#nav_api)*
@@ -119,7 +121,7 @@ impl Model {
             impl<'graph, Mutability, Graph, Walker> #trait_name<'graph, Mutability, Graph, Walker>
                 for graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
             where
-                Walker: graph_api_lib::VertexWalker<'graph, Graph = Graph>,
+                Walker: graph_api_lib::RebindVertexWalker<'graph, Graph = Graph>,
                 Graph: graph_api_lib::Graph<Vertex = #element_ident>,
             {
                 #(#nav_impl)*
@@ -155,6 +157,12 @@ impl Model {
             .flat_map(Variant::index_type)
             .collect::<Vec<_>>();
 
+        let index_names: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .flat_map(Variant::index_names)
+            .collect::<Vec<_>>();
+
         if index_count == 0 {
             quote! {
                 impl graph_api_lib::Index for #index_ident {
@@ -169,6 +177,10 @@ impl Model {
                     fn index_type(&self) -> graph_api_lib::IndexType {
                         unreachable!("this index enum has no variants")
                     }
+
+                    fn name(&self) -> &'static str {
+                        unreachable!("this index enum has no variants")
+                    }
                 }
             }
         } else {
@@ -191,6 +203,12 @@ impl Model {
                             #(#index_ident::#all_indexes => #index_index_types),*
                         }
                     }
+
+                    fn name(&self) -> &'static str {
+                        match self {
+                            #(#index_ident::#all_indexes => #index_names),*
+                        }
+                    }
                 }
             }
         }
@@ -211,6 +229,38 @@ impl Model {
             .flat_map(Variant::index_accessors)
             .collect::<Vec<_>>();
 
+        let property_accessor: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .flat_map(Variant::property_accessors)
+            .collect::<Vec<_>>();
+
+        let field_constructors: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .filter_map(Variant::field_constructor)
+            .collect::<Vec<_>>();
+
+        let fn_from_fields = if field_constructors.is_empty() {
+            quote! {}
+        } else {
+            let unsupported = if field_constructors.len() < self.variants.len() {
+                quote! {
+                    _ => Err(graph_api_lib::FieldError::Unsupported { label: graph_api_lib::Label::name(&label) })
+                }
+            } else {
+                quote! {}
+            };
+            quote! {
+                fn from_fields<'a>(label: Self::Label, field: &dyn Fn(&str) -> Option<&'a str>) -> Result<Self, graph_api_lib::FieldError> {
+                    match label {
+                        #(#field_constructors,)*
+                        #unsupported
+                    }
+                }
+            }
+        };
+
         let fn_value = if index_accessor.is_empty() {
             quote! {}
         } else {
@@ -224,6 +274,19 @@ impl Model {
             }
         };
 
+        let fn_property = if property_accessor.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn property(&self, name: &str) -> Option<graph_api_lib::Value> {
+                    match (self, name) {
+                        #(#property_accessor),*
+                        (_,_)=> None
+                    }
+                }
+            }
+        };
+
         quote! {
             impl graph_api_lib::Element for #ident {
                 type Label = #label_ident;
@@ -235,6 +298,10 @@ impl Model {
 
                 #fn_value
 
+                #fn_property
+
+                #fn_from_fields
+
             }
         }
     }
@@ -255,6 +322,29 @@ impl Model {
             .map(Variant::static_indexes)
             .collect::<Vec<_>>();
 
+        let fields_by_label: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .map(Variant::static_fields)
+            .collect::<Vec<_>>();
+
+        let fn_endpoints = if self.variants.iter().all(|v| v.endpoints.is_empty()) {
+            quote! {}
+        } else {
+            let endpoints_by_label: Vec<TokenStream> = self
+                .variants
+                .iter()
+                .map(Variant::static_endpoints)
+                .collect::<Vec<_>>();
+            quote! {
+                fn endpoints(&self) -> &'static [graph_api_lib::Endpoints] {
+                    match self {
+                        #(#endpoints_by_label),*
+                    }
+                }
+            }
+        };
+
         quote! {
             #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
             #vis enum #label_ident {
@@ -285,6 +375,14 @@ impl Model {
                         #(#label_ident::#labels => stringify!(#labels)),*
                     }
                 }
+
+                fn fields(&self) -> &'static [graph_api_lib::schema::FieldSchema] {
+                    match self {
+                        #(#fields_by_label),*
+                    }
+                }
+
+                #fn_endpoints
             }
         }
     }
@@ -300,6 +398,7 @@ impl Model {
 
         quote! {
             #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+            #[allow(clippy::enum_variant_names)]
             #vis enum #index_ident {
                 #(#indexes),*
             }
@@ -325,11 +424,18 @@ impl Model {
             .flat_map(Variant::index_range_selectors)
             .collect::<Vec<_>>();
 
+        let typed_helpers: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .map(Variant::typed_helper)
+            .collect::<Vec<_>>();
+
         quote! {
             impl #ident {
                 #(#label_selectors)*
                 #(#index_selectors)*
                 #(#index_range_selectors)*
+                #(#typed_helpers)*
             }
         }
     }
@@ -342,7 +448,7 @@ impl EnumVariants {
                 quote! {
                   fn #all_ident(
                     self,
-                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
             EnumVariants::NamedFields {
@@ -355,11 +461,11 @@ impl EnumVariants {
                   fn #filter_ident<F: Fn(#filter_param<Graph::Edge>, &Walker::Context)->bool>(
                     self,
                         filter: F
-                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>;
 
                   fn #all_ident(
                     self,
-                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
             EnumVariants::UnnamedFields {
@@ -372,11 +478,11 @@ impl EnumVariants {
                   fn #filter_ident<F: Fn(#(&#filter_params_types),*, &Walker::Context)->bool>(
                     self,
                         filter: F
-                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>;
 
                   fn #all_ident(
                     self,
-                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
         }
@@ -391,7 +497,7 @@ impl EnumVariants {
                 quote! {
                    fn #all_ident(
                         self,
-                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
+                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(|f, _| match graph_api_lib::EdgeReference::weight(f) {
                             #target_ty::#target_variant { .. } => true,
@@ -411,7 +517,7 @@ impl EnumVariants {
                     fn #filter_ident<F: Fn(#filter_param<Graph::Edge>, &Walker::Context)->bool>(
                         self,
                         filter: F
-                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, c| {
                             if let Some(projection) = graph_api_lib::EdgeReference::project(f) {
@@ -424,7 +530,7 @@ impl EnumVariants {
                     }
                      fn #all_ident(
                         self,
-                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, _| match graph_api_lib::EdgeReference::weight(f) {
                             #target_ty::#target_variant { .. } => true,
@@ -447,7 +553,7 @@ impl EnumVariants {
                     fn #filter_ident<F: Fn(#(&#filter_params_types),*, &Walker::Context)->bool>(
                         self,
                         filter: F
-                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, c| match graph_api_lib::EdgeReference::weight(f) {
                             #target_ty::#target_variant ( #(#params),* ) => (filter)(#(#params),*, c),
@@ -456,7 +562,7 @@ impl EnumVariants {
                     }
                     fn #all_ident(
                         self,
-                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::EdgeWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::EdgeWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindEdgeWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, _| match graph_api_lib::EdgeReference::weight(f) {
                             #target_ty::#target_variant ( .. ) => true,
@@ -476,7 +582,7 @@ impl EnumVariants {
                 quote! {
                   fn #all_ident(
                     self,
-                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
             EnumVariants::NamedFields {
@@ -488,12 +594,12 @@ impl EnumVariants {
                 quote! {
                     fn #all_ident(
                     self,
-                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>;
 
                   fn #ident<F: Fn(#filter_param<Graph::Vertex>, &Walker::Context)->bool>(
                     self,
                         filter: F
-                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
             EnumVariants::UnnamedFields {
@@ -505,12 +611,12 @@ impl EnumVariants {
                 quote! {
                   fn #all_ident(
                     self,
-                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>;
 
                   fn #ident<F: Fn(#(&#filter_params_types),*, &Walker::Context)->bool>(
                     self,
                         filter: F
-                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>;
+                  ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>;
                 }
             }
         }
@@ -525,7 +631,7 @@ impl EnumVariants {
                 quote! {
                    fn #all_ident(
                         self,
-                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(|f, _| match graph_api_lib::VertexReference::weight(f) {
                             #target_ty::#target_variant { .. } => true,
@@ -545,7 +651,7 @@ impl EnumVariants {
                 quote! {
                     fn #all_ident(
                         self,
-                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(|f, _| match graph_api_lib::VertexReference::weight(f) {
                             #target_ty::#target_variant { .. } => true,
@@ -555,7 +661,7 @@ impl EnumVariants {
                     fn #filter_ident<F: Fn(#filter_param<Graph::Vertex>, &Walker::Context)->bool>(
                         self,
                         filter: F
-                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, c| {
                             if let Some(projection) = graph_api_lib::VertexReference::project(f) {
@@ -582,7 +688,7 @@ impl EnumVariants {
                 quote! {
                     fn #all_ident(
                         self,
-                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(|f, _| match graph_api_lib::VertexReference::weight(f) {
                             #target_ty::#target_variant { .. } => true,
@@ -592,7 +698,7 @@ impl EnumVariants {
                     fn #filter_ident<F: Fn(#(&#filter_params_types),*, &Walker::Context)->bool>(
                         self,
                         filter: F
-                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::VertexWalker<'graph, Graph = Graph, Context=Walker::Context>>
+                    ) -> graph_api_lib::VertexWalkerBuilder<'graph, Mutability, Graph, impl graph_api_lib::RebindVertexWalker<'graph, Graph = Graph, Context = Walker::Context>>
                     {
                         self.filter(move |f, c| match graph_api_lib::VertexReference::weight(f) {
                             #target_ty::#target_variant ( #(#params),* ) => (filter)(#(#params),*, c),
@@ -653,6 +759,118 @@ impl Variant {
         }
     }
 
+    fn static_fields(&self) -> TokenStream {
+        let variant = &self.ident;
+        let label_ident = &self.label_ident;
+        let names = self.fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let types = self.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        let indexes = self
+            .fields
+            .iter()
+            .map(|f| {
+                if f.hash {
+                    quote! {Some(graph_api_lib::IndexType::Hash)}
+                } else if f.range {
+                    quote! {Some(graph_api_lib::IndexType::Range)}
+                } else if f.full_text {
+                    quote! {Some(graph_api_lib::IndexType::FullText)}
+                } else {
+                    quote! {None}
+                }
+            })
+            .collect::<Vec<_>>();
+        let field_count = self.fields.len();
+        quote! {
+            #label_ident::#variant => {
+                static FIELDS: [graph_api_lib::schema::FieldSchema; #field_count] = [#(graph_api_lib::schema::FieldSchema { name: stringify!(#names), ty: stringify!(#types), index: #indexes }),*];
+                &FIELDS
+            }
+        }
+    }
+
+    fn static_endpoints(&self) -> TokenStream {
+        let variant = &self.ident;
+        let label_ident = &self.label_ident;
+        let from = self.endpoints.iter().map(|e| &e.from).collect::<Vec<_>>();
+        let to = self.endpoints.iter().map(|e| &e.to).collect::<Vec<_>>();
+        let from_label = self.endpoints.iter().map(|e| &e.from_label);
+        let to_label = self.endpoints.iter().map(|e| &e.to_label);
+        let endpoint_count = self.endpoints.len();
+        quote! {
+            #label_ident::#variant => {
+                static ENDPOINTS: [graph_api_lib::Endpoints; #endpoint_count] = [#(graph_api_lib::Endpoints { from: stringify!(#from), to: stringify!(#to), from_ordinal: #from_label as usize, to_ordinal: #to_label as usize }),*];
+                &ENDPOINTS
+            }
+        }
+    }
+
+    /// Vertices get a constructor for ids with this label, and edges with endpoints get a helper
+    /// that adds them between ids with the allowed labels.
+    fn typed_helper(&self) -> TokenStream {
+        let vis = &self.visibility;
+        let element_ident = &self.element_ident;
+        let label_ident = &self.label_ident;
+        let variant = &self.ident;
+        let snake = self.ident.to_string().to_snake();
+
+        if self.element_type == "Vertex" {
+            let fn_name = format_ident!("{}_id", snake);
+            return quote! {
+                #vis fn #fn_name<Graph>(
+                    graph: &Graph,
+                    id: Graph::VertexId,
+                ) -> Option<graph_api_lib::LabelledVertexId<Graph, { #label_ident::#variant as usize }>>
+                where
+                    Graph: graph_api_lib::Graph<Vertex = #element_ident>,
+                {
+                    graph_api_lib::LabelledVertexId::new(graph, id)
+                }
+            };
+        }
+        if self.endpoints.is_empty() {
+            return quote! {};
+        }
+
+        let fn_name = format_ident!("add_{}", snake);
+        let names = self
+            .parameters
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let types = self.parameters.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+        let weight = match self.variant_type {
+            VariantType::Unit => quote! { #element_ident::#variant },
+            VariantType::Named => quote! { #element_ident::#variant { #(#names),* } },
+            VariantType::Unnamed => quote! { #element_ident::#variant ( #(#names),* ) },
+        };
+        quote! {
+            #vis fn #fn_name<Graph, const FROM: usize, const TO: usize>(
+                graph: &mut Graph,
+                from: graph_api_lib::LabelledVertexId<Graph, FROM>,
+                to: graph_api_lib::LabelledVertexId<Graph, TO>,
+                #(#names: #types),*
+            ) -> Graph::EdgeId
+            where
+                Graph: graph_api_lib::Graph<Edge = #element_ident>,
+                #element_ident: graph_api_lib::Joins<{ #label_ident::#variant as usize }, FROM, TO>,
+            {
+                graph_api_lib::Graph::add_edge(graph, from.id(), to.id(), #weight)
+            }
+        }
+    }
+
+    /// The vertex labels that this edge may join, named so that the compiler checks them.
+    fn joins(&self) -> TokenStream {
+        let element_ident = &self.element_ident;
+        let label_ident = &self.label_ident;
+        let variant = &self.ident;
+        let from = self.endpoints.iter().map(|e| &e.from_label);
+        let to = self.endpoints.iter().map(|e| &e.to_label);
+        quote! {
+            #(impl graph_api_lib::Joins<{ #label_ident::#variant as usize }, { #from as usize }, { #to as usize }> for #element_ident {})*
+        }
+    }
+
     fn label_selector(&self) -> TokenStream {
         let vis = &self.visibility;
         let element_ident = &self.element_ident;
@@ -747,6 +965,50 @@ impl Variant {
             .collect()
     }
 
+    fn property_accessors(&self) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .filter(|f| value_type(&f.ty))
+            .map(|f| {
+                let element = &self.element_ident;
+                let element_variant = &self.ident;
+                let field = &f.ident;
+                let name = field.to_string();
+                quote! {(#element::#element_variant{#field: value,..}, #name) => {Some((value).into())}}
+            })
+            .collect()
+    }
+
+    /// The match arm that creates this variant from the text of its fields, if every field can
+    /// be parsed.
+    fn field_constructor(&self) -> Option<TokenStream> {
+        let element = &self.element_ident;
+        let element_variant = &self.ident;
+        let label_ident = &self.label_ident;
+        let label = self.ident.to_string();
+        match self.variant_type {
+            VariantType::Unit => {
+                Some(quote! {#label_ident::#element_variant => Ok(#element::#element_variant)})
+            }
+            VariantType::Named if self.fields.iter().all(|f| value_type(&f.ty)) => {
+                let fields = self.fields.iter().map(|f| {
+                    let field = &f.ident;
+                    let name = field.to_string();
+                    quote! {
+                        #field: {
+                            let value = field(#name).ok_or(graph_api_lib::FieldError::Missing { label: #label, field: #name })?;
+                            value.parse().map_err(|_| graph_api_lib::FieldError::Invalid { label: #label, field: #name, value: value.to_string() })?
+                        }
+                    }
+                });
+                Some(
+                    quote! {#label_ident::#element_variant => Ok(#element::#element_variant { #(#fields),* })},
+                )
+            }
+            _ => None,
+        }
+    }
+
     fn index_ty(&self) -> Vec<TokenStream> {
         self.indexed_fields()
             .map(|f| {
@@ -765,6 +1027,15 @@ impl Variant {
             .collect()
     }
 
+    fn index_names(&self) -> Vec<TokenStream> {
+        self.indexed_fields()
+            .map(|f| {
+                let ident = &f.ident;
+                quote! {stringify!(#ident)}
+            })
+            .collect()
+    }
+
     fn indexed_fields(&self) -> impl Iterator<Item = &Field> {
         self.fields.iter().filter(|f| f.indexed)
     }
@@ -934,6 +1205,14 @@ impl Variant {
                         }
                     }
                 }
+
+                impl<'reference> graph_api_lib::ProjectionMut<#element_ident>
+                    for #mut_ident<'reference, #element_ident, ()>
+                {
+                    type Projection<'projection, MutationListener> = #mut_ident<'projection, #element_ident, MutationListener>
+                    where
+                        MutationListener: graph_api_lib::MutationListener<'projection, #element_ident>;
+                }
             }
         }
     }
@@ -1003,6 +1282,14 @@ impl Variant {
                         }
                     }
                 }
+
+                impl<'reference> graph_api_lib::ProjectionMut<#element_ident>
+                    for #mut_ident<'reference, #element_ident, ()>
+                {
+                    type Projection<'projection, MutationListener> = #mut_ident<'projection, #element_ident, MutationListener>
+                    where
+                        MutationListener: graph_api_lib::MutationListener<'projection, #element_ident>;
+                }
             }
         }
     }
@@ -1057,8 +1344,12 @@ mod tests {
         let input = quote! {
             #[derive(EdgeExt)]
             pub enum Edge {
+                #[edge(from = VertexLabel::Person, to = VertexLabel::Person)]
                 Knows { since: i32 },
+                #[edge(from = VertexLabel::Person, to = VertexLabel::Project)]
+                #[edge(from = VertexLabel::Person, to = VertexLabel::Rust)]
                 Created,
+                #[edge(from = VertexLabel::Project, to = VertexLabel::Rust)]
                 Language(Language),
             }
         };
@@ -1,5 +1,5 @@
 use graph_api_derive::{EdgeExt, VertexExt};
-use graph_api_lib::{Element, Index, Label};
+use graph_api_lib::{Element, Endpoints, FieldError, Index, Label};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, VertexExt)]
@@ -28,8 +28,13 @@ pub struct Project {
 
 #[derive(Debug, Clone, EdgeExt)]
 pub enum Edge {
-    Knows { since: i32 },
+    Knows {
+        since: i32,
+    },
+    #[edge(from = VertexLabel::Person, to = VertexLabel::Project)]
+    #[edge(from = VertexLabel::Person, to = VertexLabel::Rust)]
     Created,
+    #[edge(from = VertexLabel::Project, to = VertexLabel::Rust)]
     Language(Language),
 }
 #[derive(Debug, Clone)]
@@ -52,3 +57,107 @@ fn test_label() {
     assert_eq!(label.indexes().len(), 5);
     assert_eq!(label.indexes()[0].ty(), std::any::TypeId::of::<String>());
 }
+
+#[test]
+fn test_endpoints() {
+    assert!(EdgeLabel::Knows.endpoints().is_empty());
+    assert_eq!(
+        EdgeLabel::Created.endpoints(),
+        &[
+            Endpoints {
+                from: "Person",
+                to: "Project",
+                from_ordinal: VertexLabel::Person as usize,
+                to_ordinal: VertexLabel::Project as usize,
+            },
+            Endpoints {
+                from: "Person",
+                to: "Rust",
+                from_ordinal: VertexLabel::Person as usize,
+                to_ordinal: VertexLabel::Rust as usize,
+            }
+        ]
+    );
+    assert_eq!(
+        EdgeLabel::Language.endpoints(),
+        &[Endpoints {
+            from: "Project",
+            to: "Rust",
+            from_ordinal: VertexLabel::Project as usize,
+            to_ordinal: VertexLabel::Rust as usize,
+        }]
+    );
+}
+
+mod renamed {
+    use graph_api_derive::{EdgeExt, VertexExt};
+
+    #[derive(Debug, Clone, VertexExt)]
+    pub enum Node {
+        Project,
+        Person,
+    }
+
+    #[derive(Debug, Clone, EdgeExt)]
+    pub enum Link {
+        #[edge(from = NodeLabel::Person, to = NodeLabel::Project)]
+        Created,
+    }
+}
+
+#[test]
+fn test_endpoints_renamed_vertex() {
+    use renamed::{Link, Node, NodeLabel};
+    let endpoints = Link::Created.label().endpoints();
+    assert_eq!(
+        endpoints,
+        &[Endpoints {
+            from: "Person",
+            to: "Project",
+            from_ordinal: NodeLabel::Person as usize,
+            to_ordinal: NodeLabel::Project as usize,
+        }]
+    );
+    assert_eq!(endpoints[0].from_ordinal, Node::Person.label().ordinal());
+    assert_eq!(endpoints[0].to_ordinal, Node::Project.label().ordinal());
+}
+
+#[test]
+fn test_from_fields() {
+    let fields = |name: &str| match name {
+        "since" => Some("1999"),
+        _ => None,
+    };
+    assert!(matches!(
+        Edge::from_fields(EdgeLabel::Knows, &fields),
+        Ok(Edge::Knows { since: 1999 })
+    ));
+    assert!(matches!(
+        Edge::from_fields(EdgeLabel::Created, &fields),
+        Ok(Edge::Created)
+    ));
+    assert_eq!(
+        Edge::from_fields(EdgeLabel::Language, &fields).err(),
+        Some(FieldError::Unsupported { label: "Language" })
+    );
+
+    let fields = |name: &str| match name {
+        "age" => Some("old"),
+        _ => Some("1"),
+    };
+    assert_eq!(
+        Vertex::from_fields(VertexLabel::Person, &fields).err(),
+        Some(FieldError::Invalid {
+            label: "Person",
+            field: "age",
+            value: "old".to_string()
+        })
+    );
+    assert_eq!(
+        Vertex::from_fields(VertexLabel::Person, &|_| None).err(),
+        Some(FieldError::Missing {
+            label: "Person",
+            field: "non_indexed"
+        })
+    );
+}
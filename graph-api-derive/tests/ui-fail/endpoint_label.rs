@@ -0,0 +1,15 @@
+use graph_api_derive::{EdgeExt, VertexExt};
+
+#[derive(Debug, Clone, VertexExt)]
+pub enum Vertex {
+    Person { name: String },
+    Project,
+}
+
+#[derive(Debug, Clone, EdgeExt)]
+pub enum Edge {
+    #[edge(from = VertexLabel::Persn, to = VertexLabel::Project)]
+    Created,
+}
+
+fn main() {}
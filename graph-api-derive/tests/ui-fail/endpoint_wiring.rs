@@ -0,0 +1,25 @@
+use graph_api_derive::{EdgeExt, VertexExt};
+
+#[derive(Debug, Clone, VertexExt)]
+pub enum Vertex {
+    Person { name: String },
+    Project,
+}
+
+#[derive(Debug, Clone, EdgeExt)]
+pub enum Edge {
+    #[edge(from = VertexLabel::Person, to = VertexLabel::Project)]
+    Created,
+}
+
+fn wire<G>(graph: &mut G, person: G::VertexId, project: G::VertexId)
+where
+    G: graph_api_lib::Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let person = Vertex::person_id(graph, person).unwrap();
+    let project = Vertex::project_id(graph, project).unwrap();
+    // Projects don't create people
+    Edge::add_created(graph, project, person);
+}
+
+fn main() {}
@@ -0,0 +1,90 @@
+use graph_api_lib::Direction;
+pub(crate) use graph_api_lib::query::Literal;
+use graph_api_lib::query::Predicate;
+
+/// A parsed traversal, with labels and properties still referred to by name.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Traversal {
+    pub(crate) start: Start,
+    pub(crate) steps: Vec<Step>,
+    pub(crate) terminal: Terminal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Start {
+    /// `g.V()`
+    Vertices,
+    /// `g.E()`
+    Edges,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Step {
+    /// `hasLabel('Person', ...)`
+    HasLabel(Vec<String>),
+    /// `has('name')`, `has('name', value)` or `has('Person', 'name', value)`
+    Has {
+        label: Option<String>,
+        property: String,
+        test: Option<Test>,
+    },
+    /// `out`, `in`, `outE`, `inE` and `bothE`. Steps that end on vertices have an end.
+    Move {
+        direction: Direction,
+        labels: Vec<String>,
+        end: Option<End>,
+    },
+    /// `outV` and `inV`
+    End(End),
+    /// `limit(n)`
+    Limit(usize),
+    /// `dedup()`
+    Dedup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum End {
+    Head,
+    Tail,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Terminal {
+    /// The traversal ends on elements, or with `id()`.
+    Elements,
+    /// `count()`
+    Count,
+    /// `label()`
+    Label,
+    /// `values('name', ...)`
+    Values(Vec<String>),
+}
+
+/// A predicate from the `P` class, such as `gt(30)`. A bare value is `eq`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Test {
+    Eq(Literal),
+    Neq(Literal),
+    Lt(Literal),
+    Lte(Literal),
+    Gt(Literal),
+    Gte(Literal),
+    Between(Literal, Literal),
+}
+
+impl Test {
+    pub(crate) fn predicate(&self) -> Predicate {
+        match self {
+            Test::Eq(literal) => Predicate::Eq(literal.clone()),
+            Test::Neq(literal) => Predicate::Ne(literal.clone()),
+            Test::Lt(literal) => Predicate::Lt(literal.clone()),
+            Test::Lte(literal) => Predicate::Le(literal.clone()),
+            Test::Gt(literal) => Predicate::Gt(literal.clone()),
+            Test::Gte(literal) => Predicate::Ge(literal.clone()),
+            Test::Between(start, end) => Predicate::Range {
+                start: start.clone(),
+                end: end.clone(),
+            },
+        }
+    }
+}
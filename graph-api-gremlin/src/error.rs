@@ -0,0 +1,41 @@
+use graph_api_lib::query::QueryError;
+use thiserror::Error;
+
+/// Errors that can occur when parsing or executing a traversal.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The traversal is not valid Gremlin.
+    #[error("syntax error at position {position}: {message}")]
+    Syntax { position: usize, message: String },
+
+    /// A Gremlin step that is outside of the supported subset.
+    #[error("unsupported step {step} at position {position}")]
+    UnsupportedStep { step: String, position: usize },
+
+    /// A supported step called with the wrong arguments.
+    #[error("invalid arguments to {step}: {message}")]
+    Arguments { step: String, message: String },
+
+    /// A step that can't be applied to the output of the previous step, such as `out()` after
+    /// `count()`.
+    #[error("{step} can't be applied to {current}")]
+    Misplaced { step: String, current: &'static str },
+
+    /// A vertex or edge label that the graph does not have.
+    #[error("unknown label {0}")]
+    UnknownLabel(String),
+
+    /// The traversal doesn't fit the graph in a way that the checks above don't cover.
+    #[error(transparent)]
+    Query(QueryError),
+}
+
+impl From<QueryError> for Error {
+    fn from(error: QueryError) -> Self {
+        match error {
+            QueryError::UnknownLabel(label) => Error::UnknownLabel(label),
+            error => Error::Query(error),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Ident(String),
+    Str(String),
+    Int(i64),
+    Float(f64),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+}
+
+/// Splits a traversal into tokens, each paired with its byte offset in the traversal.
+pub(crate) fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '.' => Token::Dot,
+            '\'' | '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, 'n')) => value.push('\n'),
+                            Some((_, 't')) => value.push('\t'),
+                            Some((_, escaped)) => value.push(escaped),
+                            None => break,
+                        },
+                        Some((_, end)) if end == c => break,
+                        Some((_, next)) => value.push(next),
+                        None => {
+                            return Err(Error::Syntax {
+                                position,
+                                message: "unterminated string".to_string(),
+                            });
+                        }
+                    }
+                }
+                Token::Str(value)
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut end = position + c.len_utf8();
+                let mut float = false;
+                while let Some(&(next_position, next)) = chars.peek() {
+                    let fraction = next == '.'
+                        && !float
+                        && text[next_position + 1..]
+                            .chars()
+                            .next()
+                            .is_some_and(|c| c.is_ascii_digit());
+                    if !next.is_ascii_digit() && !fraction {
+                        break;
+                    }
+                    float |= fraction;
+                    end = next_position + next.len_utf8();
+                    chars.next();
+                }
+                // Gremlin allows type suffixes such as 10L and 1.5d
+                if let Some(&(_, 'L' | 'l' | 'd' | 'D' | 'f' | 'F')) = chars.peek() {
+                    chars.next();
+                }
+                let number = &text[position..end];
+                let invalid = || Error::Syntax {
+                    position,
+                    message: format!("invalid number {}", number),
+                };
+                if float {
+                    Token::Float(number.parse().map_err(|_| invalid())?)
+                } else {
+                    Token::Int(number.parse().map_err(|_| invalid())?)
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = position + c.len_utf8();
+                while let Some(&(next_position, next)) = chars.peek() {
+                    if !next.is_alphanumeric() && next != '_' {
+                        break;
+                    }
+                    end = next_position + next.len_utf8();
+                    chars.next();
+                }
+                Token::Ident(text[position..end].to_string())
+            }
+            c => {
+                return Err(Error::Syntax {
+                    position,
+                    message: format!("unexpected character '{}'", c),
+                });
+            }
+        };
+        tokens.push((token, position));
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,187 @@
+//! [Gremlin](https://tinkerpop.apache.org/gremlin.html) traversal strings for any
+//! [`Graph`](graph_api_lib::Graph).
+//!
+//! Traversals are parsed once and then compiled to a [`Query`](graph_api_lib::query::Query),
+//! so `g.V().hasLabel('Person').has('name', 'Bryn').out('Knows').values('name')` becomes a
+//! vertex search, a filter, an edge step and a head step.
+//!
+//! ```rust
+//! use graph_api_gremlin::{Output, Traversal};
+//! use graph_api_lib::plan::Capabilities;
+//! use graph_api_simplegraph::SimpleGraph;
+//! use graph_api_test::populate_graph;
+//!
+//! let mut graph = SimpleGraph::new();
+//! populate_graph(&mut graph);
+//!
+//! let traversal =
+//!     Traversal::parse("g.V().hasLabel('Person').has('name', 'Bryn').out('Knows').values('name')")
+//!         .unwrap();
+//! if let Output::Values(values) = traversal.execute(&graph, &Capabilities::all()).unwrap() {
+//!     for name in &values {
+//!         println!("{}", name);
+//!     }
+//! }
+//! ```
+//!
+//! The supported steps are:
+//! * `g.V()` and `g.E()`, without ids.
+//! * `hasLabel`, and `has` with a value or one of `eq`, `neq`, `lt`, `lte`, `gt`, `gte` and
+//!   `between`.
+//! * `out`, `in`, `outE`, `inE`, `bothE`, `outV` and `inV`.
+//! * `limit` and `dedup`.
+//! * `count`, `id`, `label`, `values` and `toList` at the end of a traversal.
+//!
+//! Any other step is rejected with [`Error::UnsupportedStep`].
+
+mod ast;
+mod error;
+mod lexer;
+mod parser;
+mod traversal;
+
+pub use error::Error;
+pub use traversal::{Output, Traversal, Values};
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, Output, Traversal};
+    use graph_api_lib::plan::Capabilities;
+    use graph_api_lib::{Graph, OwnedValue};
+    use graph_api_simplegraph::SimpleGraph;
+    use graph_api_test::{Edge, Vertex, populate_graph};
+    use petgraph::stable_graph::StableGraph;
+
+    fn values<G>(graph: &G, capabilities: &Capabilities, text: &str) -> Vec<String>
+    where
+        G: Graph,
+    {
+        let traversal = Traversal::parse(text).expect("traversal must parse");
+        let Output::Values(values) = traversal
+            .execute(graph, capabilities)
+            .expect("traversal must run")
+        else {
+            panic!("expected values");
+        };
+        values
+            .into_iter()
+            .map(|value| match value {
+                OwnedValue::String(value) => value,
+                value => format!("{:?}", value.value()),
+            })
+            .collect()
+    }
+
+    fn count<G>(graph: &G, capabilities: &Capabilities, text: &str) -> usize
+    where
+        G: Graph,
+    {
+        let traversal = Traversal::parse(text).expect("traversal must parse");
+        match traversal.execute(graph, capabilities) {
+            Ok(Output::Count(count)) => count,
+            _ => panic!("expected a count"),
+        }
+    }
+
+    fn traversals<G>(graph: &mut G, capabilities: &Capabilities)
+    where
+        G: Graph<Vertex = Vertex, Edge = Edge>,
+    {
+        let refs = populate_graph(graph);
+
+        assert_eq!(
+            values(
+                graph,
+                capabilities,
+                "g.V().hasLabel('Person').has('name', 'Bryn').out('Knows').values('name')"
+            ),
+            vec!["Julia"]
+        );
+        assert_eq!(
+            values(
+                graph,
+                capabilities,
+                "g.V().has('Person', 'age', P.gt(46)).values('username', 'age')"
+            ),
+            vec!["julia", "U64(48)"]
+        );
+        assert_eq!(
+            values(
+                graph,
+                capabilities,
+                "g.V().hasLabel('Rust').in('Language').in().label()"
+            ),
+            vec!["Person"]
+        );
+
+        // Non-indexed edge properties
+        assert_eq!(
+            count(
+                graph,
+                capabilities,
+                "g.E().has('since', between(1990, 2000)).outV().dedup().count()"
+            ),
+            2
+        );
+        assert_eq!(count(graph, capabilities, "g.V().out().dedup().count()"), 4);
+        assert_eq!(
+            count(graph, capabilities, "g.V().bothE('Knows').count()"),
+            4
+        );
+        assert_eq!(
+            count(
+                graph,
+                capabilities,
+                "g.V().hasLabel('Person').limit(1).count()"
+            ),
+            1
+        );
+
+        let traversal = Traversal::parse("g.V().has('name', 'Julia').id().toList()").unwrap();
+        assert!(matches!(
+            traversal.execute(graph, capabilities),
+            Ok(Output::Vertices(vertices)) if vertices == vec![refs.julia]
+        ));
+        let traversal = Traversal::parse("g.V().has('name', 'Bryn').outE('Created')").unwrap();
+        assert!(matches!(
+            traversal.execute(graph, capabilities),
+            Ok(Output::Edges(edges)) if edges == vec![refs.bryn_created_graph_api]
+        ));
+    }
+
+    #[test]
+    fn simple_graph() {
+        traversals(&mut SimpleGraph::new(), &Capabilities::all());
+    }
+
+    #[test]
+    fn petgraph() {
+        traversals(&mut StableGraph::new(), &Capabilities::none());
+    }
+
+    #[test]
+    fn errors() {
+        let graph = SimpleGraph::<Vertex, Edge>::new();
+        let execute = |text: &str| {
+            Traversal::parse(text)
+                .and_then(|traversal| traversal.execute(&graph, &Capabilities::all()).map(|_| ()))
+        };
+
+        assert_eq!(
+            execute("g.V().hasLabel('Robot')"),
+            Err(Error::UnknownLabel("Robot".to_string()))
+        );
+        // Person is a vertex label, so it can't be followed
+        assert_eq!(
+            execute("g.V().out('Person')"),
+            Err(Error::UnknownLabel("Person".to_string()))
+        );
+        assert_eq!(
+            execute("g.V().both()"),
+            Err(Error::UnsupportedStep {
+                step: "both".to_string(),
+                position: 6,
+            })
+        );
+    }
+}
@@ -0,0 +1,452 @@
+use crate::Error;
+use crate::ast::{End, Literal, Start, Step, Terminal, Test, Traversal};
+use crate::lexer::{Token, tokenize};
+use graph_api_lib::Direction;
+
+/// Parses the text of a traversal.
+pub(crate) fn parse(text: &str) -> Result<Traversal, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+        end: text.len(),
+    };
+    parser.traversal()
+}
+
+/// The steps that are supported in some position.
+const STEPS: &[&str] = &[
+    "hasLabel", "has", "limit", "dedup", "out", "in", "outE", "inE", "bothE", "outV", "inV",
+    "count", "id", "label", "values", "toList",
+];
+
+/// What the traversal is positioned on after a step, used to reject steps that don't apply.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Current {
+    Vertices,
+    Edges,
+    Terminal(&'static str),
+}
+
+impl Current {
+    fn description(self) -> &'static str {
+        match self {
+            Current::Vertices => "vertices",
+            Current::Edges => "edges",
+            Current::Terminal(description) => description,
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map(|(_, offset)| *offset)
+            .unwrap_or(self.end)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, Error> {
+        Err(Error::Syntax {
+            position: self.offset(),
+            message: message.into(),
+        })
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token, description: &str) -> Result<(), Error> {
+        if self.eat(&token) {
+            Ok(())
+        } else {
+            self.error(format!("expected {}", description))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.position += 1;
+                Ok(ident)
+            }
+            _ => self.error("expected an identifier"),
+        }
+    }
+
+    fn traversal(&mut self) -> Result<Traversal, Error> {
+        if self.ident()? != "g" {
+            self.position -= 1;
+            return self.error("traversals must start with g");
+        }
+        self.expect(Token::Dot, "'.'")?;
+        let position = self.offset();
+        let name = self.ident()?;
+        let start = match name.as_str() {
+            "V" => Start::Vertices,
+            "E" => Start::Edges,
+            _ => {
+                return Err(Error::UnsupportedStep {
+                    step: name,
+                    position,
+                });
+            }
+        };
+        if !self.arguments(&name)?.is_empty() {
+            return Err(Error::Arguments {
+                step: name,
+                message: "element ids are not supported".to_string(),
+            });
+        }
+
+        let mut current = match start {
+            Start::Vertices => Current::Vertices,
+            Start::Edges => Current::Edges,
+        };
+        let mut steps = Vec::new();
+        let mut terminal = Terminal::Elements;
+        while self.eat(&Token::Dot) {
+            let position = self.offset();
+            let name = self.ident()?;
+            if !STEPS.contains(&name.as_str()) {
+                return Err(Error::UnsupportedStep {
+                    step: name,
+                    position,
+                });
+            }
+            let arguments = self.arguments(&name)?;
+            let args = Arguments {
+                step: &name,
+                arguments,
+            };
+            let misplaced = |current: Current| {
+                Err(Error::Misplaced {
+                    step: name.clone(),
+                    current: current.description(),
+                })
+            };
+
+            let (step, next) = match (name.as_str(), current) {
+                ("toList", _) => {
+                    args.none()?;
+                    continue;
+                }
+                (_, Current::Terminal(_)) => return misplaced(current),
+                ("hasLabel", _) => (Step::HasLabel(args.strings(1)?), current),
+                ("has", _) => (args.has()?, current),
+                ("limit", _) => (Step::Limit(args.count()?), current),
+                ("dedup", _) => {
+                    args.none()?;
+                    (Step::Dedup, current)
+                }
+                ("out", Current::Vertices) => (
+                    args.movement(Direction::Outgoing, Some(End::Head))?,
+                    Current::Vertices,
+                ),
+                ("in", Current::Vertices) => (
+                    args.movement(Direction::Incoming, Some(End::Tail))?,
+                    Current::Vertices,
+                ),
+                ("outE", Current::Vertices) => {
+                    (args.movement(Direction::Outgoing, None)?, Current::Edges)
+                }
+                ("inE", Current::Vertices) => {
+                    (args.movement(Direction::Incoming, None)?, Current::Edges)
+                }
+                ("bothE", Current::Vertices) => {
+                    (args.movement(Direction::All, None)?, Current::Edges)
+                }
+                ("outV", Current::Edges) => {
+                    args.none()?;
+                    (Step::End(End::Tail), Current::Vertices)
+                }
+                ("inV", Current::Edges) => {
+                    args.none()?;
+                    (Step::End(End::Head), Current::Vertices)
+                }
+                ("out" | "in" | "outE" | "inE" | "bothE" | "outV" | "inV", _) => {
+                    return misplaced(current);
+                }
+                ("count", _) => {
+                    args.none()?;
+                    terminal = Terminal::Count;
+                    current = Current::Terminal("a count");
+                    continue;
+                }
+                ("id", _) => {
+                    args.none()?;
+                    current = Current::Terminal("ids");
+                    continue;
+                }
+                ("label", _) => {
+                    args.none()?;
+                    terminal = Terminal::Label;
+                    current = Current::Terminal("labels");
+                    continue;
+                }
+                ("values", _) => {
+                    terminal = Terminal::Values(args.strings(1)?);
+                    current = Current::Terminal("values");
+                    continue;
+                }
+                _ => unreachable!("steps are checked against STEPS"),
+            };
+            steps.push(step);
+            current = next;
+        }
+
+        if self.peek().is_some() {
+            return self.error("expected '.'");
+        }
+        Ok(Traversal {
+            start,
+            steps,
+            terminal,
+        })
+    }
+
+    /// Parses the parenthesised arguments of a step.
+    fn arguments(&mut self, step: &str) -> Result<Vec<Argument>, Error> {
+        self.expect(Token::LParen, "'('")?;
+        let mut arguments = Vec::new();
+        if self.eat(&Token::RParen) {
+            return Ok(arguments);
+        }
+        loop {
+            arguments.push(self.argument(step)?);
+            if !self.eat(&Token::Comma) {
+                break;
+            }
+        }
+        self.expect(Token::RParen, "')'")?;
+        Ok(arguments)
+    }
+
+    fn argument(&mut self, step: &str) -> Result<Argument, Error> {
+        let position = self.offset();
+        let literal = match self.peek() {
+            Some(Token::Str(value)) => Literal::Str(value.clone()),
+            Some(Token::Int(value)) => Literal::Int(*value),
+            Some(Token::Float(value)) => Literal::Float(*value),
+            Some(Token::Ident(ident)) if ident == "true" => Literal::Bool(true),
+            Some(Token::Ident(ident)) if ident == "false" => Literal::Bool(false),
+            Some(Token::Ident(_)) => {
+                let mut name = self.ident()?;
+                // Predicates may be written as P.gt(1) or gt(1)
+                if name == "P" && self.eat(&Token::Dot) {
+                    name = self.ident()?;
+                }
+                let mut arguments = self.arguments(step)?.into_iter();
+                let mut literal = || match arguments.next() {
+                    Some(Argument::Literal(literal)) => Ok(literal),
+                    _ => Err(Error::Arguments {
+                        step: step.to_string(),
+                        message: format!("{} takes literal values", name),
+                    }),
+                };
+                let test = match name.as_str() {
+                    "eq" => Test::Eq(literal()?),
+                    "neq" => Test::Neq(literal()?),
+                    "lt" => Test::Lt(literal()?),
+                    "lte" => Test::Lte(literal()?),
+                    "gt" => Test::Gt(literal()?),
+                    "gte" => Test::Gte(literal()?),
+                    "between" => Test::Between(literal()?, literal()?),
+                    _ => {
+                        return Err(Error::UnsupportedStep {
+                            step: name,
+                            position,
+                        });
+                    }
+                };
+                if arguments.next().is_some() {
+                    return Err(Error::Arguments {
+                        step: step.to_string(),
+                        message: format!("too many arguments to {}", name),
+                    });
+                }
+                return Ok(Argument::Test(test));
+            }
+            _ => return self.error("expected a value"),
+        };
+        self.position += 1;
+        Ok(Argument::Literal(literal))
+    }
+}
+
+enum Argument {
+    Literal(Literal),
+    Test(Test),
+}
+
+/// The arguments of a step, with helpers to check them against what the step expects.
+struct Arguments<'a> {
+    step: &'a str,
+    arguments: Vec<Argument>,
+}
+
+impl Arguments<'_> {
+    fn invalid<T>(&self, message: &str) -> Result<T, Error> {
+        Err(Error::Arguments {
+            step: self.step.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    fn none(&self) -> Result<(), Error> {
+        if self.arguments.is_empty() {
+            Ok(())
+        } else {
+            self.invalid("expected no arguments")
+        }
+    }
+
+    fn strings(&self, minimum: usize) -> Result<Vec<String>, Error> {
+        if self.arguments.len() < minimum {
+            return self.invalid("expected at least one string");
+        }
+        self.arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Literal(Literal::Str(value)) => Ok(value.clone()),
+                _ => self.invalid("expected strings"),
+            })
+            .collect()
+    }
+
+    fn count(&self) -> Result<usize, Error> {
+        match self.arguments.as_slice() {
+            [Argument::Literal(Literal::Int(value))] if *value >= 0 => Ok(*value as usize),
+            _ => self.invalid("expected a non-negative integer"),
+        }
+    }
+
+    fn movement(&self, direction: Direction, end: Option<End>) -> Result<Step, Error> {
+        Ok(Step::Move {
+            direction,
+            labels: self.strings(0)?,
+            end,
+        })
+    }
+
+    fn has(&self) -> Result<Step, Error> {
+        let test = |argument: &Argument| match argument {
+            Argument::Literal(literal) => Test::Eq(literal.clone()),
+            Argument::Test(test) => test.clone(),
+        };
+        let string = |argument: &Argument| match argument {
+            Argument::Literal(Literal::Str(value)) => Ok(value.clone()),
+            _ => self.invalid("expected a property name"),
+        };
+        Ok(match self.arguments.as_slice() {
+            [property] => Step::Has {
+                label: None,
+                property: string(property)?,
+                test: None,
+            },
+            [property, value] => Step::Has {
+                label: None,
+                property: string(property)?,
+                test: Some(test(value)),
+            },
+            [label, property, value] => Step::Has {
+                label: Some(string(label)?),
+                property: string(property)?,
+                test: Some(test(value)),
+            },
+            _ => return self.invalid("expected one to three arguments"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use crate::ast::{End, Literal, Start, Step, Terminal, Test};
+    use crate::parser::parse;
+    use graph_api_lib::Direction;
+
+    #[test]
+    fn parse_traversal() {
+        let traversal = parse(
+            "g.V().hasLabel('Person').has('age', P.gt(40)).out('Knows').dedup().limit(2).values('name')",
+        )
+        .unwrap();
+        assert_eq!(traversal.start, Start::Vertices);
+        assert_eq!(
+            traversal.steps,
+            vec![
+                Step::HasLabel(vec!["Person".to_string()]),
+                Step::Has {
+                    label: None,
+                    property: "age".to_string(),
+                    test: Some(Test::Gt(Literal::Int(40))),
+                },
+                Step::Move {
+                    direction: Direction::Outgoing,
+                    labels: vec!["Knows".to_string()],
+                    end: Some(End::Head),
+                },
+                Step::Dedup,
+                Step::Limit(2),
+            ]
+        );
+        assert_eq!(
+            traversal.terminal,
+            Terminal::Values(vec!["name".to_string()])
+        );
+
+        let traversal = parse("g.E().has('since', 1999).outV().count()").unwrap();
+        assert_eq!(traversal.start, Start::Edges);
+        assert_eq!(traversal.steps.len(), 2);
+        assert_eq!(traversal.terminal, Terminal::Count);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            parse("g.V().repeat(out())"),
+            Err(Error::UnsupportedStep { step, position: 6 }) if step == "repeat"
+        ));
+        assert!(matches!(
+            parse("g.V().has('age', within(1, 2))"),
+            Err(Error::UnsupportedStep { step, .. }) if step == "within"
+        ));
+        assert_eq!(
+            parse("g.V().count().out()"),
+            Err(Error::Misplaced {
+                step: "out".to_string(),
+                current: "a count",
+            })
+        );
+        assert_eq!(
+            parse("g.E().out()"),
+            Err(Error::Misplaced {
+                step: "out".to_string(),
+                current: "edges",
+            })
+        );
+        assert!(matches!(
+            parse("g.V().limit('a')"),
+            Err(Error::Arguments { .. })
+        ));
+        assert!(matches!(parse("g.V(1)"), Err(Error::Arguments { .. })));
+        assert!(matches!(parse("g.V().out("), Err(Error::Syntax { .. })));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::Error;
+use crate::ast::{self, End, Start, Terminal};
+use crate::parser::parse;
+use graph_api_lib::plan::Capabilities;
+use graph_api_lib::query::{self, Predicate, Query, Step};
+use graph_api_lib::{
+    EdgeReference, Element, ElementId, Graph, Label, OwnedValue, Value, VertexReference,
+};
+use std::str::FromStr;
+
+type VertexLabel<G> = <<G as Graph>::Vertex as Element>::Label;
+type EdgeLabel<G> = <<G as Graph>::Edge as Element>::Label;
+
+/// A parsed Gremlin traversal that can be executed against any graph.
+///
+/// Traversals are compiled to a [`Query`], which matches labels against [`Label::name`] and
+/// reads properties with [`Element::read`].
+#[derive(Debug, Clone)]
+pub struct Traversal {
+    traversal: ast::Traversal,
+}
+
+impl FromStr for Traversal {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Traversal::parse(text)
+    }
+}
+
+impl Traversal {
+    /// Parses a traversal such as `g.V().hasLabel('Person').out('Knows').values('name')`.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        parse(text).map(|traversal| Traversal { traversal })
+    }
+
+    /// Runs the traversal against a graph.
+    ///
+    /// Leading `hasLabel` and `has` steps are turned into a label or index lookup when the
+    /// capabilities say the graph supports it, otherwise the graph is scanned.
+    ///
+    /// Fails if the traversal refers to labels that the graph doesn't have.
+    pub fn execute<G>(&self, graph: &G, capabilities: &Capabilities) -> Result<Output<G>, Error>
+    where
+        G: Graph,
+    {
+        let query = self.compile::<G>()?;
+        let projection = match &self.traversal.terminal {
+            Terminal::Elements | Terminal::Count => None,
+            Terminal::Label => Some(Projection::Label),
+            Terminal::Values(properties) => Some(Projection::Properties(properties)),
+        };
+        let output = query.execute(graph, capabilities)?;
+        let Some(projection) = projection else {
+            return Ok(match output {
+                query::Output::Vertices(vertices) => Output::Vertices(vertices),
+                query::Output::Edges(edges) => Output::Edges(edges),
+                query::Output::Count(count) => Output::Count(count),
+                query::Output::Rows(_) => unreachable!("traversals collect or count"),
+            });
+        };
+        let elements: Vec<ElementId<G>> = match output {
+            query::Output::Vertices(vertices) => {
+                vertices.into_iter().map(ElementId::Vertex).collect()
+            }
+            query::Output::Edges(edges) => edges.into_iter().map(ElementId::Edge).collect(),
+            query::Output::Count(_) | query::Output::Rows(_) => {
+                unreachable!("traversals with a projection collect")
+            }
+        };
+        let mut values = Vec::new();
+        for element in elements {
+            match element {
+                ElementId::Vertex(id) => {
+                    if let Some(vertex) = graph.vertex(id) {
+                        projection.project(vertex.weight(), &mut values);
+                    }
+                }
+                ElementId::Edge(id) => {
+                    if let Some(edge) = graph.edge(id) {
+                        projection.project(edge.weight(), &mut values);
+                    }
+                }
+            }
+        }
+        Ok(Output::Values(Values { values }))
+    }
+
+    /// Compiles the traversal to a query. Labels are resolved against the graph first, as
+    /// `hasLabel` can name vertex and edge labels alike.
+    fn compile<G>(&self) -> Result<Query, Error>
+    where
+        G: Graph,
+    {
+        let (start, mut on_vertices) = match self.traversal.start {
+            Start::Vertices => (query::Start::Scan, true),
+            Start::Edges => (query::Start::Edges, false),
+        };
+        let mut steps = Vec::new();
+        for step in &self.traversal.steps {
+            match step {
+                ast::Step::HasLabel(names) => steps.push(has_label::<G>(names, on_vertices)?),
+                ast::Step::Has {
+                    label,
+                    property,
+                    test,
+                } => {
+                    if let Some(label) = label {
+                        steps.push(has_label::<G>(std::slice::from_ref(label), on_vertices)?);
+                    }
+                    steps.push(Step::Filter {
+                        property: property.clone(),
+                        predicate: test
+                            .as_ref()
+                            .map_or(Predicate::Exists, ast::Test::predicate),
+                    });
+                }
+                ast::Step::Move {
+                    direction,
+                    labels,
+                    end,
+                } => {
+                    steps.push(Step::Edges {
+                        label: match labels.as_slice() {
+                            [label] => Some(label.clone()),
+                            _ => None,
+                        },
+                        direction: *direction,
+                    });
+                    if labels.len() > 1 {
+                        steps.push(Step::FilterLabels(labels.clone()));
+                    }
+                    match end {
+                        Some(End::Head) => steps.push(Step::Head),
+                        Some(End::Tail) => steps.push(Step::Tail),
+                        None => on_vertices = false,
+                    }
+                }
+                ast::Step::End(end) => {
+                    steps.push(match end {
+                        End::Head => Step::Head,
+                        End::Tail => Step::Tail,
+                    });
+                    on_vertices = true;
+                }
+                ast::Step::Limit(limit) => steps.push(Step::Take(*limit)),
+                ast::Step::Dedup => steps.push(Step::Dedup),
+            }
+        }
+        Ok(Query {
+            start,
+            steps,
+            terminal: match self.traversal.terminal {
+                Terminal::Count => query::Terminal::Count,
+                _ => query::Terminal::Collect,
+            },
+        })
+    }
+}
+
+/// Gremlin's `hasLabel`, which keeps elements with any of the labels. A name can be a vertex
+/// label, an edge label or both, so the names that aren't labels of the elements the traversal
+/// is on are left out rather than rejected.
+fn has_label<G>(names: &[String], on_vertices: bool) -> Result<Step, Error>
+where
+    G: Graph,
+{
+    let mut labels = Vec::new();
+    for name in names {
+        let vertex = VertexLabel::<G>::variants()
+            .iter()
+            .any(|label| label.name() == name.as_str());
+        let edge = EdgeLabel::<G>::variants()
+            .iter()
+            .any(|label| label.name() == name.as_str());
+        if !vertex && !edge {
+            return Err(Error::UnknownLabel(name.clone()));
+        }
+        if (on_vertices && vertex) || (!on_vertices && edge) {
+            labels.push(name.clone());
+        }
+    }
+    Ok(Step::FilterLabels(labels))
+}
+
+/// The result of a traversal.
+pub enum Output<G>
+where
+    G: Graph,
+{
+    /// The vertices the traversal ended on, for traversals that end on vertices or with `id()`.
+    Vertices(Vec<G::VertexId>),
+    /// The edges the traversal ended on, for traversals that end on edges or with `id()`.
+    Edges(Vec<G::EdgeId>),
+    /// The result of `count()`.
+    Count(usize),
+    /// The result of `values(...)` or `label()`.
+    Values(Values),
+}
+
+/// The values of a `values(...)` or `label()` step, in traversal order.
+///
+/// Values are copied out of the graph, so they can be kept after the graph is changed. As in
+/// Gremlin, elements that don't have a property are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Values {
+    values: Vec<OwnedValue>,
+}
+
+impl Values {
+    /// The number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over the values in traversal order.
+    pub fn iter(&self) -> std::slice::Iter<'_, OwnedValue> {
+        self.values.iter()
+    }
+}
+
+impl IntoIterator for Values {
+    type Item = OwnedValue;
+    type IntoIter = std::vec::IntoIter<OwnedValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Values {
+    type Item = &'a OwnedValue;
+    type IntoIter = std::slice::Iter<'a, OwnedValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+/// What a traversal ending in `label()` or `values(...)` reads from each element.
+enum Projection<'t> {
+    Label,
+    Properties(&'t [String]),
+}
+
+impl Projection<'_> {
+    fn project<E>(&self, element: &E, values: &mut Vec<OwnedValue>)
+    where
+        E: Element,
+    {
+        match self {
+            Projection::Label => values.push(OwnedValue::from(Value::Str(element.label().name()))),
+            Projection::Properties(properties) => values.extend(
+                properties
+                    .iter()
+                    .filter_map(|property| element.read(property))
+                    .map(OwnedValue::from),
+            ),
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! CSV import and export, with one file per label.
+//!
+//! The columns of a vertex file are the fields of its label, as generated by the derive macros
+//! and returned by [`header`]. Edge files start with `from` and `to` columns that hold a key
+//! of the tail and head vertex, such as a `unique_id`, followed by the fields of the edge
+//! label. When reading, the keys are looked up in a vertex hash index, so the vertices must be
+//! loaded before the edges that join them.
+//!
+//! ```rust
+//! use graph_api_io::csv;
+//! use graph_api_simplegraph::SimpleGraph;
+//! use graph_api_test::{Edge, EdgeLabel, Vertex, VertexIndex, VertexLabel};
+//!
+//! let people = "\
+//! name,age,unique_id,username,biography
+//! Bryn,45,00000000-0000-0000-0000-000000000001,bryn,Did some graph stuff
+//! Julia,48,00000000-0000-0000-0000-000000000002,julia,Mastered the English language
+//! ";
+//! let knows = "\
+//! from,to,since
+//! 00000000-0000-0000-0000-000000000001,00000000-0000-0000-0000-000000000002,1999
+//! ";
+//!
+//! let mut graph = SimpleGraph::<Vertex, Edge>::new();
+//! csv::read_vertices(&mut graph, VertexLabel::Person, people.as_bytes()).unwrap();
+//! csv::read_edges(
+//!     &mut graph,
+//!     EdgeLabel::Knows,
+//!     VertexIndex::PersonUniqueId,
+//!     VertexIndex::PersonUniqueId,
+//!     knows.as_bytes(),
+//! )
+//! .unwrap();
+//!
+//! // And back again
+//! let mut output = Vec::new();
+//! csv::write_vertices(&graph, VertexLabel::Person, &mut output).unwrap();
+//! ```
+
+use crate::Error;
+use graph_api_lib::{
+    EdgeReference, EdgeSearch, Element, FieldError, Graph, Index, Label, SupportsVertexHashIndex,
+    Value, VertexReference, VertexSearch,
+};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+/// The column that holds the key of an edge's tail vertex.
+pub const FROM: &str = "from";
+
+/// The column that holds the key of an edge's head vertex.
+pub const TO: &str = "to";
+
+type VertexLabel<G> = <<G as Graph>::Vertex as Element>::Label;
+type VertexIndex<G> = <VertexLabel<G> as Label>::Index;
+type EdgeLabel<G> = <<G as Graph>::Edge as Element>::Label;
+
+/// Returns the columns of a vertex file for a label, or the columns after `from` and `to` in
+/// an edge file.
+pub fn header<L>(label: L) -> Vec<&'static str>
+where
+    L: Label,
+{
+    label.fields().iter().map(|field| field.name).collect()
+}
+
+/// Writes every vertex with a label as CSV.
+pub fn write_vertices<G, W>(graph: &G, label: VertexLabel<G>, writer: W) -> Result<(), Error>
+where
+    G: Graph,
+    W: Write,
+{
+    let mut writer = ::csv::Writer::from_writer(writer);
+    writer.write_record(header(label)).map_err(csv_error)?;
+    for vertex in graph.vertices(&VertexSearch::scan()) {
+        let weight = vertex.weight();
+        if weight.label() != label {
+            continue;
+        }
+        writer
+            .write_record(row(weight, label.fields()))
+            .map_err(csv_error)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes every edge with a label as CSV, identifying the tail and head of each edge by the
+/// value of an index.
+///
+/// Every tail vertex must have a value for `from` and every head vertex for `to`.
+pub fn write_edges<G, W>(
+    graph: &G,
+    label: EdgeLabel<G>,
+    from: VertexIndex<G>,
+    to: VertexIndex<G>,
+    writer: W,
+) -> Result<(), Error>
+where
+    G: Graph,
+    W: Write,
+{
+    let key = |id: G::VertexId, index: VertexIndex<G>| -> Result<String, Error> {
+        let vertex = graph
+            .vertex(id)
+            .ok_or_else(|| Error::UnknownVertex(format!("{:?}", id)))?;
+        let weight = vertex.weight();
+        weight
+            .value(&index)
+            .map(|value| value.to_string())
+            .ok_or_else(|| Error::Field {
+                id: format!("{:?}", id),
+                source: FieldError::Missing {
+                    label: weight.label().name(),
+                    field: index.name(),
+                },
+            })
+    };
+
+    let mut writer = ::csv::Writer::from_writer(writer);
+    let mut columns = vec![FROM, TO];
+    columns.extend(header(label));
+    writer.write_record(columns).map_err(csv_error)?;
+    for vertex in graph.vertices(&VertexSearch::scan()) {
+        for edge in graph.edges(vertex.id(), &EdgeSearch::scan().outgoing()) {
+            let weight = edge.weight();
+            if weight.label() != label {
+                continue;
+            }
+            let mut record = vec![key(edge.tail(), from)?, key(edge.head(), to)?];
+            record.extend(row(weight, label.fields()));
+            writer.write_record(record).map_err(csv_error)?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads vertices with a label from CSV, creating each one with
+/// [`Element::from_fields`](graph_api_lib::Element::from_fields).
+///
+/// The first row names the columns. Returns the ids of the new vertices in row order.
+pub fn read_vertices<G, R>(
+    graph: &mut G,
+    label: VertexLabel<G>,
+    reader: R,
+) -> Result<Vec<G::VertexId>, Error>
+where
+    G: Graph,
+    R: Read,
+{
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let columns = read_header(&mut reader)?;
+    let mut vertices = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let field = |name: &str| columns.get(name).and_then(|column| record.get(*column));
+        let vertex =
+            G::Vertex::from_fields(label, &field).map_err(|error| row_error(&record, error))?;
+        vertices.push(graph.add_vertex(vertex));
+    }
+    Ok(vertices)
+}
+
+/// Reads edges with a label from CSV, creating each one with
+/// [`Element::from_fields`](graph_api_lib::Element::from_fields).
+///
+/// The first row names the columns. The `from` and `to` columns are looked up in the `from`
+/// and `to` hash indexes to find the tail and head of each edge, and edges are checked with
+/// [`Graph::try_add_edge`]. Returns the ids of the new edges in row order.
+pub fn read_edges<G, R>(
+    graph: &mut G,
+    label: EdgeLabel<G>,
+    from: VertexIndex<G>,
+    to: VertexIndex<G>,
+    reader: R,
+) -> Result<Vec<G::EdgeId>, Error>
+where
+    G: Graph + SupportsVertexHashIndex,
+    R: Read,
+{
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let columns = read_header(&mut reader)?;
+    let mut edges = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let field = |name: &str| columns.get(name).and_then(|column| record.get(*column));
+        let endpoint = |column: &'static str, index: VertexIndex<G>| {
+            let key = field(column).ok_or_else(|| FieldError::Missing {
+                label: label.name(),
+                field: column,
+            });
+            let key = key.map_err(|error| row_error(&record, error))?;
+            let id = Value::parse(key, index.ty())
+                .and_then(|value| {
+                    graph
+                        .vertices(&VertexSearch::get(index, value))
+                        .next()
+                        .map(|vertex| vertex.id())
+                })
+                .ok_or_else(|| row_error(&record, Error::UnknownVertex(key.to_string())))?;
+            Ok::<_, Error>(id)
+        };
+        let tail = endpoint(FROM, from)?;
+        let head = endpoint(TO, to)?;
+        let edge =
+            G::Edge::from_fields(label, &field).map_err(|error| row_error(&record, error))?;
+        let id = graph
+            .try_add_edge(tail, head, edge)
+            .map_err(|error| row_error(&record, error))?;
+        edges.push(id);
+    }
+    Ok(edges)
+}
+
+fn read_header<R>(reader: &mut ::csv::Reader<R>) -> Result<HashMap<String, usize>, Error>
+where
+    R: Read,
+{
+    Ok(reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .enumerate()
+        .map(|(column, name)| (name.to_string(), column))
+        .collect())
+}
+
+fn row<E>(element: &E, fields: &[graph_api_lib::schema::FieldSchema]) -> Vec<String>
+where
+    E: Element,
+{
+    fields
+        .iter()
+        .map(|field| {
+            element
+                .property(field.name)
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn row_error(
+    record: &::csv::StringRecord,
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> Error {
+    Error::Row {
+        line: record
+            .position()
+            .map(|position| position.line())
+            .unwrap_or_default(),
+        source: Box::new(error),
+    }
+}
+
+fn csv_error(error: ::csv::Error) -> Error {
+    let message = error.to_string();
+    match error.into_kind() {
+        ::csv::ErrorKind::Io(error) => Error::Io(error),
+        _ => Error::Syntax(message),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use crate::csv::{read_edges, read_vertices, write_edges, write_vertices};
+    use graph_api_lib::{AddEdgeError, EdgeSearch, FieldError, Graph};
+    use graph_api_simplegraph::SimpleGraph;
+    use graph_api_test::{Edge, EdgeLabel, Vertex, VertexIndex, VertexLabel, populate_graph};
+
+    const PEOPLE: &str = "\
+name,age,unique_id,username,biography
+Bryn,45,00000000-0000-0000-0000-000000000001,bryn,Did some graph stuff
+Julia,48,00000000-0000-0000-0000-000000000002,julia,Mastered the English language
+";
+
+    fn people() -> SimpleGraph<Vertex, Edge> {
+        let mut graph = SimpleGraph::new();
+        read_vertices(&mut graph, VertexLabel::Person, PEOPLE.as_bytes())
+            .expect("people must be read");
+        graph
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut graph = SimpleGraph::new();
+        populate_graph(&mut graph);
+
+        let mut people = Vec::new();
+        write_vertices(&graph, VertexLabel::Person, &mut people).expect("people must be written");
+        assert_eq!(String::from_utf8(people.clone()).unwrap(), PEOPLE);
+        let mut knows = Vec::new();
+        write_edges(
+            &graph,
+            EdgeLabel::Knows,
+            VertexIndex::PersonUniqueId,
+            VertexIndex::PersonUniqueId,
+            &mut knows,
+        )
+        .expect("knows must be written");
+
+        let mut copy = SimpleGraph::<Vertex, Edge>::new();
+        let vertices = read_vertices(&mut copy, VertexLabel::Person, people.as_slice())
+            .expect("people must be read");
+        assert_eq!(vertices.len(), 2);
+        let edges = read_edges(
+            &mut copy,
+            EdgeLabel::Knows,
+            VertexIndex::PersonUniqueId,
+            VertexIndex::PersonUniqueId,
+            knows.as_slice(),
+        )
+        .expect("knows must be read");
+        assert_eq!(edges.len(), 2);
+        assert_eq!(
+            copy.walk()
+                .vertices(Vertex::person_by_name("Bryn"))
+                .edges(EdgeSearch::scan().outgoing())
+                .head()
+                .collect::<Vec<_>>(),
+            copy.walk()
+                .vertices(Vertex::person_by_name("Julia"))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn row_errors() {
+        let mut graph = SimpleGraph::<Vertex, Edge>::new();
+        let error = read_vertices(
+            &mut graph,
+            VertexLabel::Person,
+            "name,age,unique_id,username,biography\n\
+             Bryn,45,00000000-0000-0000-0000-000000000001,bryn,\n\
+             Julia,old,00000000-0000-0000-0000-000000000002,julia,\n"
+                .as_bytes(),
+        );
+        match error {
+            Err(Error::Row { line: 3, source }) => assert_eq!(
+                source.downcast_ref::<FieldError>(),
+                Some(&FieldError::Invalid {
+                    label: "Person",
+                    field: "age",
+                    value: "old".to_string()
+                })
+            ),
+            result => panic!("unexpected result {:?}", result),
+        }
+
+        let mut graph = people();
+        let error = read_edges(
+            &mut graph,
+            EdgeLabel::Knows,
+            VertexIndex::PersonUniqueId,
+            VertexIndex::PersonUniqueId,
+            "from,to,since\n\
+             00000000-0000-0000-0000-000000000001,00000000-0000-0000-0000-000000000003,1999\n"
+                .as_bytes(),
+        );
+        match error {
+            Err(Error::Row { line: 2, source }) => assert!(matches!(
+                source.downcast_ref::<Error>(),
+                Some(Error::UnknownVertex(key)) if key == "00000000-0000-0000-0000-000000000003"
+            )),
+            result => panic!("unexpected result {:?}", result),
+        }
+
+        // People can't create people
+        let error = read_edges(
+            &mut graph,
+            EdgeLabel::Created,
+            VertexIndex::PersonName,
+            VertexIndex::PersonName,
+            "from,to\nBryn,Julia\n".as_bytes(),
+        );
+        match error {
+            Err(Error::Row { line: 2, source }) => assert!(matches!(
+                source.downcast_ref::<AddEdgeError>(),
+                Some(AddEdgeError::InvalidEndpoints { .. })
+            )),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
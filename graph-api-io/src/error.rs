@@ -0,0 +1,42 @@
+use graph_api_lib::{AddEdgeError, FieldError};
+use thiserror::Error;
+
+/// Errors that can occur when importing or exporting a graph.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// The input is not valid in the format being read.
+    #[error("invalid input: {0}")]
+    Syntax(String),
+
+    /// A vertex or edge label that the graph does not have.
+    #[error("unknown label {0}")]
+    UnknownLabel(String),
+
+    /// A vertex or edge without a label.
+    #[error("{0} has no label")]
+    MissingLabel(String),
+
+    /// An edge refers to a vertex that is not in the input.
+    #[error("unknown vertex {0}")]
+    UnknownVertex(String),
+
+    /// A vertex or edge whose fields couldn't be read.
+    #[error("{id}: {source}")]
+    Field { id: String, source: FieldError },
+
+    /// An edge that the graph wouldn't accept.
+    #[error("{id}: {source}")]
+    Edge { id: String, source: AddEdgeError },
+
+    /// A row of a file that couldn't be read, with its line number.
+    #[error("line {line}: {source}")]
+    Row {
+        line: u64,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
@@ -0,0 +1,548 @@
+//! [GraphML](http://graphml.graphdrawing.org/) import and export.
+//!
+//! Each vertex is written as a `node` and each edge as a directed `edge`. The label name is
+//! stored in the `labelV` and `labelE` attributes, as TinkerPop does, and every field that
+//! converts to a `Value` is stored as an attribute with the field's name.
+//!
+//! ```rust
+//! use graph_api_io::{from_fields, graphml};
+//! use graph_api_lib::Graph;
+//! use graph_api_simplegraph::SimpleGraph;
+//! use graph_api_test::{Edge, Language, Project, Vertex, VertexExt, populate_graph};
+//!
+//! let mut graph = SimpleGraph::new();
+//! populate_graph(&mut graph);
+//!
+//! let mut xml = Vec::new();
+//! graphml::write(&graph, &mut xml).unwrap();
+//!
+//! // Projects and languages are tuple variants, which the derive can't create from fields
+//! let mut copy = SimpleGraph::<Vertex, Edge>::new();
+//! graphml::read_with(
+//!     &mut copy,
+//!     xml.as_slice(),
+//!     |label, field| match label {
+//!         "Project" => Ok(Some(Vertex::Project(Project {
+//!             name: field("name").unwrap_or_default().to_string(),
+//!         }))),
+//!         label => from_fields(label, field),
+//!     },
+//!     |label, field| match label {
+//!         "Language" => Ok(Some(Edge::Language(Language {
+//!             name: "Rust".to_string(),
+//!         }))),
+//!         label => from_fields(label, field),
+//!     },
+//! )
+//! .unwrap();
+//! assert_eq!(copy.walk().vertices(Vertex::person_by_name("Bryn")).count(), 1);
+//! ```
+
+use crate::{Error, from_fields};
+use graph_api_lib::schema::Schema;
+use graph_api_lib::{
+    EdgeReference, EdgeSearch, Element, FieldError, Graph, Label, VertexReference, VertexSearch,
+};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+
+/// The attribute that holds the label of a vertex.
+pub const VERTEX_LABEL: &str = "labelV";
+
+/// The attribute that holds the label of an edge.
+pub const EDGE_LABEL: &str = "labelE";
+
+/// Writes a graph as GraphML.
+pub fn write<G, W>(graph: &G, mut writer: W) -> Result<(), Error>
+where
+    G: Graph,
+    W: Write,
+{
+    let schema = Schema::of::<G>();
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <key id="{VERTEX_LABEL}" for="node" attr.name="{VERTEX_LABEL}" attr.type="string"/>"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <key id="{EDGE_LABEL}" for="edge" attr.name="{EDGE_LABEL}" attr.type="string"/>"#
+    )?;
+    let mut declared = Vec::new();
+    for (domain, prefix, labels) in [
+        ("node", "v", &schema.vertices),
+        ("edge", "e", &schema.edges),
+    ] {
+        for field in labels.iter().flat_map(|label| label.fields) {
+            if declared.contains(&(domain, field.name)) {
+                continue;
+            }
+            declared.push((domain, field.name));
+            writeln!(
+                writer,
+                r#"  <key id="{}_{}" for="{}" attr.name="{}" attr.type="{}"/>"#,
+                prefix,
+                escape(field.name),
+                domain,
+                escape(field.name),
+                attribute_type(field.ty)
+            )?;
+        }
+    }
+
+    writeln!(writer, r#"  <graph id="G" edgedefault="directed">"#)?;
+    let mut nodes = HashMap::new();
+    for (node, vertex) in graph.vertices(&VertexSearch::scan()).enumerate() {
+        nodes.insert(vertex.id(), node);
+        write!(writer, r#"    <node id="n{}">"#, node)?;
+        write_data(&mut writer, vertex.weight(), VERTEX_LABEL, "v")?;
+        writeln!(writer, "</node>")?;
+    }
+    let mut edge_count = 0;
+    for vertex in graph.vertices(&VertexSearch::scan()) {
+        for edge in graph.edges(vertex.id(), &EdgeSearch::scan().outgoing()) {
+            let (Some(source), Some(target)) = (nodes.get(&edge.tail()), nodes.get(&edge.head()))
+            else {
+                continue;
+            };
+            write!(
+                writer,
+                r#"    <edge id="e{}" source="n{}" target="n{}">"#,
+                edge_count, source, target
+            )?;
+            write_data(&mut writer, edge.weight(), EDGE_LABEL, "e")?;
+            writeln!(writer, "</edge>")?;
+            edge_count += 1;
+        }
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    Ok(())
+}
+
+/// Reads GraphML into a graph, creating vertices and edges with [`from_fields`].
+///
+/// Returns the vertex that was added for each GraphML node id.
+pub fn read<G, R>(graph: &mut G, reader: R) -> Result<HashMap<String, G::VertexId>, Error>
+where
+    G: Graph,
+    R: BufRead,
+{
+    read_with(graph, reader, from_fields, from_fields)
+}
+
+/// Reads GraphML into a graph, creating vertices and edges with the given constructors.
+///
+/// Each constructor is passed the label name and a function that returns the text of an
+/// attribute, and returns `Ok(None)` if it doesn't know the label. Node and edge attributes
+/// that are missing take the default of their key, if it has one.
+///
+/// Returns the vertex that was added for each GraphML node id.
+pub fn read_with<G, R, V, E>(
+    graph: &mut G,
+    reader: R,
+    mut vertex: V,
+    mut edge: E,
+) -> Result<HashMap<String, G::VertexId>, Error>
+where
+    G: Graph,
+    R: BufRead,
+    V: for<'a> FnMut(
+        &str,
+        &dyn Fn(&str) -> Option<&'a str>,
+    ) -> Result<Option<G::Vertex>, FieldError>,
+    E: for<'a> FnMut(&str, &dyn Fn(&str) -> Option<&'a str>) -> Result<Option<G::Edge>, FieldError>,
+{
+    let mut reader = Reader::from_reader(reader);
+    reader.config_mut().trim_text(true);
+    let mut buf = Vec::new();
+
+    // Attribute names by key id, and the defaults of keys that have one
+    let mut keys = HashMap::new();
+    let mut node_defaults = HashMap::new();
+    let mut edge_defaults = HashMap::new();
+    let mut key: Option<Key> = None;
+
+    let mut element: Option<Pending> = None;
+    let mut data: Option<(String, String)> = None;
+    let mut edges = Vec::new();
+    let mut ids = HashMap::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buf).map_err(syntax)?;
+        let empty = matches!(event, Event::Empty(_));
+        let ended = match event {
+            Event::Start(start) | Event::Empty(start) => {
+                match start.local_name().as_ref() {
+                    b"key" => {
+                        let id = required(&start, "id")?;
+                        let name = required(&start, "attr.name")?;
+                        keys.insert(id, name.clone());
+                        key = Some(Key {
+                            name,
+                            domain: attribute(&start, "for")?.unwrap_or_else(|| "all".to_string()),
+                        });
+                    }
+                    b"default" if key.is_some() => {
+                        data = Some((String::new(), String::new()));
+                    }
+                    b"node" => {
+                        element = Some(Pending {
+                            id: required(&start, "id")?,
+                            endpoints: None,
+                            data: HashMap::new(),
+                        });
+                    }
+                    b"edge" => {
+                        let id = attribute(&start, "id")?
+                            .unwrap_or_else(|| format!("edge {}", edges.len()));
+                        element = Some(Pending {
+                            id,
+                            endpoints: Some((
+                                required(&start, "source")?,
+                                required(&start, "target")?,
+                            )),
+                            data: HashMap::new(),
+                        });
+                    }
+                    b"data" if element.is_some() => {
+                        let id = required(&start, "key")?;
+                        let name = keys.get(&id).cloned().unwrap_or(id);
+                        data = Some((name, String::new()));
+                    }
+                    _ => {}
+                }
+                // Empty elements have no end event, so they end straight away
+                empty.then(|| String::from_utf8_lossy(start.local_name().as_ref()).into_owned())
+            }
+            Event::End(end) => {
+                Some(String::from_utf8_lossy(end.local_name().as_ref()).into_owned())
+            }
+            Event::Text(text) => {
+                if let Some((_, value)) = &mut data {
+                    value.push_str(&text.unescape().map_err(syntax)?);
+                }
+                None
+            }
+            Event::CData(text) => {
+                if let Some((_, value)) = &mut data {
+                    value.push_str(std::str::from_utf8(&text).map_err(syntax)?);
+                }
+                None
+            }
+            Event::Eof => break,
+            _ => None,
+        };
+        buf.clear();
+
+        let Some(ended) = ended else {
+            continue;
+        };
+        match ended.as_str() {
+            "key" => key = None,
+            "default" => {
+                if let (Some(key), Some((_, value))) = (&key, data.take()) {
+                    if key.domain == "node" || key.domain == "all" {
+                        node_defaults.insert(key.name.clone(), value.clone());
+                    }
+                    if key.domain == "edge" || key.domain == "all" {
+                        edge_defaults.insert(key.name.clone(), value);
+                    }
+                }
+            }
+            "data" => {
+                if let (Some(element), Some((name, value))) = (&mut element, data.take()) {
+                    element.data.insert(name, value);
+                }
+            }
+            "node" => {
+                if let Some(node) = element.take() {
+                    let weight = create(&node, VERTEX_LABEL, &node_defaults, &mut vertex)?;
+                    ids.insert(node.id, graph.add_vertex(weight));
+                }
+            }
+            "edge" => {
+                if let Some(pending) = element.take() {
+                    edges.push(pending);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // GraphML allows edges to come before the nodes that they join
+    for pending in edges {
+        let Some((source, target)) = &pending.endpoints else {
+            continue;
+        };
+        let from = *ids
+            .get(source)
+            .ok_or_else(|| Error::UnknownVertex(source.clone()))?;
+        let to = *ids
+            .get(target)
+            .ok_or_else(|| Error::UnknownVertex(target.clone()))?;
+        let weight = create(&pending, EDGE_LABEL, &edge_defaults, &mut edge)?;
+        graph
+            .try_add_edge(from, to, weight)
+            .map_err(|source| Error::Edge {
+                id: pending.id.clone(),
+                source,
+            })?;
+    }
+    Ok(ids)
+}
+
+/// A `key` element that is being read.
+struct Key {
+    name: String,
+    domain: String,
+}
+
+/// A `node` or `edge` element that is being read, with its attributes by name.
+struct Pending {
+    id: String,
+    endpoints: Option<(String, String)>,
+    data: HashMap<String, String>,
+}
+
+fn create<T>(
+    pending: &Pending,
+    label_attribute: &str,
+    defaults: &HashMap<String, String>,
+    constructor: &mut impl for<'a> FnMut(
+        &str,
+        &dyn Fn(&str) -> Option<&'a str>,
+    ) -> Result<Option<T>, FieldError>,
+) -> Result<T, Error> {
+    let field = |name: &str| {
+        pending
+            .data
+            .get(name)
+            .or_else(|| defaults.get(name))
+            .map(String::as_str)
+    };
+    let label = field(label_attribute).ok_or_else(|| Error::MissingLabel(pending.id.clone()))?;
+    constructor(label, &field)
+        .map_err(|source| Error::Field {
+            id: pending.id.clone(),
+            source,
+        })?
+        .ok_or_else(|| Error::UnknownLabel(label.to_string()))
+}
+
+fn write_data<W, E>(writer: &mut W, element: &E, label_key: &str, prefix: &str) -> Result<(), Error>
+where
+    W: Write,
+    E: Element,
+{
+    let label = element.label();
+    write!(
+        writer,
+        r#"<data key="{}">{}</data>"#,
+        label_key,
+        escape(label.name())
+    )?;
+    for field in label.fields() {
+        if let Some(value) = element.property(field.name) {
+            write!(
+                writer,
+                r#"<data key="{}_{}">{}</data>"#,
+                prefix,
+                escape(field.name),
+                escape(&value.to_string())
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn required(start: &BytesStart, name: &str) -> Result<String, Error> {
+    attribute(start, name)?.ok_or_else(|| {
+        Error::Syntax(format!(
+            "{} is missing attribute {}",
+            String::from_utf8_lossy(start.local_name().as_ref()),
+            name
+        ))
+    })
+}
+
+fn attribute(start: &BytesStart, name: &str) -> Result<Option<String>, Error> {
+    match start.try_get_attribute(name).map_err(syntax)? {
+        Some(attribute) => Ok(Some(
+            attribute.unescape_value().map_err(syntax)?.into_owned(),
+        )),
+        None => Ok(None),
+    }
+}
+
+fn syntax(error: impl Display) -> Error {
+    Error::Syntax(error.to_string())
+}
+
+/// The GraphML type of an attribute, from the Rust type of a field.
+fn attribute_type(ty: &str) -> &'static str {
+    match ty {
+        "bool" => "boolean",
+        "i8" | "i16" | "i32" | "u8" | "u16" => "int",
+        "i64" | "u32" | "u64" | "usize" | "i128" | "u128" => "long",
+        "f32" => "float",
+        "f64" => "double",
+        _ => "string",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graphml::{read, read_with, write};
+    use crate::{Error, from_fields};
+    use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference};
+    use graph_api_simplegraph::SimpleGraph;
+    use graph_api_test::{Edge, Language, Project, Vertex, populate_graph};
+
+    #[test]
+    fn round_trip() {
+        let mut graph = SimpleGraph::new();
+        populate_graph(&mut graph);
+        let mut xml = Vec::new();
+        write(&graph, &mut xml).expect("graph must be written");
+        let xml = String::from_utf8(xml).expect("xml must be utf8");
+        assert!(xml.contains(r#"<key id="v_age" for="node" attr.name="age" attr.type="long"/>"#));
+        assert!(xml.contains(r#"<data key="labelV">Person</data><data key="v_name">Bryn</data>"#));
+
+        let mut copy = SimpleGraph::<Vertex, Edge>::new();
+        let ids = read_with(
+            &mut copy,
+            xml.as_bytes(),
+            |label, field| match label {
+                "Project" => Ok(Some(Vertex::Project(Project {
+                    name: "GraphApi".to_string(),
+                }))),
+                label => from_fields(label, field),
+            },
+            |label, field| match label {
+                "Language" => Ok(Some(Edge::Language(Language {
+                    name: "Rust".to_string(),
+                }))),
+                label => from_fields(label, field),
+            },
+        )
+        .expect("graph must be read");
+        assert_eq!(ids.len(), 4);
+
+        let bryn = copy
+            .walk()
+            .vertices(Vertex::person_by_name("Bryn"))
+            .first()
+            .expect("bryn must exist");
+        match copy.vertex(bryn).expect("bryn must exist").weight() {
+            Vertex::Person { age, username, .. } => {
+                assert_eq!(*age, 45);
+                assert_eq!(username, "bryn");
+            }
+            _ => panic!("bryn must be a person"),
+        }
+        assert_eq!(
+            copy.walk()
+                .vertices_by_id(vec![bryn])
+                .edges(EdgeSearch::scan().outgoing())
+                .count(),
+            2
+        );
+        assert_eq!(copy.walk().vertices(Vertex::rust()).count(), 1);
+    }
+
+    #[test]
+    fn read_other_tools() {
+        // Edges before nodes, key defaults and whitespace, as other tools may write them
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+              <key id="d0" for="node" attr.name="labelV" attr.type="string">
+                <default>Person</default>
+              </key>
+              <key id="d1" for="node" attr.name="name" attr.type="string"/>
+              <key id="d2" for="node" attr.name="age" attr.type="long">
+                <default>30</default>
+              </key>
+              <key id="d3" for="node" attr.name="unique_id" attr.type="string">
+                <default>00000000-0000-0000-0000-000000000000</default>
+              </key>
+              <key id="d4" for="node" attr.name="username" attr.type="string"><default/></key>
+              <key id="d5" for="node" attr.name="biography" attr.type="string"><default/></key>
+              <key id="d6" for="edge" attr.name="labelE" attr.type="string">
+                <default>Knows</default>
+              </key>
+              <key id="d7" for="edge" attr.name="since" attr.type="int"/>
+              <graph edgedefault="directed">
+                <edge source="a" target="b">
+                  <data key="d7">2001</data>
+                </edge>
+                <node id="a"><data key="d1">Alice</data></node>
+                <node id="b"><data key="d1">Bob</data><data key="d2">31</data></node>
+              </graph>
+            </graphml>"#;
+        let mut graph = SimpleGraph::<Vertex, Edge>::new();
+        let ids = read(&mut graph, xml.as_bytes()).expect("graph must be read");
+        assert_eq!(
+            graph.walk().vertices(Vertex::person_by_name("Bob")).first(),
+            Some(ids["b"])
+        );
+        assert!(matches!(
+            graph
+                .edges(ids["a"], &EdgeSearch::scan().outgoing())
+                .next()
+                .map(|edge| edge.weight().clone()),
+            Some(Edge::Knows { since: 2001 })
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        let read_vertex = |node: &str| {
+            let xml = format!(
+                r#"<graphml><key id="labelV" for="node" attr.name="labelV"/><graph>{}</graph></graphml>"#,
+                node
+            );
+            read(&mut SimpleGraph::<Vertex, Edge>::new(), xml.as_bytes())
+        };
+        assert!(matches!(
+            read_vertex(r#"<node id="n0"><data key="labelV">Robot</data></node>"#),
+            Err(Error::UnknownLabel(label)) if label == "Robot"
+        ));
+        assert!(matches!(
+            read_vertex(r#"<node id="n0"/>"#),
+            Err(Error::MissingLabel(id)) if id == "n0"
+        ));
+        assert!(matches!(
+            read_vertex(r#"<node id="n0"><data key="labelV">Person</data></node>"#),
+            Err(Error::Field { id, .. }) if id == "n0"
+        ));
+        assert!(matches!(
+            read_vertex(
+                r#"<node id="n0"><data key="labelV">Rust</data></node><edge source="n0" target="n1"/>"#
+            ),
+            Err(Error::UnknownVertex(id)) if id == "n1"
+        ));
+    }
+}
@@ -0,0 +1,356 @@
+//! [JSON Lines](https://jsonlines.org/) import and export.
+//!
+//! Each line is one vertex or edge record. Vertices are written before edges, and each record
+//! carries its label and id, the ids of its endpoints if it is an edge, and its weight as
+//! `properties`:
+//!
+//! ```text
+//! {"type":"vertex","id":0,"label":"Person","properties":{"Person":{"name":"Bryn"}}}
+//! {"type":"edge","id":0,"label":"Knows","from":0,"to":1,"properties":{"Knows":{"since":1999}}}
+//! ```
+//!
+//! The weights are converted with serde, so the vertex and edge types must implement
+//! `Serialize` to be written and `Deserialize` to be read. Records are written and read one at
+//! a time, so the whole graph is never held as a single string.
+//!
+//! ```rust
+//! use graph_api_derive::{EdgeExt, VertexExt};
+//! use graph_api_io::jsonl;
+//! use graph_api_lib::Graph;
+//! use graph_api_simplegraph::SimpleGraph;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Clone, VertexExt, Serialize, Deserialize)]
+//! enum Vertex {
+//!     Person { name: String },
+//! }
+//!
+//! #[derive(Debug, Clone, EdgeExt, Serialize, Deserialize)]
+//! enum Edge {
+//!     Knows { since: i32 },
+//! }
+//!
+//! fn main() {
+//!     let mut graph = SimpleGraph::new();
+//!     let bryn = graph.add_vertex(Vertex::Person { name: "Bryn".to_string() });
+//!     let julia = graph.add_vertex(Vertex::Person { name: "Julia".to_string() });
+//!     graph.add_edge(bryn, julia, Edge::Knows { since: 1999 });
+//!
+//!     let mut lines = Vec::new();
+//!     jsonl::write(&graph, &mut lines).unwrap();
+//!
+//!     let mut copy = SimpleGraph::<Vertex, Edge>::new();
+//!     let ids = jsonl::read(&mut copy, lines.as_slice()).unwrap();
+//!     assert_eq!(ids.len(), 2);
+//! }
+//! ```
+
+use crate::Error;
+use graph_api_lib::{
+    EdgeReference, EdgeSearch, Element, Graph, Label, VertexReference, VertexSearch,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum WriteRecord<'a, V, E> {
+    Vertex {
+        id: usize,
+        label: &'static str,
+        properties: &'a V,
+    },
+    Edge {
+        id: usize,
+        label: &'static str,
+        from: usize,
+        to: usize,
+        properties: &'a E,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ReadRecord<V, E> {
+    Vertex {
+        id: serde_json::Value,
+        label: Option<String>,
+        properties: V,
+    },
+    Edge {
+        label: Option<String>,
+        from: serde_json::Value,
+        to: serde_json::Value,
+        properties: E,
+    },
+}
+
+/// Writes a graph as JSON Lines, one record at a time.
+///
+/// Vertex and edge ids are numbered from zero in the order that they are written.
+pub fn write<G, W>(graph: &G, mut writer: W) -> Result<(), Error>
+where
+    G: Graph,
+    G::Vertex: Serialize,
+    G::Edge: Serialize,
+    W: Write,
+{
+    let mut ids = HashMap::new();
+    for (id, vertex) in graph.vertices(&VertexSearch::scan()).enumerate() {
+        ids.insert(vertex.id(), id);
+        let weight = vertex.weight();
+        write_record::<_, G::Vertex, G::Edge>(
+            &mut writer,
+            &WriteRecord::Vertex {
+                id,
+                label: weight.label().name(),
+                properties: weight,
+            },
+        )?;
+    }
+
+    let mut id = 0;
+    for vertex in graph.vertices(&VertexSearch::scan()) {
+        for edge in graph.edges(vertex.id(), &EdgeSearch::scan().outgoing()) {
+            let (Some(from), Some(to)) = (ids.get(&edge.tail()), ids.get(&edge.head())) else {
+                continue;
+            };
+            let weight = edge.weight();
+            write_record::<_, G::Vertex, G::Edge>(
+                &mut writer,
+                &WriteRecord::Edge {
+                    id,
+                    label: weight.label().name(),
+                    from: *from,
+                    to: *to,
+                    properties: weight,
+                },
+            )?;
+            id += 1;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads JSON Lines into a graph, one record at a time.
+///
+/// Ids may be numbers or strings. Edges refer to vertices by the ids in the input, which are
+/// mapped to the new vertices as they are added, so an edge must come after the vertices that
+/// it joins. Blank lines are skipped, and errors report the line that failed.
+///
+/// Returns the vertex that was added for each input id, as the id's JSON text.
+pub fn read<G, R>(graph: &mut G, mut reader: R) -> Result<HashMap<String, G::VertexId>, Error>
+where
+    G: Graph,
+    G::Vertex: DeserializeOwned,
+    G::Edge: DeserializeOwned,
+    R: BufRead,
+{
+    let mut ids = HashMap::new();
+    let mut buf = String::new();
+    let mut line = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        line += 1;
+        if buf.trim().is_empty() {
+            continue;
+        }
+        let row_error =
+            |source: Box<dyn std::error::Error + Send + Sync>| Error::Row { line, source };
+
+        let record: ReadRecord<G::Vertex, G::Edge> =
+            serde_json::from_str(&buf).map_err(|error| row_error(Box::new(error)))?;
+        match record {
+            ReadRecord::Vertex {
+                id,
+                label,
+                properties,
+            } => {
+                check_label(label, &properties).map_err(|error| row_error(Box::new(error)))?;
+                ids.insert(external_id(id), graph.add_vertex(properties));
+            }
+            ReadRecord::Edge {
+                label,
+                from,
+                to,
+                properties,
+            } => {
+                check_label(label, &properties).map_err(|error| row_error(Box::new(error)))?;
+                let endpoint = |id| {
+                    let id = external_id(id);
+                    ids.get(&id)
+                        .copied()
+                        .ok_or_else(|| row_error(Box::new(Error::UnknownVertex(id))))
+                };
+                let from = endpoint(from)?;
+                let to = endpoint(to)?;
+                graph
+                    .try_add_edge(from, to, properties)
+                    .map_err(|error| row_error(Box::new(error)))?;
+            }
+        }
+    }
+    Ok(ids)
+}
+
+fn write_record<W, V, E>(writer: &mut W, record: &WriteRecord<V, E>) -> Result<(), Error>
+where
+    W: Write,
+    V: Serialize,
+    E: Serialize,
+{
+    serde_json::to_writer(&mut *writer, record).map_err(|error| Error::Io(error.into()))?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Checks that the label of a record, if it has one, is the label of its weight.
+fn check_label<E>(label: Option<String>, weight: &E) -> Result<(), Error>
+where
+    E: Element,
+{
+    let expected = weight.label().name();
+    match label {
+        Some(label) if label != expected => Err(Error::Syntax(format!(
+            "label {} doesn't match properties of {}",
+            label, expected
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn external_id(id: serde_json::Value) -> String {
+    match id {
+        serde_json::Value::String(id) => id,
+        id => id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use crate::jsonl::{read, write};
+    use graph_api_derive::{EdgeExt, VertexExt};
+    use graph_api_lib::{EdgeReference, EdgeSearch, Graph};
+    use graph_api_simplegraph::SimpleGraph;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, VertexExt, Serialize, Deserialize)]
+    pub enum Vertex {
+        Person {
+            #[index(hash)]
+            name: String,
+            age: u64,
+        },
+        Project(Project),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Project {
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, EdgeExt, Serialize, Deserialize)]
+    pub enum Edge {
+        #[edge(from = VertexLabel::Person, to = VertexLabel::Person)]
+        Knows { since: i32 },
+        #[edge(from = VertexLabel::Person, to = VertexLabel::Project)]
+        Created,
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut graph = SimpleGraph::new();
+        let bryn = graph.add_vertex(Vertex::Person {
+            name: "Bryn".to_string(),
+            age: 45,
+        });
+        let julia = graph.add_vertex(Vertex::Person {
+            name: "Julia".to_string(),
+            age: 48,
+        });
+        let graph_api = graph.add_vertex(Vertex::Project(Project {
+            name: "GraphApi".to_string(),
+        }));
+        graph.add_edge(bryn, julia, Edge::Knows { since: 1999 });
+        graph.add_edge(bryn, graph_api, Edge::Created);
+
+        let mut lines = Vec::new();
+        write(&graph, &mut lines).expect("graph must be written");
+        let text = String::from_utf8(lines.clone()).expect("lines must be utf8");
+        assert_eq!(text.lines().count(), 5);
+        assert!(text.lines().next().unwrap().starts_with(
+            r#"{"type":"vertex","id":0,"label":"Person","properties":{"Person":{"name":"Bryn","age":45}}}"#
+        ));
+
+        let mut copy = SimpleGraph::<Vertex, Edge>::new();
+        let ids = read(&mut copy, lines.as_slice()).expect("graph must be read");
+        assert_eq!(ids.len(), 3);
+        let bryn = copy
+            .walk()
+            .vertices(Vertex::person_by_name("Bryn"))
+            .first()
+            .expect("bryn must exist");
+        assert_eq!(bryn, ids["0"]);
+        let mut edges = copy
+            .edges(bryn, &EdgeSearch::scan().outgoing())
+            .map(|edge| edge.weight().clone())
+            .collect::<Vec<_>>();
+        edges.sort_by_key(|edge| format!("{:?}", edge));
+        assert_eq!(edges, vec![Edge::Created, Edge::Knows { since: 1999 }]);
+    }
+
+    #[test]
+    fn external_ids() {
+        let lines = r#"{"type":"vertex","id":"bryn","properties":{"Person":{"name":"Bryn","age":45}}}
+
+{"type":"vertex","id":"julia","properties":{"Person":{"name":"Julia","age":48}}}
+{"type":"edge","from":"bryn","to":"julia","properties":{"Knows":{"since":1999}}}
+"#;
+        let mut graph = SimpleGraph::<Vertex, Edge>::new();
+        let ids = read(&mut graph, lines.as_bytes()).expect("graph must be read");
+        assert_eq!(
+            graph
+                .walk()
+                .vertices_by_id(vec![ids["bryn"]])
+                .edges(EdgeSearch::scan().outgoing())
+                .head()
+                .collect::<Vec<_>>(),
+            vec![ids["julia"]]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let read_lines =
+            |lines: &str| read(&mut SimpleGraph::<Vertex, Edge>::new(), lines.as_bytes());
+        let person = r#"{"type":"vertex","id":1,"properties":{"Person":{"name":"Bryn","age":45}}}"#;
+
+        assert!(matches!(
+            read_lines(&format!("{}\nnot json\n", person)),
+            Err(Error::Row { line: 2, .. })
+        ));
+        match read_lines(&format!(
+            "{}\n{}\n",
+            person, r#"{"type":"edge","from":1,"to":2,"properties":"Created"}"#
+        )) {
+            Err(Error::Row { line: 2, source }) => assert!(matches!(
+                source.downcast_ref::<Error>(),
+                Some(Error::UnknownVertex(id)) if id == "2"
+            )),
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert!(matches!(
+            read_lines(
+                r#"{"type":"vertex","id":1,"label":"Project","properties":{"Person":{"name":"Bryn","age":45}}}"#
+            ),
+            Err(Error::Row { line: 1, .. })
+        ));
+    }
+}
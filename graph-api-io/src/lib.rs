@@ -0,0 +1,43 @@
+//! Import and export of [`Graph`](graph_api_lib::Graph)s in common interchange formats.
+//!
+//! Vertices and edges are described by their label name and the fields that the derive
+//! macros expose, so any graph whose `Vertex` and `Edge` types derive `VertexExt` and
+//! `EdgeExt` can be written out. Reading creates elements with
+//! [`Element::from_fields`](graph_api_lib::Element::from_fields), or with a constructor of your
+//! own for variants that the derive can't create, such as tuple variants.
+//!
+//! The supported formats are:
+//! * [GraphML](crate::graphml), for Gephi, yEd, NetworkX and TinkerPop.
+//! * [CSV](crate::csv), with a file for each vertex and edge label.
+//! * [JSON Lines](crate::jsonl), for streaming serde weights through pipelines.
+
+pub mod csv;
+mod error;
+pub mod graphml;
+pub mod jsonl;
+
+pub use error::Error;
+
+use graph_api_lib::{Element, FieldError, Label};
+
+/// Creates an element from a label name and the text of its fields, using
+/// [`Element::from_fields`].
+///
+/// Returns `Ok(None)` if the element type has no label with that name. This is the constructor
+/// that the readers use by default, and it can be called from a custom constructor to handle
+/// the variants that it doesn't cover.
+pub fn from_fields<'a, E>(
+    label: &str,
+    field: &dyn Fn(&str) -> Option<&'a str>,
+) -> Result<Option<E>, FieldError>
+where
+    E: Element,
+{
+    match <E::Label as Label>::variants()
+        .iter()
+        .find(|variant| variant.name() == label)
+    {
+        Some(label) => E::from_fields(*label, field).map(Some),
+        None => Ok(None),
+    }
+}
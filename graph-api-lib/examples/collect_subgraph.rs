@@ -0,0 +1,71 @@
+use graph_api_lib::{
+    EdgeSearch, Graph, SupportsEdgeLabelIndex, SupportsVertexHashIndex, SupportsVertexLabelIndex,
+    VertexSearch,
+};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let _refs = populate_graph(&mut graph);
+
+    vertex_example(&graph);
+    induced_example(&graph);
+    edge_example(&graph);
+}
+
+fn vertex_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexHashIndex,
+{
+    // Copy the vertices at the end of Bryn's outgoing edges into a new graph. Bryn and the
+    // edges were only passed through, so they are not copied
+    let (people, ids) = graph
+        .walk()
+        .vertices(Vertex::person_by_name("Bryn"))
+        .edges(EdgeSearch::scan().outgoing())
+        .head()
+        .collect_subgraph::<SimpleGraph<_, _>>();
+
+    println!("Copied {} vertices", ids.vertex_count());
+    assert_eq!(people.vertices(&VertexSearch::scan()).count(), 2);
+}
+
+fn induced_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexLabelIndex,
+{
+    // Copy everyone, along with the edges between them
+    let (people, ids) = graph
+        .walk()
+        .vertices(Vertex::person())
+        .collect_induced_subgraph::<SimpleGraph<_, _>>();
+
+    // The ids of the new graph can be found from the ids of the old one
+    for (source, copy) in ids.vertices() {
+        println!("{:?} was copied to {:?}", source, copy);
+    }
+    assert_eq!(ids.edge_count(), 2);
+    assert_eq!(people.vertices(&VertexSearch::scan()).count(), 2);
+}
+
+fn edge_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsEdgeLabelIndex,
+{
+    // Copy the edges of who knows who, along with the people at either end. Ending on edges
+    // is how to keep the connections a traversal followed
+    let (knows, ids) = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .edges(Edge::knows())
+        .collect_subgraph::<SimpleGraph<_, _>>();
+
+    println!(
+        "Copied {} edges and {} vertices",
+        ids.edge_count(),
+        ids.vertex_count()
+    );
+    assert_eq!(knows.vertices(&VertexSearch::scan()).count(), 2);
+}
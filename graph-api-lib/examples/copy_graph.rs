@@ -0,0 +1,72 @@
+use graph_api_lib::copy::{copy_graph, copy_graph_with};
+use graph_api_lib::{Graph, SupportsVertexHashIndex, VertexReference, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+use petgraph::stable_graph::StableGraph;
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let _refs = populate_graph(&mut graph);
+
+    example(&graph);
+    convert_example(&graph);
+}
+
+fn example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexHashIndex,
+{
+    // Move the graph to petgraph to use its algorithms
+    let mut petgraph = StableGraph::new();
+    let ids = copy_graph(graph, &mut petgraph);
+    assert_eq!(petgraph.node_count(), 4);
+    assert_eq!(petgraph.edge_count(), 4);
+
+    // Look up the copy of a vertex from its id in the original graph
+    let bryn = graph
+        .walk()
+        .vertices(Vertex::person_by_name("Bryn"))
+        .first()
+        .expect("bryn must exist");
+    println!("Bryn is {:?} in petgraph", ids.vertex(bryn));
+
+    // And copy it back again
+    let mut copy = SimpleGraph::new();
+    copy_graph(&petgraph, &mut copy);
+    assert_eq!(copy.vertices(&VertexSearch::scan()).count(), 4);
+}
+
+fn convert_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Convert the weights while copying, here to hide everyone's name
+    let mut anonymous = StableGraph::<Vertex, Edge>::new();
+    copy_graph_with(
+        graph,
+        &mut anonymous,
+        |vertex| match vertex {
+            Vertex::Person {
+                age,
+                unique_id,
+                username,
+                ..
+            } => Vertex::Person {
+                name: "Anonymous".to_string(),
+                age: *age,
+                unique_id: *unique_id,
+                username: username.clone(),
+                biography: String::new(),
+            },
+            vertex => vertex.clone(),
+        },
+        |edge| edge.clone(),
+    );
+
+    for person in anonymous.vertices(&VertexSearch::scan()) {
+        if let Vertex::Person { name, .. } = person.weight() {
+            assert_eq!(name, "Anonymous");
+        }
+    }
+}
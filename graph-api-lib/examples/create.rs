@@ -0,0 +1,58 @@
+use graph_api_lib::{Graph, VertexReference, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Project, Vertex, VertexExt, populate_graph};
+
+fn main() {
+    // Create a mutable graph for testing
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    populate_graph(&mut graph);
+
+    add_edge_to_example(&mut graph);
+    add_vertex_linked_example(&mut graph);
+}
+
+fn add_edge_to_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let project = graph.add_vertex(Vertex::Project(Project {
+        name: "Hackathon".to_string(),
+    }));
+
+    // Link every person to the new project, then count the new edges
+    let created = graph
+        .walk_mut() // Must use walk_mut to add elements
+        .vertices(VertexSearch::scan())
+        .filter_person()
+        .add_edge_to(|_| project, |_| Edge::Created)
+        .count();
+
+    println!("Added {} edges to the project", created);
+}
+
+fn add_vertex_linked_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Give every person a project of their own, named after them
+    let projects: Vec<_> = graph
+        .walk_mut() // Must use walk_mut to add elements
+        .vertices(VertexSearch::scan())
+        .filter_person()
+        .push_context(|person, _| match person.weight() {
+            Vertex::Person { name, .. } => format!("{}'s project", name),
+            _ => unreachable!(),
+        })
+        .add_vertex_linked(
+            |name| {
+                Vertex::Project(Project {
+                    name: name.to_string(),
+                })
+            },
+            |_| Edge::Created,
+        )
+        .collect();
+
+    println!("Added {} projects", projects.len());
+}
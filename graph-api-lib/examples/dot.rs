@@ -0,0 +1,67 @@
+use graph_api_lib::dot::{Dot, Format};
+use graph_api_lib::{EdgeSearch, Element, Graph, Label, SupportsVertexHashIndex, VertexReference};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Person, Vertex, populate_graph};
+
+fn main() {
+    // Create a new graph
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let _refs = populate_graph(&mut graph);
+
+    example(&graph);
+    vertex_example(&graph);
+    edge_example(&graph);
+}
+
+fn example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Write the whole graph, labelling people by name and everything else by label
+    let dot = Dot::new(graph)
+        .format(Format::Name)
+        .vertex_label(|vertex| match vertex.project::<Person<_>>() {
+            Some(person) => person.name().to_string(),
+            None => vertex.weight().label().name().to_string(),
+        })
+        .to_string();
+
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.contains("[label=\"Bryn\"]"));
+    println!("{}", dot);
+}
+
+fn vertex_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexHashIndex,
+{
+    // Highlight the people that Bryn knows
+    let dot = graph
+        .walk()
+        .vertices(Vertex::person_by_name("Bryn"))
+        .edges(EdgeSearch::scan().outgoing())
+        .head()
+        .dot()
+        .to_string();
+
+    assert!(dot.contains("fillcolor=gold"));
+    println!("{}", dot);
+}
+
+fn edge_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexHashIndex,
+{
+    // Show only the edges leaving Bryn and the vertices that they join
+    let dot = graph
+        .walk()
+        .vertices(Vertex::person_by_name("Bryn"))
+        .edges(EdgeSearch::scan().outgoing())
+        .dot()
+        .only_highlighted()
+        .to_string();
+
+    assert!(dot.contains("color=red"));
+    println!("{}", dot);
+}
@@ -0,0 +1,46 @@
+use graph_api_lib::{EdgeSearch, Graph, SupportsElementRemoval, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, EdgeExt, Vertex, VertexExt, populate_graph};
+
+fn main() {
+    // Create a mutable graph for testing
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    populate_graph(&mut graph);
+
+    edge_example(&mut graph);
+    vertex_example(&mut graph);
+}
+
+fn vertex_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsElementRemoval,
+{
+    // Remove everyone called Bryn, along with the edges that connect them to the graph
+    let dropped = graph
+        .walk_mut() // Must use walk_mut to remove elements
+        .vertices(VertexSearch::scan())
+        .filter_by_person(|person, _| person.name() == "Bryn")
+        .drop();
+
+    println!(
+        "Removed {} vertices and {} edges",
+        dropped.vertices, dropped.edges
+    );
+}
+
+fn edge_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsElementRemoval,
+{
+    // Remove the knows edges, leaving the people in place
+    let dropped = graph
+        .walk_mut() // Must use walk_mut to remove elements
+        .vertices(VertexSearch::scan())
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_knows()
+        .drop();
+
+    assert_eq!(dropped.vertices, 0);
+    println!("Removed {} edges", dropped.edges);
+}
@@ -0,0 +1,47 @@
+use graph_api_lib::{EdgeSearch, Graph, SupportsVertexLabelIndex, VertexReference, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, VertexLabel, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    vertex_example(&graph);
+    edge_example(&graph, refs.bryn);
+}
+
+fn vertex_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexLabelIndex,
+{
+    // Describe a traversal without running it
+    let explain = graph
+        .walk()
+        .vertices(VertexSearch::label(VertexLabel::Person).with_limit(10))
+        .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { age, .. } if *age > 40))
+        .edges(EdgeSearch::scan().outgoing())
+        .head()
+        .explain();
+
+    // The label index is used and the limit is pushed down to the graph
+    assert_eq!(explain.steps.len(), 4);
+    assert_eq!(explain.steps[0].limit, Some(10));
+    println!("{}", explain);
+}
+
+fn edge_example<G>(graph: &G, start_id: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Edge traversals can be explained too
+    let explain = graph
+        .walk()
+        .vertices_by_id(vec![start_id])
+        .edges(EdgeSearch::scan().incoming())
+        .take(1)
+        .explain();
+
+    assert_eq!(explain.steps.len(), 3);
+    println!("{}", explain);
+}
@@ -24,10 +24,10 @@ where
 
     // If found, print project information
     if let Some(project_id) = first_project {
-        if let Some(vertex) = graph.vertex(project_id) {
-            if let Vertex::Project(project) = vertex.weight() {
-                println!("Found first project: {}", project.name);
-            }
+        if let Some(vertex) = graph.vertex(project_id)
+            && let Vertex::Project(project) = vertex.weight()
+        {
+            println!("Found first project: {}", project.name);
         }
     } else {
         println!("No projects found");
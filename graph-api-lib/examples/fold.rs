@@ -34,11 +34,7 @@ where
                 }
             });
 
-    let average_age = if person_count > 0 {
-        total_age / person_count
-    } else {
-        0
-    };
+    let average_age = total_age.checked_div(person_count).unwrap_or(0);
     // Average age should be reasonable for our test data
     assert!(average_age > 20 && average_age < 100);
     println!("Average age of people: {}", average_age);
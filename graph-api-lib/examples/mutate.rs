@@ -12,6 +12,7 @@ fn main() {
     let _refs = populate_graph(&mut graph);
 
     basic_example(&mut graph);
+    batched_example(&mut graph);
 }
 
 fn basic_example<G>(graph: &mut G)
@@ -39,3 +40,23 @@ where
         mutations_count
     );
 }
+
+fn batched_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexLabelIndex,
+{
+    let project_id = graph.add_vertex(Vertex::Project(Project {
+        name: "BatchedProject".to_string(),
+    }));
+
+    // Only 100 people are held in memory at a time
+    let mutations_count = graph
+        .walk_mut() // Must use walk_mut for mutations
+        .vertices(Vertex::person())
+        .mutate_batched(100, |graph, person_id, _| {
+            graph.add_edge(person_id, project_id, Edge::Created);
+        });
+
+    assert!(mutations_count >= 2);
+    println!("Linked {} people in batches", mutations_count);
+}
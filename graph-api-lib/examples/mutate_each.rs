@@ -0,0 +1,65 @@
+use graph_api_lib::{EdgeReference, EdgeReferenceMut, EdgeSearch, Graph, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, EdgeExt, Project, Vertex, VertexExt, populate_graph};
+
+fn main() {
+    // Create a mutable graph for testing
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    populate_graph(&mut graph);
+
+    vertex_example(&mut graph);
+    edge_example(&mut graph);
+}
+
+fn vertex_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let project = graph.add_vertex(Vertex::Project(Project {
+        name: "Hackathon".to_string(),
+    }));
+
+    // Link every person to the project, then keep walking from the same people
+    let projects = graph
+        .walk_mut() // Must use walk_mut for mutations
+        .vertices(VertexSearch::scan())
+        .filter_person()
+        .mutate_each(|graph, person_id, _| {
+            graph.add_edge(person_id, project, Edge::Created);
+        })
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_created()
+        .count();
+
+    // The new edges are visible to the steps after mutate_each
+    assert!(projects >= 2);
+    println!("People have created {} projects", projects);
+}
+
+fn edge_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Move every knows edge to the year 2000, then look at the edges that changed
+    let since: Vec<_> = graph
+        .walk_mut() // Must use walk_mut for mutations
+        .vertices(VertexSearch::scan())
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_knows()
+        .mutate_each(|graph, edge_id, _| {
+            if let Some(mut edge) = graph.edge_mut(edge_id)
+                && let Edge::Knows { since } = edge.weight_mut()
+            {
+                *since = 2000;
+            }
+        })
+        .map(|edge, _| match edge.weight() {
+            Edge::Knows { since } => *since,
+            _ => unreachable!(),
+        })
+        .collect();
+
+    assert!(since.iter().all(|since| *since == 2000));
+    println!("Updated {} knows edges", since.len());
+}
@@ -0,0 +1,32 @@
+use graph_api_lib::rayon::prelude::*;
+use graph_api_lib::{Graph, SupportsParallelScan, VertexReference, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    populate_graph(&mut graph);
+
+    example(&graph);
+}
+
+fn example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsParallelScan,
+    G::VertexId: Send,
+{
+    // Count people on every thread in the pool and add up the per partition counts
+    let people: usize = graph
+        .walk()
+        .par_vertices(VertexSearch::scan())
+        .map_partitions(|partition| {
+            partition
+                .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+                .count()
+        })
+        .sum();
+
+    assert_eq!(people, 2);
+    println!("Found {} people", people);
+}
@@ -0,0 +1,33 @@
+use graph_api_lib::pattern::Pattern;
+use graph_api_lib::{Graph, SupportsEdgeLabelIndex, SupportsVertexLabelIndex};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    example(&graph, refs.julia, refs.graph_api);
+}
+
+fn example<G>(graph: &G, julia: G::VertexId, graph_api: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexLabelIndex + SupportsEdgeLabelIndex,
+{
+    // Person -Knows-> Person -Created-> Project
+    let mut pattern = Pattern::new();
+    let person = pattern.vertex(Vertex::person());
+    let friend = pattern.vertex(Vertex::person());
+    let project = pattern.vertex(Vertex::project());
+    pattern.edge(person, friend, Edge::knows().outgoing());
+    pattern.edge(friend, project, Edge::created().outgoing());
+
+    // Find people who know someone that created a project
+    let matches = pattern.matches(graph).collect::<Vec<_>>();
+
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].vertex(person), julia);
+    assert_eq!(matches[0].vertex(project), graph_api);
+    println!("Found {} matches", matches.len());
+}
@@ -0,0 +1,48 @@
+use graph_api_lib::plan::{Advice, Capabilities, Plan, Predicate};
+use graph_api_lib::{EdgeSearch, ElementId, Graph, IndexType, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, EdgeLabel, Vertex, VertexIndex, VertexLabel, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    example(&graph, refs.julia);
+}
+
+fn example<G>(graph: &G, julia: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Who does Bryn know? Written as a scan followed by filters
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_label(VertexLabel::Person)
+        .filter_property(VertexIndex::PersonName, Predicate::Eq("Bryn".into()))
+        .edges(EdgeSearch::label(EdgeLabel::Knows).outgoing())
+        .head()
+        .take(5);
+
+    // The optimizer starts from a lookup on the name index and keeps the filters as checks
+    let optimized = plan.optimize(&Capabilities::all());
+    assert!(optimized.advice.is_empty());
+    println!("{}", optimized.plan.explain());
+    assert_eq!(
+        optimized.plan.execute(graph),
+        vec![ElementId::Vertex(julia)]
+    );
+
+    // Without index support the plan still runs, and the optimizer says what would help
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_property(VertexIndex::PersonName, Predicate::Eq("Bryn".into()));
+    let optimized = plan.optimize(&Capabilities::none());
+    for advice in &optimized.advice {
+        println!("{}", advice);
+    }
+    assert!(optimized.advice.contains(&Advice::UnsupportedIndex {
+        label: "Person",
+        property: "name",
+        index_type: IndexType::Hash,
+    }));
+    assert_eq!(optimized.plan.execute(graph).len(), 1);
+}
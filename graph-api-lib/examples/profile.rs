@@ -0,0 +1,47 @@
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    vertex_example(&graph);
+    edge_example(&graph, refs.bryn);
+}
+
+fn vertex_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Run the traversal and measure each step
+    let profile = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+        .profile();
+
+    // The scan visited every vertex and the filter kept the people
+    assert_eq!(profile.steps[0].elements_out, 4);
+    assert_eq!(profile.steps[1].elements_in, 4);
+    assert_eq!(profile.steps[1].elements_out, 2);
+    println!("{}", profile);
+}
+
+fn edge_example<G>(graph: &G, start_id: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Profile the edges leaving a vertex
+    let profile = graph
+        .walk()
+        .vertices_by_id(vec![start_id])
+        .edges(EdgeSearch::scan().outgoing())
+        .filter(|edge, _| matches!(edge.weight(), Edge::Knows { .. }))
+        .profile();
+
+    assert_eq!(profile.steps[1].elements_out, 2);
+    assert_eq!(profile.steps[2].elements_out, 1);
+    println!("Traversal took {:?}", profile.elapsed());
+}
@@ -0,0 +1,44 @@
+use graph_api_lib::plan::Capabilities;
+use graph_api_lib::query::{Literal, Output, Predicate, Query, Start, Step, Terminal};
+use graph_api_lib::{Direction, Graph};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    populate_graph(&mut graph);
+
+    example(&graph);
+}
+
+fn example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Who has Bryn known since before 2000? Built from data, as a request handler would
+    let query = Query::new(Start::Index {
+        label: "Person".to_string(),
+        property: "name".to_string(),
+        value: Literal::from("Bryn"),
+    })
+    .step(Step::Edges {
+        label: Some("Knows".to_string()),
+        direction: Direction::Outgoing,
+    })
+    .step(Step::Filter {
+        property: "since".to_string(),
+        predicate: Predicate::Lt(2000.into()),
+    })
+    .step(Step::Head)
+    .terminal(Terminal::Values(vec![
+        "name".to_string(),
+        "age".to_string(),
+    ]));
+
+    let Ok(Output::Rows(rows)) = query.execute(graph, &Capabilities::all()) else {
+        panic!("the query returns rows");
+    };
+    assert_eq!(rows.len(), 1);
+    rows.for_each(|row| println!("{:?}", row));
+}
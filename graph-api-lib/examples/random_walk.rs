@@ -0,0 +1,49 @@
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, RandomWalk, SupportsEdgeLabelIndex};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    example(&graph, refs.bryn);
+    weighted_example(&graph, refs.bryn);
+}
+
+fn example<G>(graph: &G, start_id: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsEdgeLabelIndex,
+{
+    // Walk four steps along outgoing `knows` edges, the same seed always gives the same walk
+    let walk = graph
+        .walk()
+        .vertices_by_id(vec![start_id])
+        .random_walk(Edge::knows().outgoing(), RandomWalk::new(4).seed(42))
+        .collect::<Vec<_>>();
+
+    // Bryn and Julia only know each other, so the walk bounces between them
+    assert_eq!(walk.len(), 4);
+    println!("Visited {} vertices", walk.len());
+}
+
+fn weighted_example<G>(graph: &G, start_id: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Prefer `knows` edges over everything else
+    let walk = graph
+        .walk()
+        .vertices_by_id(vec![start_id])
+        .weighted_random_walk(
+            EdgeSearch::scan().outgoing(),
+            RandomWalk::new(3).seed(42),
+            |edge| match edge.weight() {
+                Edge::Knows { .. } => 10.0,
+                _ => 1.0,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    println!("Visited {} vertices", walk.len());
+}
@@ -0,0 +1,43 @@
+use graph_api_lib::{EdgeSearch, Graph, VertexSearch};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    vertex_example(&graph);
+    edge_example(&graph, refs.bryn);
+}
+
+fn vertex_example<G>(graph: &G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Pick two vertices at random, the same seed always gives the same sample
+    let vertices = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .sample(2, 42)
+        .collect::<Vec<_>>();
+
+    assert_eq!(vertices.len(), 2);
+    println!("Sampled {} vertices", vertices.len());
+}
+
+fn edge_example<G>(graph: &G, start_id: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Sample one edge from the neighbourhood of a vertex
+    let edges = graph
+        .walk()
+        .vertices_by_id(vec![start_id])
+        .edges(EdgeSearch::scan())
+        .sample(1, 42)
+        .collect::<Vec<_>>();
+
+    assert_eq!(edges.len(), 1);
+    println!("Sampled {} edges", edges.len());
+}
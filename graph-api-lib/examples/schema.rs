@@ -0,0 +1,27 @@
+use graph_api_lib::IndexType;
+use graph_api_lib::schema::Schema;
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex};
+
+fn main() {
+    example();
+}
+
+fn example() {
+    // Describe the model of a graph
+    let schema = Schema::of::<SimpleGraph<Vertex, Edge>>();
+
+    // Labels, fields and indexes all come from the derive macros
+    let person = schema.label("Person").expect("person is a vertex label");
+    let name = person
+        .fields
+        .iter()
+        .find(|field| field.name == "name")
+        .expect("person has a name");
+    assert_eq!(name.ty, "String");
+    assert_eq!(name.index, Some(IndexType::Hash));
+
+    // Export for tooling and documentation
+    println!("{}", schema.to_json());
+    println!("{}", schema.to_mermaid());
+}
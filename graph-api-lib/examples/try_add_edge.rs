@@ -0,0 +1,34 @@
+use graph_api_lib::{AddEdgeError, Graph};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, Vertex, populate_graph};
+
+fn main() {
+    // Create a new graph
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    example(&mut graph, refs.bryn, refs.rust);
+}
+
+fn example<G>(graph: &mut G, bryn: G::VertexId, rust: G::VertexId)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // `Created` edges are declared with #[edge(from = VertexLabel::Person, to = VertexLabel::Project)],
+    // so Rust can't have created Bryn
+    let result = graph.try_add_edge(rust, bryn, Edge::Created);
+    assert!(matches!(
+        result,
+        Err(AddEdgeError::InvalidEndpoints {
+            edge: "Created",
+            ..
+        })
+    ));
+
+    // `Knows` edges are between people, so this one is added
+    let knows = graph
+        .try_add_edge(bryn, bryn, Edge::Knows { since: 1980 })
+        .expect("people can know people");
+    println!("Added edge {:?}", knows);
+}
@@ -0,0 +1,68 @@
+use graph_api_lib::{Graph, SupportsVertexHashIndex};
+use graph_api_simplegraph::SimpleGraph;
+use graph_api_test::{Edge, PersonMut, Vertex, VertexIndex, populate_graph};
+
+fn main() {
+    // Create a new graph
+    let mut graph = SimpleGraph::new();
+    // Populate the graph with test data
+    let refs = populate_graph(&mut graph);
+
+    upsert_vertex_example(&mut graph);
+    ensure_edge_example(&mut graph, refs.bryn, refs.julia, refs.graph_api);
+}
+
+fn upsert_vertex_example<G>(graph: &mut G)
+where
+    G: Graph<Vertex = Vertex, Edge = Edge> + SupportsVertexHashIndex,
+{
+    // Bryn is already in the graph, so their age is updated
+    let (bryn, created) = graph.upsert_vertex::<PersonMut<_, _>>(
+        VertexIndex::PersonName,
+        "Bryn",
+        || Vertex::Person {
+            name: "Bryn".to_string(),
+            age: 46,
+            unique_id: Default::default(),
+            username: "bryn".to_string(),
+            biography: "Did some graph stuff".to_string(),
+        },
+        |bryn| bryn.set_age(46),
+    );
+    assert!(!created);
+    println!("Updated vertex {:?}", bryn);
+
+    // Nobody is called Alice, so a new person is added
+    let (alice, created) = graph.upsert_vertex::<PersonMut<_, _>>(
+        VertexIndex::PersonName,
+        "Alice",
+        || Vertex::Person {
+            name: "Alice".to_string(),
+            age: 30,
+            unique_id: Default::default(),
+            username: "alice".to_string(),
+            biography: "New to graphs".to_string(),
+        },
+        |_| {},
+    );
+    assert!(created);
+    println!("Added vertex {:?}", alice);
+}
+
+fn ensure_edge_example<G>(
+    graph: &mut G,
+    bryn: G::VertexId,
+    julia: G::VertexId,
+    graph_api: G::VertexId,
+) where
+    G: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    // Bryn already knows Julia, so the existing edge is returned
+    let (knows, created) = graph.ensure_edge(bryn, julia, Edge::Knows { since: 2000 });
+    assert!(!created);
+    println!("Found edge {:?}", knows);
+
+    // Julia hasn't created anything yet, so the edge is added
+    let (_, created) = graph.ensure_edge(julia, graph_api, Edge::Created);
+    assert!(created);
+}
@@ -0,0 +1,101 @@
+use crate::{EdgeReference, EdgeSearch, Element, Graph, VertexReference, VertexSearch};
+use std::collections::HashMap;
+
+/// A snapshot of the adjacency of a graph where vertices are renumbered `0..n`.
+///
+/// Algorithms work on dense indexes so that per-vertex state can live in plain vectors.
+pub(crate) struct Adjacency<G>
+where
+    G: Graph,
+{
+    /// The original vertex ids, indexed by dense index.
+    pub(crate) ids: Vec<G::VertexId>,
+    /// The neighbours of each vertex following the edge search, one entry per matching edge.
+    pub(crate) neighbours: Vec<Vec<usize>>,
+}
+
+impl<G> Adjacency<G>
+where
+    G: Graph,
+{
+    /// Snapshots every vertex in the graph, following edges that match `edges`.
+    pub(crate) fn new(graph: &G, edges: &EdgeSearch<G>) -> Self {
+        Self::with_vertices(graph, &VertexSearch::scan(), edges)
+    }
+
+    /// Snapshots the vertices matching `vertices`, following edges that match `edges`.
+    /// Edges leading to vertices outside the selection are ignored.
+    pub(crate) fn with_vertices(
+        graph: &G,
+        vertices: &VertexSearch<G>,
+        edges: &EdgeSearch<G>,
+    ) -> Self {
+        let label = match vertices {
+            VertexSearch::Label { label, .. } => Some(*label),
+            _ => None,
+        };
+        let ids: Vec<G::VertexId> = graph
+            .vertices(vertices)
+            .filter(|vertex| label.is_none_or(|label| vertex.weight().label() == label))
+            .map(|vertex| vertex.id())
+            .collect();
+        let index: HashMap<G::VertexId, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect();
+
+        let neighbours = ids
+            .iter()
+            .map(|id| {
+                graph
+                    .edges(*id, edges)
+                    .filter(|edge| {
+                        edges
+                            .label
+                            .is_none_or(|label| edge.weight().label() == label)
+                    })
+                    .filter_map(|edge| {
+                        let other = if edge.tail() == *id {
+                            edge.head()
+                        } else {
+                            edge.tail()
+                        };
+                        index.get(&other).copied()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Adjacency { ids, neighbours }
+    }
+
+    /// The number of vertices in the snapshot.
+    pub(crate) fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// The neighbours of each vertex treating edges as undirected, without duplicates or self loops.
+    /// Neighbours are sorted so that membership can be checked with a binary search.
+    pub(crate) fn undirected(&self) -> Vec<Vec<usize>> {
+        let mut undirected = vec![Vec::new(); self.len()];
+        for (vertex, neighbours) in self.neighbours.iter().enumerate() {
+            for &neighbour in neighbours {
+                if neighbour != vertex {
+                    undirected[vertex].push(neighbour);
+                    undirected[neighbour].push(vertex);
+                }
+            }
+        }
+        for neighbours in &mut undirected {
+            neighbours.sort_unstable();
+            neighbours.dedup();
+        }
+        undirected
+    }
+
+    /// Translates dense indexes back to vertex ids.
+    pub(crate) fn to_ids(&self, indexes: impl IntoIterator<Item = usize>) -> Vec<G::VertexId> {
+        indexes.into_iter().map(|index| self.ids[index]).collect()
+    }
+}
@@ -0,0 +1,404 @@
+use crate::algorithms::adjacency::Adjacency;
+use crate::rng::SeededRng;
+use crate::{Direction, EdgeSearch, Graph, SupportsDenseVertexIds, VertexSearch};
+use std::collections::{HashMap, VecDeque};
+
+/// Configuration for [`page_rank`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRank {
+    damping: f64,
+    iterations: usize,
+    tolerance: f64,
+}
+
+impl Default for PageRank {
+    fn default() -> Self {
+        PageRank {
+            damping: 0.85,
+            iterations: 20,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+impl PageRank {
+    /// Creates a configuration with a damping factor of `0.85` and at most `20` iterations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the probability of following an edge rather than jumping to a random vertex.
+    pub fn damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the maximum number of iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Stops iterating early once the total change in rank falls below the tolerance.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Ranks vertices using PageRank.
+///
+/// Rank flows along edges in the direction of the edge search. Rank held by vertices without
+/// any matching edges is redistributed evenly across all vertices.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to rank, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow, usually `EdgeSearch::scan().outgoing()` or similar
+/// - `config`: The damping factor and iteration limits
+///
+/// # Returns
+/// The rank of each vertex. Ranks sum to one.
+pub fn page_rank<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+    config: PageRank,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let len = adjacency.len();
+    if len == 0 {
+        return HashMap::new();
+    }
+
+    let uniform = 1.0 / len as f64;
+    let mut rank = vec![uniform; len];
+    let mut next = vec![0.0; len];
+    for _ in 0..config.iterations {
+        let dangling: f64 = (0..len)
+            .filter(|vertex| adjacency.neighbours[*vertex].is_empty())
+            .map(|vertex| rank[vertex])
+            .sum();
+        next.fill((1.0 - config.damping) * uniform + config.damping * dangling * uniform);
+        for (vertex, neighbours) in adjacency.neighbours.iter().enumerate() {
+            if neighbours.is_empty() {
+                continue;
+            }
+            let share = config.damping * rank[vertex] / neighbours.len() as f64;
+            for &neighbour in neighbours {
+                next[neighbour] += share;
+            }
+        }
+        let change: f64 = rank
+            .iter()
+            .zip(next.iter())
+            .map(|(previous, current)| (previous - current).abs())
+            .sum();
+        std::mem::swap(&mut rank, &mut next);
+        if change < config.tolerance {
+            break;
+        }
+    }
+    adjacency.ids.into_iter().zip(rank).collect()
+}
+
+/// Computes the degree centrality of vertices.
+///
+/// The degree of a vertex is the number of edges matching the search, normalised by the
+/// maximum possible degree of `n - 1`.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to count, `Direction::All` counts both in and out degree
+pub fn degree_centrality<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let scale = normaliser(adjacency.len());
+    adjacency
+        .ids
+        .iter()
+        .zip(adjacency.neighbours.iter())
+        .map(|(id, neighbours)| (*id, neighbours.len() as f64 * scale))
+        .collect()
+}
+
+/// Computes the betweenness centrality of vertices using Brandes' algorithm.
+///
+/// The betweenness of a vertex is the number of shortest paths between other pairs of
+/// vertices that pass through it. Paths follow the direction of the edge search; when the
+/// direction is `Direction::All` each undirected path is only counted once.
+///
+/// This is `O(nm)` and may be slow on large graphs, see [`approximate_betweenness_centrality`].
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+pub fn betweenness_centrality<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let sources: Vec<usize> = (0..adjacency.len()).collect();
+    brandes(adjacency, &sources, edges.direction)
+}
+
+/// Approximates betweenness centrality by only computing shortest paths from a random sample
+/// of source vertices and scaling the result.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+/// - `samples`: The number of source vertices to sample
+/// - `seed`: The seed for sampling, the same seed always produces the same result
+pub fn approximate_betweenness_centrality<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+    samples: usize,
+    seed: u64,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let sources = SeededRng::new(seed).sample_indexes(adjacency.len(), samples);
+    brandes(adjacency, &sources, edges.direction)
+}
+
+/// Computes the closeness centrality of vertices.
+///
+/// Closeness is the reciprocal of the average shortest path distance from a vertex to the
+/// vertices it can reach, following the direction of the edge search. It is scaled by the
+/// fraction of vertices that are reachable (Wasserman and Faust), so vertices in small
+/// components are not over-rated. Vertices that cannot reach any other vertex score zero.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+pub fn closeness_centrality<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let len = adjacency.len();
+    let mut distances = vec![usize::MAX; len];
+    let mut queue = VecDeque::new();
+    let scores: Vec<f64> = (0..len)
+        .map(|source| {
+            breadth_first(&adjacency.neighbours, source, &mut distances, &mut queue);
+            let (reached, total) = distances
+                .iter()
+                .filter(|distance| **distance != usize::MAX && **distance != 0)
+                .fold((0usize, 0usize), |(reached, total), distance| {
+                    (reached + 1, total + distance)
+                });
+            closeness(len, reached as f64, reached as f64, total as f64)
+        })
+        .collect();
+    adjacency.ids.into_iter().zip(scores).collect()
+}
+
+/// Approximates closeness centrality by measuring distances to a random sample of pivot
+/// vertices only (Eppstein and Wang).
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+/// - `samples`: The number of pivot vertices to sample
+/// - `seed`: The seed for sampling, the same seed always produces the same result
+pub fn approximate_closeness_centrality<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+    samples: usize,
+    seed: u64,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let len = adjacency.len();
+    let pivots = SeededRng::new(seed).sample_indexes(len, samples);
+
+    // Distances from every vertex to a pivot are found by searching backwards from the pivot.
+    let mut reversed = vec![Vec::new(); len];
+    for (vertex, neighbours) in adjacency.neighbours.iter().enumerate() {
+        for &neighbour in neighbours {
+            reversed[neighbour].push(vertex);
+        }
+    }
+
+    let mut reached = vec![0usize; len];
+    let mut total = vec![0usize; len];
+    let mut distances = vec![usize::MAX; len];
+    let mut queue = VecDeque::new();
+    for &pivot in &pivots {
+        breadth_first(&reversed, pivot, &mut distances, &mut queue);
+        for ((distance, reached), total) in distances
+            .iter()
+            .zip(reached.iter_mut())
+            .zip(total.iter_mut())
+        {
+            if *distance != usize::MAX && *distance != 0 {
+                *reached += 1;
+                *total += distance;
+            }
+        }
+    }
+
+    let scores: Vec<f64> = (0..len)
+        .map(|vertex| {
+            // Pivots other than the vertex itself give an unbiased sample of the other vertices
+            let sampled = pivots.len() - usize::from(pivots.contains(&vertex));
+            if sampled == 0 {
+                return 0.0;
+            }
+            let estimated_reach = reached[vertex] as f64 / sampled as f64 * (len - 1) as f64;
+            closeness(
+                len,
+                estimated_reach,
+                reached[vertex] as f64,
+                total[vertex] as f64,
+            )
+        })
+        .collect();
+    adjacency.ids.into_iter().zip(scores).collect()
+}
+
+/// Converts per-vertex results into a dense vector indexed by [`SupportsDenseVertexIds::dense_vertex_index`].
+/// Entries for vertices without a result are `f64::NAN`.
+pub fn to_dense<G>(graph: &G, scores: &HashMap<G::VertexId, f64>) -> Vec<f64>
+where
+    G: SupportsDenseVertexIds,
+{
+    let mut dense = vec![f64::NAN; graph.dense_vertex_bound()];
+    for (id, score) in scores {
+        dense[graph.dense_vertex_index(*id)] = *score;
+    }
+    dense
+}
+
+fn normaliser(len: usize) -> f64 {
+    if len > 1 { 1.0 / (len - 1) as f64 } else { 0.0 }
+}
+
+/// Scales the reciprocal of the average distance by the fraction of reachable vertices.
+fn closeness(len: usize, reach: f64, sampled: f64, total: f64) -> f64 {
+    if total == 0.0 {
+        return 0.0;
+    }
+    let average = total / sampled;
+    reach * normaliser(len) / average
+}
+
+/// Unweighted single source shortest paths, leaving distances from `source` in `distances`.
+fn breadth_first(
+    neighbours: &[Vec<usize>],
+    source: usize,
+    distances: &mut [usize],
+    queue: &mut VecDeque<usize>,
+) {
+    distances.fill(usize::MAX);
+    distances[source] = 0;
+    queue.clear();
+    queue.push_back(source);
+    while let Some(vertex) = queue.pop_front() {
+        for &neighbour in &neighbours[vertex] {
+            if distances[neighbour] == usize::MAX {
+                distances[neighbour] = distances[vertex] + 1;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+}
+
+/// Brandes' betweenness accumulation from the given sources, scaled up if the sources are a sample.
+fn brandes<G>(
+    adjacency: Adjacency<G>,
+    sources: &[usize],
+    direction: Direction,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let len = adjacency.len();
+    let mut betweenness = vec![0.0; len];
+    let mut distances = vec![usize::MAX; len];
+    let mut paths = vec![0.0f64; len];
+    let mut dependency = vec![0.0f64; len];
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); len];
+    let mut order = Vec::with_capacity(len);
+    let mut queue = VecDeque::new();
+
+    for &source in sources {
+        distances.fill(usize::MAX);
+        paths.fill(0.0);
+        dependency.fill(0.0);
+        predecessors.iter_mut().for_each(Vec::clear);
+        order.clear();
+
+        distances[source] = 0;
+        paths[source] = 1.0;
+        queue.push_back(source);
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+            for &neighbour in &adjacency.neighbours[vertex] {
+                if distances[neighbour] == usize::MAX {
+                    distances[neighbour] = distances[vertex] + 1;
+                    queue.push_back(neighbour);
+                }
+                if distances[neighbour] == distances[vertex] + 1 {
+                    paths[neighbour] += paths[vertex];
+                    predecessors[neighbour].push(vertex);
+                }
+            }
+        }
+
+        while let Some(vertex) = order.pop() {
+            for &predecessor in &predecessors[vertex] {
+                dependency[predecessor] +=
+                    paths[predecessor] / paths[vertex] * (1.0 + dependency[vertex]);
+            }
+            if vertex != source {
+                betweenness[vertex] += dependency[vertex];
+            }
+        }
+    }
+
+    let mut scale = if sources.is_empty() {
+        0.0
+    } else {
+        len as f64 / sources.len() as f64
+    };
+    if direction == Direction::All {
+        scale /= 2.0;
+    }
+    adjacency
+        .ids
+        .into_iter()
+        .zip(betweenness)
+        .map(|(id, score)| (id, score * scale))
+        .collect()
+}
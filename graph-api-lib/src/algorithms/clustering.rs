@@ -0,0 +1,120 @@
+use crate::algorithms::adjacency::Adjacency;
+use crate::{EdgeSearch, Graph, VertexSearch};
+use std::collections::HashMap;
+
+/// Counts the triangles that each vertex is part of.
+///
+/// Edges are treated as undirected, the direction of the search is ignored. Parallel edges
+/// and self loops do not form additional triangles. The total number of triangles in the
+/// graph is the sum of the counts divided by three.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+pub fn triangle_count<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, usize>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let triangles = triangles(&adjacency.undirected());
+    adjacency.ids.into_iter().zip(triangles).collect()
+}
+
+/// Computes the local clustering coefficient of each vertex, the fraction of pairs of
+/// neighbours of the vertex that are themselves connected.
+///
+/// Edges are treated as undirected, the direction of the search is ignored. Vertices with
+/// fewer than two neighbours have a coefficient of zero.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+pub fn local_clustering_coefficient<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, f64>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let neighbours = adjacency.undirected();
+    let triangles = triangles(&neighbours);
+    adjacency
+        .ids
+        .into_iter()
+        .zip(neighbours.iter().zip(triangles))
+        .map(|(id, (neighbours, triangles))| {
+            let pairs = pairs(neighbours.len());
+            let coefficient = if pairs == 0 {
+                0.0
+            } else {
+                triangles as f64 / pairs as f64
+            };
+            (id, coefficient)
+        })
+        .collect()
+}
+
+/// Computes the global clustering coefficient (transitivity) of a graph, the fraction of
+/// connected triples of vertices that form a triangle.
+///
+/// Edges are treated as undirected, the direction of the search is ignored.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+pub fn global_clustering_coefficient<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> f64
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let neighbours = adjacency.undirected();
+    // Each triangle closes three triples, one centred on each of its vertices
+    let closed: usize = triangles(&neighbours).into_iter().sum();
+    let triples: usize = neighbours
+        .iter()
+        .map(|neighbours| pairs(neighbours.len()))
+        .sum();
+    if triples == 0 {
+        0.0
+    } else {
+        closed as f64 / triples as f64
+    }
+}
+
+fn pairs(degree: usize) -> usize {
+    degree * degree.saturating_sub(1) / 2
+}
+
+/// Counts triangles per vertex given sorted, deduplicated undirected neighbours.
+fn triangles(neighbours: &[Vec<usize>]) -> Vec<usize> {
+    let mut triangles = vec![0; neighbours.len()];
+    for (first, first_neighbours) in neighbours.iter().enumerate() {
+        // Only count each triangle from its lowest vertex, in increasing order
+        for (position, &second) in first_neighbours.iter().enumerate() {
+            if second <= first {
+                continue;
+            }
+            for &third in &first_neighbours[position + 1..] {
+                if neighbours[second].binary_search(&third).is_ok() {
+                    triangles[first] += 1;
+                    triangles[second] += 1;
+                    triangles[third] += 1;
+                }
+            }
+        }
+    }
+    triangles
+}
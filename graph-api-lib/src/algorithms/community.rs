@@ -0,0 +1,199 @@
+use crate::algorithms::adjacency::Adjacency;
+use crate::rng::SeededRng;
+use crate::{EdgeSearch, Graph, VertexSearch};
+use std::collections::{BTreeMap, HashMap};
+
+/// Label propagation stops after this many rounds even if labels are still changing.
+const MAX_LABEL_PROPAGATION_ROUNDS: usize = 100;
+
+/// Detects communities using label propagation.
+///
+/// Every vertex starts in its own community, then repeatedly adopts the community that is most
+/// common amongst its neighbours until no vertex changes. Vertices are visited in a random
+/// order and ties are broken randomly, so the seed determines the result.
+///
+/// Edges are treated as undirected, the direction of the search is ignored.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+/// - `seed`: The seed for ordering and tie breaking, the same seed always produces the same result
+///
+/// # Returns
+/// The community of each vertex. Communities are numbered from zero.
+pub fn label_propagation<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+    seed: u64,
+) -> HashMap<G::VertexId, usize>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+    let neighbours = adjacency.undirected();
+    let mut rng = SeededRng::new(seed);
+    let mut community: Vec<usize> = (0..adjacency.len()).collect();
+    let mut order: Vec<usize> = (0..adjacency.len()).collect();
+    let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut best = Vec::new();
+
+    for _ in 0..MAX_LABEL_PROPAGATION_ROUNDS {
+        rng.shuffle(&mut order);
+        let mut changed = false;
+        for &vertex in &order {
+            if neighbours[vertex].is_empty() {
+                continue;
+            }
+            counts.clear();
+            for &neighbour in &neighbours[vertex] {
+                *counts.entry(community[neighbour]).or_default() += 1;
+            }
+            let highest = counts.values().copied().max().unwrap_or_default();
+            best.clear();
+            best.extend(
+                counts
+                    .iter()
+                    .filter(|(_, count)| **count == highest)
+                    .map(|(label, _)| *label),
+            );
+            // Staying put on a tie lets the labels settle
+            if !best.contains(&community[vertex]) {
+                community[vertex] = best[rng.below(best.len())];
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    adjacency
+        .ids
+        .into_iter()
+        .zip(renumber(&community))
+        .collect()
+}
+
+/// Detects communities using the Louvain method.
+///
+/// Vertices are greedily moved between communities to maximise modularity, then each
+/// community is collapsed into a single vertex and the process repeats until modularity can
+/// no longer be improved. The result is deterministic.
+///
+/// Edges are treated as undirected, the direction of the search is ignored. Parallel edges
+/// increase the strength of the connection between two vertices.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `vertices`: The vertices to include, edges to vertices outside this search are ignored
+/// - `edges`: The edges to follow
+///
+/// # Returns
+/// The community of each vertex. Communities are numbered from zero.
+pub fn louvain<G>(
+    graph: &G,
+    vertices: &VertexSearch<G>,
+    edges: &EdgeSearch<G>,
+) -> HashMap<G::VertexId, usize>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::with_vertices(graph, vertices, edges);
+
+    // Symmetric edge weights, collapsed communities become vertices with self loops.
+    let mut weights: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); adjacency.len()];
+    for (vertex, neighbours) in adjacency.neighbours.iter().enumerate() {
+        for &neighbour in neighbours {
+            *weights[vertex].entry(neighbour).or_default() += 1.0;
+            *weights[neighbour].entry(vertex).or_default() += 1.0;
+        }
+    }
+    let mut membership: Vec<usize> = (0..adjacency.len()).collect();
+
+    while let Some(community) = louvain_level(&weights) {
+        let community = renumber(&community);
+        let communities = community.iter().max().map_or(0, |max| max + 1);
+        for member in &mut membership {
+            *member = community[*member];
+        }
+
+        let mut collapsed: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); communities];
+        for (vertex, neighbours) in weights.iter().enumerate() {
+            for (neighbour, weight) in neighbours {
+                *collapsed[community[vertex]]
+                    .entry(community[*neighbour])
+                    .or_default() += weight;
+            }
+        }
+        weights = collapsed;
+    }
+
+    adjacency.ids.into_iter().zip(membership).collect()
+}
+
+/// Moves vertices between communities until no move improves modularity.
+/// Returns `None` if no vertex moved.
+fn louvain_level(weights: &[BTreeMap<usize, f64>]) -> Option<Vec<usize>> {
+    let strength: Vec<f64> = weights.iter().map(|edges| edges.values().sum()).collect();
+    let total: f64 = strength.iter().sum();
+    if total == 0.0 {
+        return None;
+    }
+
+    let mut community: Vec<usize> = (0..weights.len()).collect();
+    let mut community_strength = strength.clone();
+    let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+    let mut improved = false;
+    loop {
+        let mut moved = false;
+        for (vertex, edges) in weights.iter().enumerate() {
+            let current = community[vertex];
+            links.clear();
+            for (neighbour, weight) in edges {
+                if *neighbour != vertex {
+                    *links.entry(community[*neighbour]).or_default() += weight;
+                }
+            }
+
+            // The gain in modularity for joining a community, up to a constant factor
+            community_strength[current] -= strength[vertex];
+            let gain = |candidate: usize, links: f64| {
+                links - community_strength[candidate] * strength[vertex] / total
+            };
+            let mut best = current;
+            let mut best_gain = gain(current, links.get(&current).copied().unwrap_or_default());
+            for (candidate, weight) in &links {
+                let candidate_gain = gain(*candidate, *weight);
+                if candidate_gain > best_gain + f64::EPSILON {
+                    best = *candidate;
+                    best_gain = candidate_gain;
+                }
+            }
+            community_strength[best] += strength[vertex];
+
+            if best != current {
+                community[vertex] = best;
+                moved = true;
+                improved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+    improved.then_some(community)
+}
+
+/// Renumbers community labels densely from zero in order of first appearance.
+fn renumber(community: &[usize]) -> Vec<usize> {
+    let mut numbering = HashMap::new();
+    community
+        .iter()
+        .map(|label| {
+            let next = numbering.len();
+            *numbering.entry(*label).or_insert(next)
+        })
+        .collect()
+}
@@ -0,0 +1,289 @@
+use crate::algorithms::adjacency::Adjacency;
+use crate::{Direction, EdgeSearch, Graph};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+const UNVISITED: usize = usize::MAX;
+
+/// Returned by [`topological_sort`] when the edges being followed contain a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<VertexId> {
+    cycle: Vec<VertexId>,
+}
+
+impl<VertexId> CycleError<VertexId> {
+    /// The vertices that form the cycle, in traversal order.
+    /// The last vertex has an edge back to the first.
+    pub fn cycle(&self) -> &[VertexId] {
+        &self.cycle
+    }
+
+    /// Consumes the error, returning the vertices that form the cycle.
+    pub fn into_cycle(self) -> Vec<VertexId> {
+        self.cycle
+    }
+}
+
+impl<VertexId> Display for CycleError<VertexId> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "graph contains a cycle of {} vertices", self.cycle.len())
+    }
+}
+
+impl<VertexId> Error for CycleError<VertexId> where VertexId: Debug {}
+
+/// Finds the weakly connected components of a graph.
+///
+/// The direction of the edge search is ignored, two vertices are in the same component if
+/// there is a path between them following edges in either direction. The label of the search
+/// is respected.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `search`: The edges to follow
+///
+/// # Returns
+/// The components of the graph. Every vertex appears in exactly one component.
+pub fn weakly_connected_components<G>(graph: &G, search: &EdgeSearch<G>) -> Vec<Vec<G::VertexId>>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::new(graph, &search.clone().direction(Direction::All));
+    let mut component = vec![UNVISITED; adjacency.len()];
+    let mut components = Vec::new();
+    let mut queue = VecDeque::new();
+
+    for root in 0..adjacency.len() {
+        if component[root] != UNVISITED {
+            continue;
+        }
+        let id = components.len();
+        let mut members = Vec::new();
+        component[root] = id;
+        queue.push_back(root);
+        while let Some(vertex) = queue.pop_front() {
+            members.push(vertex);
+            for &neighbour in &adjacency.neighbours[vertex] {
+                if component[neighbour] == UNVISITED {
+                    component[neighbour] = id;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        components.push(adjacency.to_ids(members));
+    }
+    components
+}
+
+/// Finds the strongly connected components of a graph using Tarjan's algorithm.
+///
+/// Edges are followed in the direction of the search, so usually the search should be
+/// `EdgeSearch::scan().outgoing()` or similar. Searching with `Direction::All` yields the
+/// weakly connected components.
+///
+/// The implementation is iterative and will not overflow the stack on deep graphs.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `search`: The edges to follow
+///
+/// # Returns
+/// The components of the graph in reverse topological order, a component is returned before
+/// any component that has edges leading to it.
+pub fn strongly_connected_components<G>(graph: &G, search: &EdgeSearch<G>) -> Vec<Vec<G::VertexId>>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::new(graph, search);
+    tarjan(&adjacency.neighbours)
+        .into_iter()
+        .map(|component| adjacency.to_ids(component))
+        .collect()
+}
+
+/// Returns true if following the edges of the search from any vertex can lead back to that vertex.
+///
+/// Note that when the search direction is `Direction::All` every edge forms a cycle.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `search`: The edges to follow
+pub fn is_cyclic<G>(graph: &G, search: &EdgeSearch<G>) -> bool
+where
+    G: Graph,
+{
+    find_cycle(graph, search).is_some()
+}
+
+/// Finds a cycle in the graph following the edges of the search.
+///
+/// Note that when the search direction is `Direction::All` every edge forms a cycle.
+///
+/// # Parameters
+/// - `graph`: The graph to analyse
+/// - `search`: The edges to follow
+///
+/// # Returns
+/// The vertices of a cycle in traversal order, or `None` if the graph is acyclic.
+/// The last vertex has an edge back to the first.
+pub fn find_cycle<G>(graph: &G, search: &EdgeSearch<G>) -> Option<Vec<G::VertexId>>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::new(graph, search);
+    dense_cycle(&adjacency.neighbours).map(|cycle| adjacency.to_ids(cycle))
+}
+
+/// Sorts the vertices of a graph so that every edge of the search leads from an earlier
+/// vertex to a later one, using Kahn's algorithm.
+///
+/// # Parameters
+/// - `graph`: The graph to sort
+/// - `search`: The edges to follow, usually `EdgeSearch::scan().outgoing()` or similar
+///
+/// # Returns
+/// All vertices in topological order, or a [`CycleError`] describing a cycle if no order exists.
+pub fn topological_sort<G>(
+    graph: &G,
+    search: &EdgeSearch<G>,
+) -> Result<Vec<G::VertexId>, CycleError<G::VertexId>>
+where
+    G: Graph,
+{
+    let adjacency = Adjacency::new(graph, search);
+    let mut in_degree = vec![0usize; adjacency.len()];
+    for neighbours in &adjacency.neighbours {
+        for &neighbour in neighbours {
+            in_degree[neighbour] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..adjacency.len())
+        .filter(|vertex| in_degree[*vertex] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(adjacency.len());
+    while let Some(vertex) = queue.pop_front() {
+        order.push(vertex);
+        for &neighbour in &adjacency.neighbours[vertex] {
+            in_degree[neighbour] -= 1;
+            if in_degree[neighbour] == 0 {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    if order.len() == adjacency.len() {
+        Ok(adjacency.to_ids(order))
+    } else {
+        let cycle = dense_cycle(&adjacency.neighbours)
+            .expect("vertices remaining after topological sort must be part of a cycle");
+        Err(CycleError {
+            cycle: adjacency.to_ids(cycle),
+        })
+    }
+}
+
+/// Iterative Tarjan's strongly connected components over dense adjacency.
+fn tarjan(neighbours: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let len = neighbours.len();
+    let mut index = vec![UNVISITED; len];
+    let mut low_link = vec![0; len];
+    let mut on_stack = vec![false; len];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+    // Each frame is a vertex and the position of the next neighbour to visit.
+    let mut frames: Vec<(usize, usize)> = Vec::new();
+
+    for root in 0..len {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        frames.push((root, 0));
+
+        while let Some(frame) = frames.last_mut() {
+            let vertex = frame.0;
+            if let Some(&neighbour) = neighbours[vertex].get(frame.1) {
+                frame.1 += 1;
+                if index[neighbour] == UNVISITED {
+                    index[neighbour] = next_index;
+                    low_link[neighbour] = next_index;
+                    next_index += 1;
+                    stack.push(neighbour);
+                    on_stack[neighbour] = true;
+                    frames.push((neighbour, 0));
+                } else if on_stack[neighbour] {
+                    low_link[vertex] = low_link[vertex].min(index[neighbour]);
+                }
+            } else {
+                frames.pop();
+                if let Some(&(parent, _)) = frames.last() {
+                    low_link[parent] = low_link[parent].min(low_link[vertex]);
+                }
+                if low_link[vertex] == index[vertex] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == vertex {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+    }
+    components
+}
+
+/// Iterative depth first search for a cycle over dense adjacency.
+fn dense_cycle(neighbours: &[Vec<usize>]) -> Option<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Unvisited,
+        OnPath,
+        Done,
+    }
+
+    let mut state = vec![State::Unvisited; neighbours.len()];
+    let mut frames: Vec<(usize, usize)> = Vec::new();
+    for root in 0..neighbours.len() {
+        if state[root] != State::Unvisited {
+            continue;
+        }
+        state[root] = State::OnPath;
+        frames.push((root, 0));
+        while let Some(frame) = frames.last_mut() {
+            let vertex = frame.0;
+            if let Some(&neighbour) = neighbours[vertex].get(frame.1) {
+                frame.1 += 1;
+                match state[neighbour] {
+                    State::Unvisited => {
+                        state[neighbour] = State::OnPath;
+                        frames.push((neighbour, 0));
+                    }
+                    State::OnPath => {
+                        // Every vertex on the path from the neighbour to here is part of the cycle.
+                        let start = frames
+                            .iter()
+                            .position(|(member, _)| *member == neighbour)
+                            .expect("vertex on path must have a frame");
+                        return Some(frames[start..].iter().map(|(member, _)| *member).collect());
+                    }
+                    State::Done => {}
+                }
+            } else {
+                state[vertex] = State::Done;
+                frames.pop();
+            }
+        }
+    }
+    None
+}
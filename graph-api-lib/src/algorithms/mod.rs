@@ -0,0 +1,39 @@
+//! Graph algorithms that run directly against any [`Graph`](crate::Graph) implementation.
+//!
+//! Algorithms take an [`EdgeSearch`](crate::EdgeSearch) describing which edges to follow.
+//! The label and direction of the search are respected, so the same graph can be analysed
+//! as directed (`EdgeSearch::scan().outgoing()`), reversed (`.incoming()`) or undirected
+//! (`.direction(Direction::All)`), and restricted to a subset of edge labels.
+//!
+//! Internally each algorithm takes a snapshot of the adjacency of the graph with vertices
+//! renumbered densely, so the cost of walking the graph is only paid once.
+//!
+//! Results are keyed by vertex id. They can be stored on the vertices themselves by looking up
+//! each vertex with [`Graph::vertex_mut`](crate::Graph::vertex_mut) and using the setters of
+//! the projection generated by `#[derive(VertexExt)]`.
+
+mod adjacency;
+mod centrality;
+mod clustering;
+mod community;
+mod connectivity;
+
+pub use centrality::PageRank;
+pub use centrality::approximate_betweenness_centrality;
+pub use centrality::approximate_closeness_centrality;
+pub use centrality::betweenness_centrality;
+pub use centrality::closeness_centrality;
+pub use centrality::degree_centrality;
+pub use centrality::page_rank;
+pub use centrality::to_dense;
+pub use clustering::global_clustering_coefficient;
+pub use clustering::local_clustering_coefficient;
+pub use clustering::triangle_count;
+pub use community::label_propagation;
+pub use community::louvain;
+pub use connectivity::CycleError;
+pub use connectivity::find_cycle;
+pub use connectivity::is_cyclic;
+pub use connectivity::strongly_connected_components;
+pub use connectivity::topological_sort;
+pub use connectivity::weakly_connected_components;
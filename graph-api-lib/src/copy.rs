@@ -0,0 +1,208 @@
+//! Copying vertices and edges from one graph into another.
+//!
+//! The target can be any [`Graph`] implementation, so a graph built with one backend can be
+//! moved to another with [`copy_graph`], or to a graph with different vertex and edge types
+//! with [`copy_graph_with`]. Copies return an [`IdMap`] from the ids in the source graph to the
+//! ids of the copies, so that results found in one graph can be looked up in the other.
+
+use crate::{EdgeReference, EdgeSearch, Graph, VertexReference, VertexSearch};
+use std::collections::HashMap;
+
+/// Maps the ids of copied vertices and edges in a source graph to their ids in the target
+/// graph.
+pub struct IdMap<Source, Target>
+where
+    Source: Graph,
+    Target: Graph,
+{
+    vertices: HashMap<Source::VertexId, Target::VertexId>,
+    edges: HashMap<Source::EdgeId, Target::EdgeId>,
+}
+
+impl<Source, Target> IdMap<Source, Target>
+where
+    Source: Graph,
+    Target: Graph,
+{
+    fn new() -> Self {
+        IdMap {
+            vertices: HashMap::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    /// Returns the id of the copy of a source vertex, if it was copied.
+    pub fn vertex(&self, id: Source::VertexId) -> Option<Target::VertexId> {
+        self.vertices.get(&id).copied()
+    }
+
+    /// Returns the id of the copy of a source edge, if it was copied.
+    pub fn edge(&self, id: Source::EdgeId) -> Option<Target::EdgeId> {
+        self.edges.get(&id).copied()
+    }
+
+    /// Returns the source and target id of every copied vertex, in no particular order.
+    pub fn vertices(&self) -> impl Iterator<Item = (Source::VertexId, Target::VertexId)> + '_ {
+        self.vertices
+            .iter()
+            .map(|(source, target)| (*source, *target))
+    }
+
+    /// Returns the source and target id of every copied edge, in no particular order.
+    pub fn edges(&self) -> impl Iterator<Item = (Source::EdgeId, Target::EdgeId)> + '_ {
+        self.edges.iter().map(|(source, target)| (*source, *target))
+    }
+
+    /// Returns the number of copied vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of copied edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+/// Copies every vertex and edge of one graph into another.
+///
+/// The target may be any graph implementation with the same vertex and edge types, and may
+/// already contain elements, which are left as they are.
+///
+/// # Parameters
+/// - `source`: The graph to copy
+/// - `target`: The graph to add the copies to
+///
+/// # Returns
+/// An [`IdMap`] from the id of every vertex and edge in `source` to the id of its copy.
+pub fn copy_graph<Source, Target>(source: &Source, target: &mut Target) -> IdMap<Source, Target>
+where
+    Source: Graph,
+    Target: Graph<Vertex = Source::Vertex, Edge = Source::Edge>,
+    Source::Vertex: Clone,
+    Source::Edge: Clone,
+{
+    copy_graph_with(source, target, |vertex| vertex.clone(), |edge| edge.clone())
+}
+
+/// Copies every vertex and edge of one graph into another, converting their weights.
+///
+/// This is [`copy_graph`] for when the two graphs have different vertex or edge types.
+/// Each converted edge must still be allowed between the copies of its endpoints.
+///
+/// # Parameters
+/// - `source`: The graph to copy
+/// - `target`: The graph to add the copies to
+/// - `vertex`: Converts a vertex of `source` into a vertex of `target`
+/// - `edge`: Converts an edge of `source` into an edge of `target`
+///
+/// # Returns
+/// An [`IdMap`] from the id of every vertex and edge in `source` to the id of its copy.
+pub fn copy_graph_with<Source, Target, V, E>(
+    source: &Source,
+    target: &mut Target,
+    vertex: V,
+    edge: E,
+) -> IdMap<Source, Target>
+where
+    Source: Graph,
+    Target: Graph,
+    V: FnMut(&Source::Vertex) -> Target::Vertex,
+    E: FnMut(&Source::Edge) -> Target::Edge,
+{
+    let vertices: Vec<_> = source
+        .vertices(&VertexSearch::scan())
+        .map(|reference| reference.id())
+        .collect();
+    copy_elements(
+        source,
+        target,
+        vertices,
+        std::iter::empty(),
+        true,
+        vertex,
+        edge,
+    )
+}
+
+/// Copies vertices and edges from `source` into `target`, converting their weights.
+///
+/// The tail and head of each edge are copied along with it. If `induced` is set, every edge of
+/// the source graph between two copied vertices is copied as well.
+pub(crate) fn copy_elements<Source, Target, V, E>(
+    source: &Source,
+    target: &mut Target,
+    vertices: impl IntoIterator<Item = Source::VertexId>,
+    edges: impl IntoIterator<Item = Source::EdgeId>,
+    induced: bool,
+    mut vertex: V,
+    mut edge: E,
+) -> IdMap<Source, Target>
+where
+    Source: Graph,
+    Target: Graph,
+    V: FnMut(&Source::Vertex) -> Target::Vertex,
+    E: FnMut(&Source::Edge) -> Target::Edge,
+{
+    let mut ids = IdMap::new();
+    // The copied vertices in the order that they were copied, so that induced edges are too
+    let mut copied = Vec::new();
+    let mut copy_vertex =
+        |ids: &mut IdMap<Source, Target>, target: &mut Target, id: Source::VertexId| {
+            if let Some(copy) = ids.vertex(id) {
+                return Some(copy);
+            }
+            let weight = vertex(source.vertex(id)?.weight());
+            let copy = target.add_vertex(weight);
+            ids.vertices.insert(id, copy);
+            copied.push((id, copy));
+            Some(copy)
+        };
+
+    for id in vertices {
+        copy_vertex(&mut ids, target, id);
+    }
+    let mut copy_edge = |ids: &mut IdMap<Source, Target>,
+                         target: &mut Target,
+                         id: Source::EdgeId,
+                         tail: Target::VertexId,
+                         head: Target::VertexId| {
+        if ids.edges.contains_key(&id) {
+            return;
+        }
+        if let Some(reference) = source.edge(id) {
+            let weight = edge(reference.weight());
+            ids.edges.insert(id, target.add_edge(tail, head, weight));
+        }
+    };
+    for id in edges {
+        let Some((tail, head)) = source
+            .edge(id)
+            .map(|reference| (reference.tail(), reference.head()))
+        else {
+            continue;
+        };
+        let (Some(tail), Some(head)) = (
+            copy_vertex(&mut ids, target, tail),
+            copy_vertex(&mut ids, target, head),
+        ) else {
+            continue;
+        };
+        copy_edge(&mut ids, target, id, tail, head);
+    }
+
+    if induced {
+        for (tail, tail_copy) in copied {
+            let outgoing: Vec<_> = source
+                .edges(tail, &EdgeSearch::scan().outgoing())
+                .map(|reference| (reference.id(), reference.head()))
+                .collect();
+            for (id, head) in outgoing {
+                if let Some(head_copy) = ids.vertex(head) {
+                    copy_edge(&mut ids, target, id, tail_copy, head_copy);
+                }
+            }
+        }
+    }
+    ids
+}
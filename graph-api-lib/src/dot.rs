@@ -0,0 +1,257 @@
+//! Graphviz DOT export.
+//!
+//! [`Dot`] writes a graph, or just the elements that a traversal visited, in the DOT language
+//! so that it can be rendered with `dot -Tsvg` or any other Graphviz tool. Elements that were
+//! visited are highlighted, which makes it easy to see what a traversal did on a small graph.
+
+use crate::graph::{EdgeReference, VertexReference};
+use crate::pattern::Match;
+use crate::{EdgeSearch, Element, ElementId, Graph, Label, VertexSearch};
+use include_doc::function_body;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Write};
+
+type VertexFormatter<'graph, G> =
+    Box<dyn Fn(&<G as Graph>::VertexReference<'_>) -> String + 'graph>;
+type EdgeFormatter<'graph, G> = Box<dyn Fn(&<G as Graph>::EdgeReference<'_>) -> String + 'graph>;
+
+/// How vertices and edges are labelled in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Only the label name, such as `Person`.
+    Name,
+    /// The label name followed by a line for each field that has a value, such as `age: 45`.
+    #[default]
+    Properties,
+    /// The `Debug` output of the vertex or edge.
+    Debug,
+}
+
+/// Writes a graph in the Graphviz DOT language.
+///
+/// Create one with [`Dot::new`] for a whole graph, or with the `dot` step for the elements
+/// that a traversal visited. The output is produced by `Display`, so `to_string` returns the
+/// DOT source.
+///
+/// ```rust
+#[doc = function_body!("examples/dot.rs", example, [])]
+/// ```
+pub struct Dot<'graph, G>
+where
+    G: Graph,
+{
+    graph: &'graph G,
+    format: Format,
+    vertex_formatter: Option<VertexFormatter<'graph, G>>,
+    edge_formatter: Option<EdgeFormatter<'graph, G>>,
+    highlighted: HashSet<ElementId<G>>,
+    only_highlighted: bool,
+}
+
+impl<'graph, G> Dot<'graph, G>
+where
+    G: Graph,
+{
+    /// Creates a DOT writer for every vertex and edge in the graph.
+    pub fn new(graph: &'graph G) -> Self {
+        Dot {
+            graph,
+            format: Format::default(),
+            vertex_formatter: None,
+            edge_formatter: None,
+            highlighted: HashSet::new(),
+            only_highlighted: false,
+        }
+    }
+
+    /// Sets how vertices and edges are labelled. Defaults to [`Format::Properties`].
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Labels vertices with a function instead of the format.
+    ///
+    /// The function can use projections, for instance
+    /// `|vertex| vertex.project::<Person<_>>().map(|person| person.name().to_string()).unwrap_or_default()`.
+    pub fn vertex_label<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(&G::VertexReference<'_>) -> String + 'graph,
+    {
+        self.vertex_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Labels edges with a function instead of the format.
+    pub fn edge_label<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(&G::EdgeReference<'_>) -> String + 'graph,
+    {
+        self.edge_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Highlights vertices in the output.
+    pub fn highlight_vertices(mut self, vertices: impl IntoIterator<Item = G::VertexId>) -> Self {
+        self.highlighted
+            .extend(vertices.into_iter().map(ElementId::Vertex));
+        self
+    }
+
+    /// Highlights edges in the output.
+    pub fn highlight_edges(mut self, edges: impl IntoIterator<Item = G::EdgeId>) -> Self {
+        self.highlighted
+            .extend(edges.into_iter().map(ElementId::Edge));
+        self
+    }
+
+    /// Highlights the vertices and edges of a pattern match, so that the path it took through
+    /// the graph is shown.
+    pub fn highlight_match(self, matched: &Match<G>) -> Self {
+        self.highlight_vertices(matched.vertices().iter().copied())
+            .highlight_edges(matched.edges().iter().copied())
+    }
+
+    /// Leaves out everything that isn't highlighted, apart from the vertices at either end of
+    /// highlighted edges.
+    pub fn only_highlighted(mut self) -> Self {
+        self.only_highlighted = true;
+        self
+    }
+
+    fn vertex_text(&self, vertex: &G::VertexReference<'_>) -> String {
+        if let Some(formatter) = &self.vertex_formatter {
+            return formatter(vertex);
+        }
+        element_text(vertex.weight(), self.format)
+    }
+
+    fn edge_text(&self, edge: &G::EdgeReference<'_>) -> String {
+        if let Some(formatter) = &self.edge_formatter {
+            return formatter(edge);
+        }
+        element_text(edge.weight(), self.format)
+    }
+
+    /// The ids of the vertices and edges to write, in the order that the graph returns them.
+    fn elements(&self) -> (Vec<G::VertexId>, Vec<G::EdgeId>) {
+        let edges: Vec<G::EdgeId> = self
+            .graph
+            .vertices(&VertexSearch::scan())
+            .flat_map(|vertex| {
+                self.graph
+                    .edges(vertex.id(), &EdgeSearch::scan().outgoing())
+                    .map(|edge| edge.id())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|id| !self.only_highlighted || self.highlighted.contains(&ElementId::Edge(*id)))
+            .collect();
+
+        let endpoints: HashSet<G::VertexId> = if self.only_highlighted {
+            edges
+                .iter()
+                .filter_map(|id| self.graph.edge(*id))
+                .flat_map(|edge| [edge.tail(), edge.head()])
+                .collect()
+        } else {
+            HashSet::new()
+        };
+        let vertices = self
+            .graph
+            .vertices(&VertexSearch::scan())
+            .map(|vertex| vertex.id())
+            .filter(|id| {
+                !self.only_highlighted
+                    || self.highlighted.contains(&ElementId::Vertex(*id))
+                    || endpoints.contains(id)
+            })
+            .collect();
+        (vertices, edges)
+    }
+}
+
+impl<G> Display for Dot<'_, G>
+where
+    G: Graph,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (vertices, edges) = self.elements();
+        let nodes: HashMap<G::VertexId, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(node, id)| (*id, node))
+            .collect();
+
+        writeln!(f, "digraph {{")?;
+        for (node, id) in vertices.iter().enumerate() {
+            let Some(vertex) = self.graph.vertex(*id) else {
+                continue;
+            };
+            write!(
+                f,
+                "    v{} [label=\"{}\"",
+                node,
+                escape(&self.vertex_text(&vertex))
+            )?;
+            if self.highlighted.contains(&ElementId::Vertex(*id)) {
+                write!(f, ", style=filled, fillcolor=gold")?;
+            }
+            writeln!(f, "];")?;
+        }
+        for id in edges {
+            let Some(edge) = self.graph.edge(id) else {
+                continue;
+            };
+            let (Some(tail), Some(head)) = (nodes.get(&edge.tail()), nodes.get(&edge.head()))
+            else {
+                continue;
+            };
+            write!(
+                f,
+                "    v{} -> v{} [label=\"{}\"",
+                tail,
+                head,
+                escape(&self.edge_text(&edge))
+            )?;
+            if self.highlighted.contains(&ElementId::Edge(id)) {
+                write!(f, ", color=red, penwidth=2")?;
+            }
+            writeln!(f, "];")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+fn element_text<E>(element: &E, format: Format) -> String
+where
+    E: Element,
+{
+    let label = element.label();
+    match format {
+        Format::Name => label.name().to_string(),
+        Format::Properties => {
+            let mut text = label.name().to_string();
+            for field in label.fields() {
+                if let Some(value) = element.property(field.name) {
+                    let _ = write!(text, "\n{}: {}", field.name, value);
+                }
+            }
+            text
+        }
+        Format::Debug => format!("{:?}", element),
+    }
+}
+
+/// Escapes text for a quoted DOT string, writing line breaks as centered lines.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,5 +1,6 @@
-use crate::{Label, Value};
-use std::fmt::Debug;
+use crate::{Index, Label, Value};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 
 /// An element in a graph. This is either an edge or a vertex.
@@ -11,11 +12,96 @@ pub trait Element: Debug {
     fn label(&self) -> Self::Label;
 
     /// Given an index returns the value that is associated with this index.
-    fn value(&self, _index: &<Self::Label as Label>::Index) -> Option<Value> {
+    fn value(&self, _index: &<Self::Label as Label>::Index) -> Option<Value<'_>> {
         None
     }
+
+    /// Returns the value of a field by name.
+    ///
+    /// Unlike `value` this covers fields that are not indexed. The derive macros implement it for
+    /// fields with a type that converts to a `Value`, such as strings and numbers.
+    fn property(&self, _name: &str) -> Option<Value<'_>> {
+        None
+    }
+
+    /// Returns the value of a field by name, through `value` if the field is indexed and
+    /// `property` otherwise.
+    ///
+    /// This is how queries that refer to fields by name, such as those built at runtime, read
+    /// them.
+    fn read(&self, name: &str) -> Option<Value<'_>> {
+        match self
+            .label()
+            .indexes()
+            .iter()
+            .find(|index| index.name() == name)
+        {
+            Some(index) => self.value(index),
+            None => self.property(name),
+        }
+    }
+
+    /// Creates an element with the given label from the text of its fields, such as the
+    /// attributes of a node in a file.
+    ///
+    /// The derive macros implement it for unit variants and for variants whose named fields all
+    /// have a type that converts to a `Value`. Each field is parsed with `FromStr`.
+    fn from_fields<'a>(
+        label: Self::Label,
+        _field: &dyn Fn(&str) -> Option<&'a str>,
+    ) -> Result<Self, FieldError>
+    where
+        Self: Sized,
+    {
+        Err(FieldError::Unsupported {
+            label: label.name(),
+        })
+    }
 }
 
+/// Returned by [`Element::from_fields`] when an element can't be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldError {
+    /// The label can't be created from fields, for instance because it is a tuple variant.
+    Unsupported { label: &'static str },
+    /// A field of the label has no value.
+    Missing {
+        label: &'static str,
+        field: &'static str,
+    },
+    /// The text of a field couldn't be parsed as the field's type.
+    Invalid {
+        label: &'static str,
+        field: &'static str,
+        value: String,
+    },
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::Unsupported { label } => {
+                write!(f, "{} can't be created from its fields", label)
+            }
+            FieldError::Missing { label, field } => {
+                write!(f, "{} is missing field {}", label, field)
+            }
+            FieldError::Invalid {
+                label,
+                field,
+                value,
+            } => write!(
+                f,
+                "invalid value {:?} for field {} of {}",
+                value, field, label
+            ),
+        }
+    }
+}
+
+impl Error for FieldError {}
+
 impl Element for () {
     type Label = ();
 
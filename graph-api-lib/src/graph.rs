@@ -3,11 +3,14 @@ use crate::walker::builder::{ImmutableMarker, MutableMarker, StartWalkerBuilder}
 use crate::{EdgeSearch, Value, walker};
 use crate::{Label, VertexSearch};
 use derivative::Derivative;
-use std::fmt::Debug;
+use include_doc::function_body;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 
 /// The direction of an edge in a graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Direction {
     /// Outgoing edges.
@@ -59,6 +62,71 @@ where
     Edge(Graph::EdgeId),
 }
 
+/// The identifier of a vertex that has been checked to have a particular label.
+///
+/// `LABEL` is the ordinal of the vertex label. `VertexExt` generates a constructor for each
+/// label, such as `Vertex::person_id(graph, id)`, and `EdgeExt` generates helpers such as
+/// `Edge::add_knows` that only accept ids with the labels allowed by `#[edge(from, to)]`.
+#[derive(Debug, Derivative)]
+#[derivative(
+    Copy(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct LabelledVertexId<Graph, const LABEL: usize>
+where
+    Graph: crate::Graph,
+{
+    id: Graph::VertexId,
+}
+
+impl<Graph, const LABEL: usize> LabelledVertexId<Graph, LABEL>
+where
+    Graph: crate::Graph,
+{
+    /// Returns the id if the vertex exists and its label has the ordinal `LABEL`.
+    pub fn new(graph: &Graph, id: Graph::VertexId) -> Option<Self> {
+        graph
+            .vertex(id)
+            .filter(|vertex| vertex.weight().label().ordinal() == LABEL)
+            .map(|_| LabelledVertexId { id })
+    }
+
+    /// The id of the vertex.
+    pub fn id(&self) -> Graph::VertexId {
+        self.id
+    }
+}
+
+/// Returned by [`Graph::try_add_edge`] when an edge can't be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AddEdgeError {
+    /// The tail or head of the edge isn't in the graph.
+    MissingVertex,
+    /// The edge's label doesn't allow it to join vertices with these labels.
+    InvalidEndpoints {
+        edge: &'static str,
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+impl Display for AddEdgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddEdgeError::MissingVertex => write!(f, "the tail or head vertex does not exist"),
+            AddEdgeError::InvalidEndpoints { edge, from, to } => {
+                write!(f, "{} edges can't join {} to {}", edge, from, to)
+            }
+        }
+    }
+}
+
+impl Error for AddEdgeError {}
+
 /// Graphs that implement this trait can be used with the walker API.
 pub trait Graph: Sized + Debug {
     /// The type of the vertices in the graph. This is usually an enum.
@@ -114,6 +182,46 @@ pub trait Graph: Sized + Debug {
         edge: Self::Edge,
     ) -> Self::EdgeId;
 
+    /// Adds an edge to the graph after checking that both vertices exist and that the edge's
+    /// label allows it to join them.
+    ///
+    /// The allowed vertex labels come from [`Label::endpoints`], which `EdgeExt` generates from
+    /// `#[edge(from = .., to = ..)]` attributes. Edges without any are only checked for their
+    /// vertices.
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/try_add_edge.rs", example, [])]
+    /// ```
+    fn try_add_edge(
+        &mut self,
+        from: Self::VertexId,
+        to: Self::VertexId,
+        edge: Self::Edge,
+    ) -> Result<Self::EdgeId, AddEdgeError> {
+        let label_of = |id| {
+            self.vertex(id)
+                .map(|vertex| vertex.weight().label())
+                .ok_or(AddEdgeError::MissingVertex)
+        };
+        let from_label = label_of(from)?;
+        let to_label = label_of(to)?;
+        let label = edge.label();
+        let endpoints = label.endpoints();
+        if !endpoints.is_empty()
+            && !endpoints.iter().any(|endpoints| {
+                endpoints.from_ordinal == from_label.ordinal()
+                    && endpoints.to_ordinal == to_label.ordinal()
+            })
+        {
+            return Err(AddEdgeError::InvalidEndpoints {
+                edge: label.name(),
+                from: from_label.name(),
+                to: to_label.name(),
+            });
+        }
+        Ok(self.add_edge(from, to, edge))
+    }
+
     /// Gets the vertex with the specified identifier.
     fn vertex(&self, id: Self::VertexId) -> Option<Self::VertexReference<'_>>;
 
@@ -141,6 +249,41 @@ pub trait Graph: Sized + Debug {
         search: &EdgeSearch<'search, Self>,
     ) -> Self::EdgeIter<'search, '_>;
 
+    /// Finds an edge from `from` to `to` with the same label as `edge`, adding `edge` if there
+    /// isn't one.
+    ///
+    /// Returns the id of the edge, and `true` if it was added. An existing edge is left as it
+    /// is, even if its fields differ from `edge`.
+    ///
+    /// This takes the whole edge rather than just its label. A label can't construct an edge
+    /// that has fields, such as `Knows { since }`, so `edge` is what gets added when there
+    /// is no match. Only its label is used to look for an existing edge.
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/upsert.rs", ensure_edge_example, [])]
+    /// ```
+    fn ensure_edge(
+        &mut self,
+        from: Self::VertexId,
+        to: Self::VertexId,
+        edge: Self::Edge,
+    ) -> (Self::EdgeId, bool) {
+        let label = edge.label();
+        // Graphs without a label index may overfetch, so the label is checked again.
+        let existing = self
+            .edges(from, &EdgeSearch::label(label).outgoing())
+            .find(|existing| {
+                existing.tail() == from
+                    && existing.head() == to
+                    && existing.weight().label() == label
+            })
+            .map(|existing| existing.id());
+        match existing {
+            Some(id) => (id, false),
+            None => (self.add_edge(from, to, edge), true),
+        }
+    }
+
     /// Clears the graph. Default implementation returns an error.
     /// Implement the `SupportsClear` trait to provide this functionality.
     fn clear(&mut self) {
@@ -160,7 +303,7 @@ pub trait Graph: Sized + Debug {
     }
 
     /// Returns an immutable walker builder for the graph.
-    fn walk(&self) -> StartWalkerBuilder<ImmutableMarker, Self, ()>
+    fn walk(&self) -> StartWalkerBuilder<'_, ImmutableMarker, Self, ()>
     where
         Self: Sized,
     {
@@ -168,7 +311,7 @@ pub trait Graph: Sized + Debug {
     }
 
     /// Returns a mutable walker builder for the graph.
-    fn walk_mut(&mut self) -> StartWalkerBuilder<MutableMarker, Self, ()>
+    fn walk_mut(&mut self) -> StartWalkerBuilder<'_, MutableMarker, Self, ()>
     where
         Self: Sized,
     {
@@ -312,6 +455,21 @@ where
     ) -> Option<Self>;
 }
 
+/// Names a mutable projection independently of the lifetime of the weight it borrows.
+///
+/// The derive macros implement this for each mutable projection with `()` as the mutation
+/// listener, so `PersonMut<_, _>` can be passed to methods that create the projection themselves,
+/// such as `SupportsVertexHashIndex::upsert_vertex`. The graph supplies the actual listener.
+pub trait ProjectionMut<Weight>
+where
+    Weight: Element,
+{
+    /// The projection of a weight borrowed for `'reference`, reporting changes to `MutationListener`.
+    type Projection<'reference, MutationListener>: ProjectMut<'reference, Weight, MutationListener>
+    where
+        MutationListener: crate::MutationListener<'reference, Weight>;
+}
+
 /// Trait to allow graphs to react to mutation of elements.
 /// When an indexed element is updated the mutation listener is called with the index and the before and after values.
 pub trait MutationListener<'reference, Element>
@@ -4,6 +4,7 @@ use std::hash::Hash;
 
 /// The type of index
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum IndexType {
     /// A hash index that supports lookup by value.
@@ -45,6 +46,12 @@ where
 
     /// Returns the type of this index (Hash, Range, or FullText).
     fn index_type(&self) -> IndexType;
+
+    /// Returns the name of the field that this index covers.
+    ///
+    /// Queries use this to resolve property names, the derive macros return the field name.
+    /// Names must be unique among the indexes of an element.
+    fn name(&self) -> &'static str;
 }
 
 impl Index for () {
@@ -59,4 +66,8 @@ impl Index for () {
     fn index_type(&self) -> IndexType {
         unimplemented!("index not implemented")
     }
+
+    fn name(&self) -> &'static str {
+        unimplemented!("index not implemented")
+    }
 }
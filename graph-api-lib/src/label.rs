@@ -1,4 +1,5 @@
 use crate::Index;
+use crate::schema::FieldSchema;
 use std::fmt::Debug;
 use std::hash::Hash;
 
@@ -21,8 +22,46 @@ where
 
     /// The name of the label
     fn name(&self) -> &'static str;
+
+    /// The named fields of this label, in declaration order.
+    fn fields(&self) -> &'static [FieldSchema] {
+        &[]
+    }
+
+    /// The vertex labels that an edge with this label may join. An empty slice means that the
+    /// edge may join any vertices.
+    fn endpoints(&self) -> &'static [Endpoints] {
+        &[]
+    }
+}
+
+/// A tail and head vertex label that an edge label allows.
+///
+/// Generated by `#[edge(from = .., to = ..)]` on the variants of an `EdgeExt` enum, and
+/// checked by [`Graph::try_add_edge`](crate::Graph::try_add_edge), which compares the labels by
+/// ordinal. The names are for display and schema export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Endpoints {
+    /// The name of the tail vertex label.
+    pub from: &'static str,
+    /// The name of the head vertex label.
+    pub to: &'static str,
+    /// The ordinal of the tail vertex label, see [`Label::ordinal`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub from_ordinal: usize,
+    /// The ordinal of the head vertex label, see [`Label::ordinal`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub to_ordinal: usize,
 }
 
+/// Implemented by an edge type for each pair of vertex labels that an edge label may join, by
+/// ordinal.
+///
+/// Generated by `#[edge(from = .., to = ..)]` on the variants of an `EdgeExt` enum, and used as
+/// a bound by the generated `add_*` helpers so that edges joining the wrong labels don't compile.
+pub trait Joins<const EDGE: usize, const FROM: usize, const TO: usize> {}
+
 impl Label for () {
     type Index = ();
 
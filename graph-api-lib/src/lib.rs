@@ -1,39 +1,66 @@
 #![allow(clippy::type_complexity)]
 // The types that are flagged by clippy generally can't be factored out as they use trait associated types.
+pub mod algorithms;
+pub mod copy;
+pub mod dot;
 mod element;
 mod graph;
 mod index;
 mod label;
+pub mod pattern;
+pub mod plan;
+pub mod query;
+mod rng;
+pub mod schema;
 mod search;
 mod support;
 mod value;
 mod walker;
 
 pub use element::Element;
+pub use element::FieldError;
+pub use graph::AddEdgeError;
 pub use graph::Direction;
 pub use graph::EdgeReference;
 pub use graph::EdgeReferenceMut;
 pub use graph::ElementId;
 pub use graph::Graph;
+pub use graph::LabelledVertexId;
 pub use graph::MutationListener;
 pub use graph::Project;
 pub use graph::ProjectMut;
+pub use graph::ProjectionMut;
 pub use graph::VertexReference;
 pub use graph::VertexReferenceMut;
 pub use index::Index;
 pub use index::IndexType;
+pub use label::Endpoints;
+pub use label::Joins;
 pub use label::Label;
 pub use search::edge::EdgeSearch;
 pub use search::vertex::VertexSearch;
 pub use support::*;
+pub use value::OwnedValue;
 pub use value::Value;
 pub use value::ValueRange;
 pub use walker::EdgeWalker;
+pub use walker::ProfileEdgeWalker;
+pub use walker::ProfileVertexWalker;
+pub use walker::RebindEdgeWalker;
+pub use walker::RebindVertexWalker;
 pub use walker::VertexWalker;
 pub use walker::Walker;
 pub use walker::builder::EdgeWalkerBuilder;
 pub use walker::builder::VertexWalkerBuilder;
 pub use walker::builder::WalkerBuilder;
+pub use walker::profiler::{Access, Counted, Explain, Profile, Profiler, StepInfo, StepProfile};
+pub use walker::steps::Dropped;
+pub use walker::steps::RandomWalk;
+#[cfg(feature = "parallel")]
+pub use walker::steps::{ParallelVertices, PartitionWalkerBuilder};
+
+#[cfg(feature = "parallel")]
+pub use rayon;
 
 #[cfg(feature = "petgraph")]
 pub mod petgraph;
@@ -0,0 +1,541 @@
+//! Subgraph pattern matching.
+//!
+//! A [`Pattern`] describes a small graph of vertex and edge patterns, for instance
+//! `Person -Knows-> Person -Created-> Project`. Matching it against a graph yields every
+//! assignment of graph elements to the pattern, without having to write nested walkers and
+//! carry partial results around in context.
+//!
+//! Matching uses backtracking. Each disconnected part of the pattern is anchored at the vertex
+//! pattern with the most selective [`VertexSearch`], so an index backed search such as
+//! `Vertex::person_by_name("Bryn")` is used to find starting points rather than a scan. The
+//! rest of the pattern is found by following edges out from vertices that are already bound.
+
+use crate::{Direction, EdgeReference, EdgeSearch, Element, Graph, VertexReference, VertexSearch};
+use derivative::Derivative;
+use include_doc::function_body;
+use std::collections::HashSet;
+
+type VertexPredicate<'pattern, G> =
+    Box<dyn Fn(&<G as Graph>::VertexReference<'_>) -> bool + 'pattern>;
+type EdgePredicate<'pattern, G> = Box<dyn Fn(&<G as Graph>::EdgeReference<'_>) -> bool + 'pattern>;
+
+/// A handle to a vertex in a [`Pattern`], used to look up what it was bound to in a [`Match`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternVertex(usize);
+
+/// A handle to an edge in a [`Pattern`], used to look up what it was bound to in a [`Match`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternEdge(usize);
+
+/// Describes the vertices that a pattern vertex may be bound to.
+///
+/// A vertex search on its own can be used wherever a `VertexPattern` is expected.
+pub struct VertexPattern<'pattern, G>
+where
+    G: Graph,
+{
+    search: VertexSearch<'pattern, G>,
+    predicates: Vec<VertexPredicate<'pattern, G>>,
+}
+
+impl<'pattern, G> VertexPattern<'pattern, G>
+where
+    G: Graph,
+{
+    /// Creates a vertex pattern matching vertices found by the search.
+    ///
+    /// The search doubles as an index hint, the most selective search in the pattern is used
+    /// to find the vertices to start matching from.
+    pub fn new(search: VertexSearch<'pattern, G>) -> Self {
+        VertexPattern {
+            search,
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Further restricts the pattern to vertices that satisfy the predicate.
+    ///
+    /// The predicate can use the projections generated by `#[derive(VertexExt)]`, for instance
+    /// `|vertex| vertex.project::<Person<_>>().is_some_and(|person| person.age() > 40)`.
+    pub fn filter<Predicate>(mut self, predicate: Predicate) -> Self
+    where
+        Predicate: Fn(&G::VertexReference<'_>) -> bool + 'pattern,
+    {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    fn accepts(&self, vertex: &G::VertexReference<'_>) -> bool {
+        self.predicates.iter().all(|predicate| predicate(vertex))
+    }
+}
+
+impl<'pattern, G> From<VertexSearch<'pattern, G>> for VertexPattern<'pattern, G>
+where
+    G: Graph,
+{
+    fn from(search: VertexSearch<'pattern, G>) -> Self {
+        VertexPattern::new(search)
+    }
+}
+
+/// Describes the edges that a pattern edge may be bound to.
+///
+/// An edge search on its own can be used wherever an `EdgePattern` is expected.
+pub struct EdgePattern<'pattern, G>
+where
+    G: Graph,
+{
+    search: EdgeSearch<'pattern, G>,
+    predicates: Vec<EdgePredicate<'pattern, G>>,
+}
+
+impl<'pattern, G> EdgePattern<'pattern, G>
+where
+    G: Graph,
+{
+    /// Creates an edge pattern matching edges found by the search.
+    ///
+    /// The direction of the search is relative to the `from` vertex of the pattern edge.
+    pub fn new(search: EdgeSearch<'pattern, G>) -> Self {
+        EdgePattern {
+            search,
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Further restricts the pattern to edges that satisfy the predicate.
+    pub fn filter<Predicate>(mut self, predicate: Predicate) -> Self
+    where
+        Predicate: Fn(&G::EdgeReference<'_>) -> bool + 'pattern,
+    {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    fn accepts(&self, edge: &G::EdgeReference<'_>) -> bool {
+        self.predicates.iter().all(|predicate| predicate(edge))
+    }
+}
+
+impl<'pattern, G> From<EdgeSearch<'pattern, G>> for EdgePattern<'pattern, G>
+where
+    G: Graph,
+{
+    fn from(search: EdgeSearch<'pattern, G>) -> Self {
+        EdgePattern::new(search)
+    }
+}
+
+/// A pattern of vertices and edges to find in a graph.
+///
+/// By default patterns are matched as subgraph homomorphisms, two pattern vertices may be bound
+/// to the same graph vertex. Use [`Pattern::isomorphic`] to require distinct vertices and edges.
+///
+/// # Example
+///
+/// ```rust
+#[doc = function_body!("examples/pattern.rs", example, [])]
+/// ```
+pub struct Pattern<'pattern, G>
+where
+    G: Graph,
+{
+    vertices: Vec<VertexPattern<'pattern, G>>,
+    edges: Vec<(PatternVertex, PatternVertex, EdgePattern<'pattern, G>)>,
+    isomorphic: bool,
+}
+
+impl<G> Default for Pattern<'_, G>
+where
+    G: Graph,
+{
+    fn default() -> Self {
+        Pattern {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            isomorphic: false,
+        }
+    }
+}
+
+impl<'pattern, G> Pattern<'pattern, G>
+where
+    G: Graph,
+{
+    /// Creates an empty pattern.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires every pattern vertex and every pattern edge to be bound to a distinct element.
+    pub fn isomorphic(mut self) -> Self {
+        self.isomorphic = true;
+        self
+    }
+
+    /// Adds a vertex to the pattern.
+    pub fn vertex<T: Into<VertexPattern<'pattern, G>>>(&mut self, vertex: T) -> PatternVertex {
+        self.vertices.push(vertex.into());
+        PatternVertex(self.vertices.len() - 1)
+    }
+
+    /// Adds an edge between two vertices of the pattern.
+    ///
+    /// The direction of the edge search is relative to `from`, so `Edge::knows().outgoing()`
+    /// matches edges from `from` to `to`, `.incoming()` matches edges from `to` to `from`, and
+    /// a search in both directions matches either.
+    ///
+    /// # Panics
+    /// If either vertex was not added to this pattern.
+    pub fn edge<T: Into<EdgePattern<'pattern, G>>>(
+        &mut self,
+        from: PatternVertex,
+        to: PatternVertex,
+        edge: T,
+    ) -> PatternEdge {
+        assert!(
+            from.0 < self.vertices.len() && to.0 < self.vertices.len(),
+            "pattern edge must connect vertices of the same pattern"
+        );
+        self.edges.push((from, to, edge.into()));
+        PatternEdge(self.edges.len() - 1)
+    }
+
+    /// Returns an iterator over every match of the pattern in the graph.
+    ///
+    /// Matches are found lazily, stopping early avoids searching the rest of the graph.
+    /// An empty pattern has no matches.
+    pub fn matches<'a>(&'a self, graph: &'a G) -> Matches<'a, 'pattern, G> {
+        Matches::new(self, graph)
+    }
+
+    /// Orders the pattern into steps so that every step after an anchor starts from a bound vertex.
+    fn plan(&self) -> Vec<Step> {
+        let mut bound = vec![false; self.vertices.len()];
+        let mut expanded = vec![false; self.edges.len()];
+        let mut steps = Vec::with_capacity(self.vertices.len() + self.edges.len());
+        loop {
+            // Edges that close a cycle are cheap to check so they go first
+            let next_edge = (0..self.edges.len())
+                .filter(|edge| !expanded[*edge])
+                .filter(|edge| {
+                    let (from, to, _) = &self.edges[*edge];
+                    bound[from.0] || bound[to.0]
+                })
+                .min_by_key(|edge| {
+                    let (from, to, _) = &self.edges[*edge];
+                    !bound[from.0] || !bound[to.0]
+                });
+            if let Some(edge) = next_edge {
+                let (from, to, _) = &self.edges[edge];
+                steps.push(Step::Expand {
+                    edge,
+                    reverse: !bound[from.0],
+                    closes: bound[from.0] && bound[to.0],
+                });
+                expanded[edge] = true;
+                bound[from.0] = true;
+                bound[to.0] = true;
+                continue;
+            }
+
+            match (0..self.vertices.len())
+                .filter(|vertex| !bound[*vertex])
+                .min_by_key(|vertex| selectivity(&self.vertices[*vertex].search))
+            {
+                Some(vertex) => {
+                    steps.push(Step::Anchor(vertex));
+                    bound[vertex] = true;
+                }
+                None => return steps,
+            }
+        }
+    }
+}
+
+/// Ranks searches so that the ones likely to return the fewest vertices come first.
+fn selectivity<G>(search: &VertexSearch<G>) -> usize
+where
+    G: Graph,
+{
+    match search {
+        VertexSearch::Index { .. } => 0,
+        VertexSearch::FullText { .. } => 1,
+        VertexSearch::Range { .. } => 2,
+        VertexSearch::Label { .. } => 3,
+        VertexSearch::Scan { .. } => 4,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    /// Binds a vertex by searching the graph.
+    Anchor(usize),
+    /// Binds an edge by following it from a bound vertex. Unless the edge closes a cycle the
+    /// vertex at the far end is bound too.
+    Expand {
+        edge: usize,
+        reverse: bool,
+        closes: bool,
+    },
+}
+
+/// A single match of a [`Pattern`].
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Clone(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct Match<G>
+where
+    G: Graph,
+{
+    vertices: Vec<G::VertexId>,
+    edges: Vec<G::EdgeId>,
+}
+
+impl<G> Match<G>
+where
+    G: Graph,
+{
+    /// Returns the graph vertex that the pattern vertex was bound to.
+    pub fn vertex(&self, vertex: PatternVertex) -> G::VertexId {
+        self.vertices[vertex.0]
+    }
+
+    /// Returns the graph edge that the pattern edge was bound to.
+    pub fn edge(&self, edge: PatternEdge) -> G::EdgeId {
+        self.edges[edge.0]
+    }
+
+    /// Returns the graph vertices of the match, in the order that the pattern vertices were added.
+    pub fn vertices(&self) -> &[G::VertexId] {
+        &self.vertices
+    }
+
+    /// Returns the graph edges of the match, in the order that the pattern edges were added.
+    pub fn edges(&self) -> &[G::EdgeId] {
+        &self.edges
+    }
+}
+
+/// The candidates for one step of the plan and how many have been tried.
+struct Level<G>
+where
+    G: Graph,
+{
+    candidates: Vec<(Option<G::EdgeId>, G::VertexId)>,
+    position: usize,
+}
+
+/// An iterator over the matches of a [`Pattern`], created by [`Pattern::matches`].
+pub struct Matches<'a, 'pattern, G>
+where
+    G: Graph,
+{
+    pattern: &'a Pattern<'pattern, G>,
+    graph: &'a G,
+    steps: Vec<Step>,
+    /// Vertices found by index searches of pattern vertices that are not used as anchors.
+    members: Vec<Option<HashSet<G::VertexId>>>,
+    vertices: Vec<Option<G::VertexId>>,
+    edges: Vec<Option<G::EdgeId>>,
+    levels: Vec<Level<G>>,
+    started: bool,
+}
+
+impl<'a, 'pattern, G> Matches<'a, 'pattern, G>
+where
+    G: Graph,
+{
+    fn new(pattern: &'a Pattern<'pattern, G>, graph: &'a G) -> Self {
+        let steps = pattern.plan();
+        let members = pattern
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(vertex, vertex_pattern)| match vertex_pattern.search {
+                VertexSearch::Scan { .. } | VertexSearch::Label { .. } => None,
+                _ if steps
+                    .iter()
+                    .any(|step| matches!(step, Step::Anchor(anchor) if *anchor == vertex)) =>
+                {
+                    None
+                }
+                _ => Some(
+                    graph
+                        .vertices(&vertex_pattern.search)
+                        .map(|vertex| vertex.id())
+                        .collect(),
+                ),
+            })
+            .collect();
+
+        Matches {
+            pattern,
+            graph,
+            steps,
+            members,
+            vertices: vec![None; pattern.vertices.len()],
+            edges: vec![None; pattern.edges.len()],
+            levels: Vec::new(),
+            started: false,
+        }
+    }
+
+    fn accepts_vertex(&self, vertex: usize, reference: &G::VertexReference<'_>) -> bool {
+        let vertex_pattern = &self.pattern.vertices[vertex];
+        let in_search = match &vertex_pattern.search {
+            VertexSearch::Label { label, .. } => reference.weight().label() == *label,
+            _ => self.members[vertex]
+                .as_ref()
+                .is_none_or(|members| members.contains(&reference.id())),
+        };
+        in_search
+            && vertex_pattern.accepts(reference)
+            && (!self.pattern.isomorphic || !self.vertices.contains(&Some(reference.id())))
+    }
+
+    fn candidates(&self, step: Step) -> Vec<(Option<G::EdgeId>, G::VertexId)> {
+        match step {
+            Step::Anchor(vertex) => self
+                .graph
+                .vertices(&self.pattern.vertices[vertex].search)
+                .filter(|reference| self.accepts_vertex(vertex, reference))
+                .map(|reference| (None, reference.id()))
+                .collect(),
+            Step::Expand {
+                edge,
+                reverse,
+                closes,
+            } => {
+                let (from, to, edge_pattern) = &self.pattern.edges[edge];
+                let (near, far) = if reverse { (to, from) } else { (from, to) };
+                let origin = self.vertices[near.0].expect("plan must bind the near end first");
+                let has_adjacent_label = |id: G::VertexId| {
+                    edge_pattern.search.adjacent_label.is_none_or(|label| {
+                        self.graph
+                            .vertex(id)
+                            .is_some_and(|vertex| vertex.weight().label() == label)
+                    })
+                };
+
+                let mut search = edge_pattern.search.clone();
+                search.limit = None;
+                if reverse {
+                    // The adjacent label refers to `to`, which is where we are starting from
+                    if !has_adjacent_label(origin) {
+                        return Vec::new();
+                    }
+                    search.adjacent_label = None;
+                    search.direction = match search.direction {
+                        Direction::Outgoing => Direction::Incoming,
+                        Direction::Incoming => Direction::Outgoing,
+                        Direction::All => Direction::All,
+                    };
+                }
+
+                self.graph
+                    .edges(origin, &search)
+                    .filter(|reference| {
+                        search
+                            .label
+                            .is_none_or(|label| reference.weight().label() == label)
+                    })
+                    .filter(|reference| edge_pattern.accepts(reference))
+                    .filter(|reference| {
+                        !self.pattern.isomorphic || !self.edges.contains(&Some(reference.id()))
+                    })
+                    .filter_map(|reference| {
+                        let other = if reference.tail() == origin {
+                            reference.head()
+                        } else {
+                            reference.tail()
+                        };
+                        if !reverse && !has_adjacent_label(other) {
+                            return None;
+                        }
+                        let accepted = if closes {
+                            self.vertices[far.0] == Some(other)
+                        } else {
+                            self.graph
+                                .vertex(other)
+                                .is_some_and(|vertex| self.accepts_vertex(far.0, &vertex))
+                        };
+                        accepted.then_some((Some(reference.id()), other))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn bind(&mut self, step: Step, edge: Option<G::EdgeId>, vertex: Option<G::VertexId>) {
+        match step {
+            Step::Anchor(anchor) => self.vertices[anchor] = vertex,
+            Step::Expand {
+                edge: expanded,
+                reverse,
+                closes,
+            } => {
+                self.edges[expanded] = edge;
+                if !closes {
+                    let (from, to, _) = &self.pattern.edges[expanded];
+                    let far = if reverse { from } else { to };
+                    self.vertices[far.0] = vertex;
+                }
+            }
+        }
+    }
+}
+
+impl<G> Iterator for Matches<'_, '_, G>
+where
+    G: Graph,
+{
+    type Item = Match<G>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            let first = *self.steps.first()?;
+            let candidates = self.candidates(first);
+            self.levels.push(Level {
+                candidates,
+                position: 0,
+            });
+        }
+
+        loop {
+            let depth = self.levels.len().checked_sub(1)?;
+            let step = self.steps[depth];
+            // Undo the previous candidate at this depth before trying the next one
+            self.bind(step, None, None);
+            let level = &mut self.levels[depth];
+            let Some(&(edge, vertex)) = level.candidates.get(level.position) else {
+                self.levels.pop();
+                continue;
+            };
+            level.position += 1;
+            self.bind(step, edge, Some(vertex));
+
+            match self.steps.get(depth + 1) {
+                Some(next) => {
+                    let candidates = self.candidates(*next);
+                    self.levels.push(Level {
+                        candidates,
+                        position: 0,
+                    });
+                }
+                None => {
+                    return Some(Match {
+                        vertices: self.vertices.iter().flatten().copied().collect(),
+                        edges: self.edges.iter().flatten().copied().collect(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::graph::{EdgeReferenceMut, VertexReference, VertexReferenceMut};
 use crate::search::vertex::VertexSearch;
 use crate::{Direction, EdgeReference, Element, ElementId, Graph, Project, ProjectMut};
-use crate::{EdgeSearch, SupportsClear, SupportsElementRemoval};
+use crate::{EdgeSearch, SupportsClear, SupportsDenseVertexIds, SupportsElementRemoval};
+use crate::{Label, SupportsParallelScan, VertexPartition};
 use petgraph::EdgeType;
 use petgraph::stable_graph::StableGraph;
 use petgraph::stable_graph::{EdgeIndex, Edges, IndexType};
 use petgraph::stable_graph::{NodeIndex, NodeIndices};
-use petgraph::visit::EdgeRef;
+use petgraph::visit::{EdgeRef, NodeIndexable};
 /// Implement Graph for `petgraph::StableGraph`.
 /// Ideally implementations for specific graphs are not included, petgraph is the most popular graph in Rust..
 /// If ever petgraph decided to implement graph-api directly then this could be removed as a private implementation detail of the graph-api-tests crate.
@@ -173,6 +174,61 @@ where
     }
 }
 
+impl<Vertex, Edge, Ty, Ix> SupportsDenseVertexIds for StableGraph<Vertex, Edge, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    Vertex: Element,
+    Edge: Element,
+{
+    fn dense_vertex_bound(&self) -> usize {
+        self.node_bound()
+    }
+
+    fn dense_vertex_index(&self, id: Self::VertexId) -> usize {
+        id.index()
+    }
+}
+
+impl<Vertex, Edge, Ty, Ix> SupportsParallelScan for StableGraph<Vertex, Edge, Ty, Ix>
+where
+    Ty: EdgeType + Sync,
+    Ix: IndexType + Send + Sync,
+    Vertex: Element + Sync,
+    Edge: Element + Sync,
+{
+    fn vertex_partitions<'graph>(
+        &'graph self,
+        search: &VertexSearch<'_, Self>,
+        partitions: usize,
+    ) -> Vec<VertexPartition<'graph, Self>> {
+        let label = match search {
+            VertexSearch::Scan { limit: None } => None,
+            VertexSearch::Label { label, limit: None } => Some(label.ordinal()),
+            _ => {
+                // Index lookups are already narrow and limits must be applied in order, so these stay whole
+                let ids: Vec<Self::VertexId> = self.vertices(search).map(|v| v.id()).collect();
+                return vec![Box::new(ids.into_iter()) as VertexPartition<'graph, Self>];
+            }
+        };
+
+        // Split the node index space into ranges, skipping removed nodes as we go
+        let bound = self.node_bound();
+        let size = bound.div_ceil(partitions.max(1)).max(1);
+        (0..bound)
+            .step_by(size)
+            .map(|start| {
+                let end = (start + size).min(bound);
+                Box::new((start..end).map(NodeIndex::new).filter(move |id| {
+                    self.node_weight(*id).is_some_and(|weight| {
+                        label.is_none_or(|label| weight.label().ordinal() == label)
+                    })
+                })) as VertexPartition<'graph, Self>
+            })
+            .collect()
+    }
+}
+
 impl<Graph, Ix> From<NodeIndex<Ix>> for ElementId<Graph>
 where
     Graph: crate::Graph<VertexId = NodeIndex<Ix>>,
@@ -234,10 +290,10 @@ where
                     .node_weight(node)
                     .expect("node weight should exist"),
             }) {
-                if let VertexSearch::Label { label, .. } = &self.vertex_search {
-                    if *label != Element::label(next.weight()) {
-                        continue;
-                    }
+                if let VertexSearch::Label { label, .. } = &self.vertex_search
+                    && *label != Element::label(next.weight())
+                {
+                    continue;
                 }
 
                 self.count += 1;
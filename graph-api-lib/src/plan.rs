@@ -0,0 +1,718 @@
+//! Reified walker pipelines and a rule-based optimizer.
+//!
+//! Walkers are built from closures, which makes them fast but opaque. A [`Plan`] describes the
+//! same kind of pipeline as data instead: a starting vertex search followed by steps such as
+//! label and property filters, `edges`, `head`, `tail`, `take` and `dedup`. Because the steps
+//! can be inspected, [`Plan::optimize`] can rewrite them into cheaper lookups:
+//!
+//! - A label filter directly after a scan becomes a `VertexSearch::Label`
+//! - An equality filter on a hash or range indexed property becomes a `VertexSearch::Index`
+//! - A range filter on a range indexed property becomes a `VertexSearch::Range`
+//! - A `take` directly after the start becomes the search limit, and a `take` after `edges`
+//!   limits the edges visited per vertex
+//!
+//! When a filter could have been a lookup but wasn't, the optimizer returns [`Advice`]
+//! describing the index or graph support that would have helped.
+
+use crate::walker::profiler::{Access, Explain, StepInfo};
+use crate::{
+    EdgeReference, EdgeSearch, Element, ElementId, Graph, Index, IndexType, Label, Value,
+    VertexReference, VertexSearch,
+};
+use derivative::Derivative;
+use include_doc::function_body;
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+type VertexLabel<G> = <<G as Graph>::Vertex as Element>::Label;
+type VertexIndex<G> = <VertexLabel<G> as Label>::Index;
+type EdgeLabel<G> = <<G as Graph>::Edge as Element>::Label;
+type EdgeIndex<G> = <EdgeLabel<G> as Label>::Index;
+
+/// A comparison of an indexed property against a value.
+///
+/// Numbers compare by value regardless of their type, so `Predicate::Eq(45i32.into())` matches
+/// a `u64` property holding 45.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate<'a> {
+    Eq(Value<'a>),
+    Ne(Value<'a>),
+    Lt(Value<'a>),
+    Le(Value<'a>),
+    Gt(Value<'a>),
+    Ge(Value<'a>),
+    /// Matches values in the half open range `start..end`.
+    Range(Range<Value<'a>>),
+}
+
+impl Predicate<'_> {
+    /// Returns true if the value satisfies the predicate.
+    pub fn test(&self, value: &Value) -> bool {
+        match self {
+            Predicate::Eq(expected) => value == expected,
+            Predicate::Ne(expected) => value != expected,
+            Predicate::Lt(bound) => value < bound,
+            Predicate::Le(bound) => value <= bound,
+            Predicate::Gt(bound) => value > bound,
+            Predicate::Ge(bound) => value >= bound,
+            Predicate::Range(range) => &range.start <= value && value < &range.end,
+        }
+    }
+}
+
+/// A step of a [`Plan`].
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+#[non_exhaustive]
+pub enum Step<'a, G>
+where
+    G: Graph,
+{
+    /// Keeps vertices with the label.
+    FilterLabel(VertexLabel<G>),
+    /// Keeps vertices where the indexed property satisfies the predicate.
+    FilterProperty {
+        index: VertexIndex<G>,
+        predicate: Predicate<'a>,
+    },
+    /// Moves from each vertex to the edges found by the search.
+    Edges(EdgeSearch<'a, G>),
+    /// Keeps edges with the label.
+    FilterEdgeLabel(EdgeLabel<G>),
+    /// Keeps edges where the indexed property satisfies the predicate.
+    FilterEdgeProperty {
+        index: EdgeIndex<G>,
+        predicate: Predicate<'a>,
+    },
+    /// Moves from each edge to the vertex it points to.
+    Head,
+    /// Moves from each edge to the vertex it starts from.
+    Tail,
+    /// Keeps the first `n` elements.
+    Take(usize),
+    /// Removes elements that have already been seen.
+    Dedup,
+}
+
+/// Where a [`Plan`] starts.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub enum Start<'a, G>
+where
+    G: Graph,
+{
+    Vertices(VertexSearch<'a, G>),
+    VerticesById(Vec<G::VertexId>),
+}
+
+/// The searches that a graph supports, used by [`Plan::optimize`] to decide which rewrites are
+/// possible.
+///
+/// Graph support is expressed with marker traits such as `SupportsVertexHashIndex`, which can't
+/// be queried at runtime, so it is described here instead. The default supports nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Capabilities {
+    /// The graph implements `SupportsVertexLabelIndex`.
+    pub vertex_label_index: bool,
+    /// The graph implements `SupportsVertexHashIndex`.
+    pub vertex_hash_index: bool,
+    /// The graph implements `SupportsVertexRangeIndex`.
+    pub vertex_range_index: bool,
+}
+
+impl Capabilities {
+    /// A graph that supports every search, such as `SimpleGraph`.
+    pub fn all() -> Self {
+        Capabilities {
+            vertex_label_index: true,
+            vertex_hash_index: true,
+            vertex_range_index: true,
+        }
+    }
+
+    /// A graph that can only scan.
+    pub fn none() -> Self {
+        Capabilities::default()
+    }
+
+    fn supports(&self, index_type: IndexType) -> bool {
+        match index_type {
+            IndexType::Hash => self.vertex_hash_index,
+            IndexType::Range => self.vertex_range_index,
+            _ => false,
+        }
+    }
+}
+
+/// Explains why a filter in a plan could not be turned into a lookup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Advice {
+    /// A label filter could be a label lookup, but the graph has no vertex label index.
+    LabelIndex { label: &'static str },
+    /// A property filter could use an index of this type, but the graph doesn't support it.
+    UnsupportedIndex {
+        label: &'static str,
+        property: &'static str,
+        index_type: IndexType,
+    },
+    /// A property filter would be a lookup if the property had an index of the needed type.
+    IndexType {
+        label: &'static str,
+        property: &'static str,
+        needed: IndexType,
+        actual: IndexType,
+    },
+    /// The value in a property filter can't be converted to the type of the property.
+    ValueType {
+        label: &'static str,
+        property: &'static str,
+    },
+}
+
+impl Display for Advice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Advice::LabelIndex { label } => write!(
+                f,
+                "filter on label {} would use a label index, but the graph does not support vertex label indexes",
+                label
+            ),
+            Advice::UnsupportedIndex {
+                label,
+                property,
+                index_type,
+            } => write!(
+                f,
+                "filter on {}.{} would use its {} index, but the graph does not support vertex {} indexes",
+                label, property, index_type, index_type
+            ),
+            Advice::IndexType {
+                label,
+                property,
+                needed,
+                actual,
+            } => write!(
+                f,
+                "filter on {}.{} would use a {} index, but the property has a {} index",
+                label, property, needed, actual
+            ),
+            Advice::ValueType { label, property } => write!(
+                f,
+                "filter on {}.{} compares against a value of a different type",
+                label, property
+            ),
+        }
+    }
+}
+
+/// The result of [`Plan::optimize`].
+pub struct Optimized<'a, G>
+where
+    G: Graph,
+{
+    /// The rewritten plan, which returns the same elements as the original.
+    pub plan: Plan<'a, G>,
+    /// Filters that could not be rewritten into lookups, and what would help.
+    pub advice: Vec<Advice>,
+}
+
+/// A walker pipeline described as data so that it can be inspected and optimized.
+///
+/// Plans are built with the same step names as walkers and run with [`Plan::execute`]. Filters
+/// compare indexed properties, as those are the only properties that can be read without
+/// knowing the vertex type.
+///
+/// # Example
+///
+/// ```rust
+#[doc = function_body!("examples/plan.rs", example, [])]
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct Plan<'a, G>
+where
+    G: Graph,
+{
+    start: Start<'a, G>,
+    steps: Vec<Step<'a, G>>,
+}
+
+impl<'a, G> Plan<'a, G>
+where
+    G: Graph,
+{
+    /// Starts a plan with the vertices found by a search.
+    pub fn vertices<T: Into<VertexSearch<'a, G>>>(search: T) -> Self {
+        Plan {
+            start: Start::Vertices(search.into()),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Starts a plan with the vertices with the given ids.
+    pub fn vertices_by_id(ids: impl IntoIterator<Item = G::VertexId>) -> Self {
+        Plan {
+            start: Start::VerticesById(ids.into_iter().collect()),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Returns where the plan starts.
+    pub fn start(&self) -> &Start<'a, G> {
+        &self.start
+    }
+
+    /// Returns the steps of the plan, in order.
+    pub fn steps(&self) -> &[Step<'a, G>] {
+        &self.steps
+    }
+
+    /// Returns true if the plan currently ends on vertices rather than edges.
+    pub fn on_vertices(&self) -> bool {
+        self.steps
+            .iter()
+            .fold(true, |on_vertices, step| match step {
+                Step::Edges(_) => false,
+                Step::Head | Step::Tail => true,
+                _ => on_vertices,
+            })
+    }
+
+    /// Keeps vertices with the label.
+    ///
+    /// # Panics
+    /// If the plan is on edges.
+    pub fn filter_label(self, label: VertexLabel<G>) -> Self {
+        self.vertex_step(Step::FilterLabel(label))
+    }
+
+    /// Keeps vertices where the indexed property satisfies the predicate.
+    ///
+    /// # Panics
+    /// If the plan is on edges.
+    pub fn filter_property(self, index: VertexIndex<G>, predicate: Predicate<'a>) -> Self {
+        self.vertex_step(Step::FilterProperty { index, predicate })
+    }
+
+    /// Moves from each vertex to the edges found by the search.
+    ///
+    /// # Panics
+    /// If the plan is on edges.
+    pub fn edges(self, search: EdgeSearch<'a, G>) -> Self {
+        self.vertex_step(Step::Edges(search))
+    }
+
+    /// Keeps edges with the label.
+    ///
+    /// # Panics
+    /// If the plan is on vertices.
+    pub fn filter_edge_label(self, label: EdgeLabel<G>) -> Self {
+        self.edge_step(Step::FilterEdgeLabel(label))
+    }
+
+    /// Keeps edges where the indexed property satisfies the predicate.
+    ///
+    /// # Panics
+    /// If the plan is on vertices.
+    pub fn filter_edge_property(self, index: EdgeIndex<G>, predicate: Predicate<'a>) -> Self {
+        self.edge_step(Step::FilterEdgeProperty { index, predicate })
+    }
+
+    /// Moves from each edge to the vertex it points to.
+    ///
+    /// # Panics
+    /// If the plan is on vertices.
+    pub fn head(self) -> Self {
+        self.edge_step(Step::Head)
+    }
+
+    /// Moves from each edge to the vertex it starts from.
+    ///
+    /// # Panics
+    /// If the plan is on vertices.
+    pub fn tail(self) -> Self {
+        self.edge_step(Step::Tail)
+    }
+
+    /// Keeps the first `n` elements.
+    pub fn take(mut self, n: usize) -> Self {
+        self.steps.push(Step::Take(n));
+        self
+    }
+
+    /// Removes elements that have already been seen.
+    pub fn dedup(mut self) -> Self {
+        self.steps.push(Step::Dedup);
+        self
+    }
+
+    fn vertex_step(mut self, step: Step<'a, G>) -> Self {
+        assert!(self.on_vertices(), "step must follow a step on vertices");
+        self.steps.push(step);
+        self
+    }
+
+    fn edge_step(mut self, step: Step<'a, G>) -> Self {
+        assert!(!self.on_vertices(), "step must follow a step on edges");
+        self.steps.push(step);
+        self
+    }
+
+    /// Describes the steps of the plan in the same form as the `explain` step of a walker.
+    pub fn explain(&self) -> Explain {
+        let mut steps = vec![match &self.start {
+            Start::Vertices(search) => StepInfo::vertices("vertices", search),
+            Start::VerticesById(_) => {
+                StepInfo::new("vertices_by_id").with_access(Access::VertexIds)
+            }
+        }];
+        steps.extend(self.steps.iter().map(|step| match step {
+            Step::FilterLabel(_)
+            | Step::FilterProperty { .. }
+            | Step::FilterEdgeLabel(_)
+            | Step::FilterEdgeProperty { .. } => StepInfo::new("filter"),
+            Step::Edges(search) => StepInfo::edges("edges", search),
+            Step::Head => StepInfo::new("head"),
+            Step::Tail => StepInfo::new("tail"),
+            Step::Take(_) => StepInfo::new("take"),
+            Step::Dedup => StepInfo::new("dedup"),
+        }));
+        Explain { steps }
+    }
+
+    /// Runs the plan, returning the ids of the elements it ends on.
+    pub fn execute(&self, graph: &G) -> Vec<ElementId<G>> {
+        let mut elements: Vec<ElementId<G>> = match &self.start {
+            Start::Vertices(search) => graph
+                .vertices(search)
+                .map(|vertex| ElementId::Vertex(vertex.id()))
+                .collect(),
+            Start::VerticesById(ids) => ids
+                .iter()
+                .filter(|id| graph.vertex(**id).is_some())
+                .map(|id| ElementId::Vertex(*id))
+                .collect(),
+        };
+
+        for step in &self.steps {
+            match step {
+                Step::FilterLabel(label) => elements.retain(|element| {
+                    vertex_of(graph, element)
+                        .is_some_and(|vertex| vertex.weight().label() == *label)
+                }),
+                Step::FilterProperty { index, predicate } => elements.retain(|element| {
+                    vertex_of(graph, element).is_some_and(|vertex| {
+                        vertex
+                            .weight()
+                            .value(index)
+                            .is_some_and(|value| predicate.test(&value))
+                    })
+                }),
+                Step::Edges(search) => {
+                    elements = elements
+                        .iter()
+                        .filter_map(|element| match element {
+                            ElementId::Vertex(id) => Some(*id),
+                            ElementId::Edge(_) => None,
+                        })
+                        .flat_map(|id| {
+                            graph
+                                .edges(id, search)
+                                .map(|edge| ElementId::Edge(edge.id()))
+                                .collect::<Vec<_>>()
+                        })
+                        .collect()
+                }
+                Step::FilterEdgeLabel(label) => elements.retain(|element| {
+                    edge_of(graph, element).is_some_and(|edge| edge.weight().label() == *label)
+                }),
+                Step::FilterEdgeProperty { index, predicate } => elements.retain(|element| {
+                    edge_of(graph, element).is_some_and(|edge| {
+                        edge.weight()
+                            .value(index)
+                            .is_some_and(|value| predicate.test(&value))
+                    })
+                }),
+                Step::Head => {
+                    elements = elements
+                        .iter()
+                        .filter_map(|element| edge_of(graph, element))
+                        .map(|edge| ElementId::Vertex(edge.head()))
+                        .collect()
+                }
+                Step::Tail => {
+                    elements = elements
+                        .iter()
+                        .filter_map(|element| edge_of(graph, element))
+                        .map(|edge| ElementId::Vertex(edge.tail()))
+                        .collect()
+                }
+                Step::Take(n) => elements.truncate(*n),
+                Step::Dedup => {
+                    let mut seen = HashSet::new();
+                    elements.retain(|element| seen.insert(*element));
+                }
+            }
+        }
+        elements
+    }
+
+    /// Rewrites the plan into an equivalent one that uses the lookups the graph supports.
+    ///
+    /// Only the filters directly after a `vertices` start can become lookups. When several
+    /// property filters qualify, an equality filter is preferred over a range. Filters that
+    /// couldn't be rewritten are reported as [`Advice`] if a different index or graph would
+    /// have allowed it.
+    ///
+    /// A filter that becomes a lookup is kept as a check on its results. Graphs may answer a
+    /// search they don't support with a scan, so `capabilities` only decides which lookups are
+    /// worth trying and a wrong description costs speed rather than correctness.
+    pub fn optimize(mut self, capabilities: &Capabilities) -> Optimized<'a, G> {
+        let mut advice = Vec::new();
+
+        if let Start::Vertices(search) = &mut self.start {
+            let prefix = self
+                .steps
+                .iter()
+                .take_while(|step| {
+                    matches!(step, Step::FilterLabel(_) | Step::FilterProperty { .. })
+                })
+                .count();
+            let rest = self.steps.split_off(prefix);
+            let mut filters = std::mem::take(&mut self.steps);
+            let start_label = match search {
+                VertexSearch::Scan { limit: None } => Some(None),
+                VertexSearch::Label { label, limit: None } => Some(Some(*label)),
+                // Filters apply after a limit, so a limited search can't absorb them
+                _ => None,
+            };
+
+            if let Some(start_label) = start_label {
+                match lookup(start_label, &filters, capabilities, &mut advice) {
+                    Some(lookup) => *search = lookup,
+                    None if start_label.is_none() => {
+                        let label = filters.iter().find_map(|step| match step {
+                            Step::FilterLabel(label) => Some(*label),
+                            _ => None,
+                        });
+                        match label {
+                            Some(label) if capabilities.vertex_label_index => {
+                                *search = VertexSearch::Label { label, limit: None };
+                            }
+                            Some(label) => advice.push(Advice::LabelIndex {
+                                label: label.name(),
+                            }),
+                            None => {}
+                        }
+                    }
+                    None => {}
+                }
+            }
+
+            filters.extend(rest);
+            self.steps = filters;
+
+            if let Some(&Step::Take(n)) = self.steps.first() {
+                *search = search.clone().with_limit(n.min(search.limit()));
+                self.steps.remove(0);
+            }
+        }
+
+        for position in 1..self.steps.len() {
+            let (before, after) = self.steps.split_at_mut(position);
+            if let (Some(Step::Edges(search)), Step::Take(n)) = (before.last_mut(), &after[0]) {
+                search.limit = Some(search.limit().min(*n));
+            }
+        }
+
+        Optimized { plan: self, advice }
+    }
+}
+
+fn vertex_of<'g, G>(graph: &'g G, element: &ElementId<G>) -> Option<G::VertexReference<'g>>
+where
+    G: Graph,
+{
+    match element {
+        ElementId::Vertex(id) => graph.vertex(*id),
+        ElementId::Edge(_) => None,
+    }
+}
+
+fn edge_of<'g, G>(graph: &'g G, element: &ElementId<G>) -> Option<G::EdgeReference<'g>>
+where
+    G: Graph,
+{
+    match element {
+        ElementId::Edge(id) => graph.edge(*id),
+        ElementId::Vertex(_) => None,
+    }
+}
+
+/// Returns the label that an index belongs to.
+fn owner<G>(index: &VertexIndex<G>) -> Option<VertexLabel<G>>
+where
+    G: Graph,
+{
+    VertexLabel::<G>::variants()
+        .iter()
+        .find(|label| label.indexes().contains(index))
+        .copied()
+}
+
+/// Finds the best property filter to turn into a lookup, returning the search that replaces the
+/// scan. Advice is only given if no filter qualifies.
+fn lookup<'a, G>(
+    start_label: Option<VertexLabel<G>>,
+    filters: &[Step<'a, G>],
+    capabilities: &Capabilities,
+    advice: &mut Vec<Advice>,
+) -> Option<VertexSearch<'a, G>>
+where
+    G: Graph,
+{
+    let mut best: Option<VertexSearch<'a, G>> = None;
+    let mut misses = Vec::new();
+    for step in filters {
+        let Step::FilterProperty { index, predicate } = step else {
+            continue;
+        };
+        let Some(label) = owner::<G>(index) else {
+            continue;
+        };
+        if start_label.is_some_and(|start_label| start_label != label) {
+            continue;
+        }
+        match property_search(label, index, predicate, capabilities) {
+            Ok(search) => {
+                let exact = matches!(search, VertexSearch::Index { .. });
+                let replace = match &best {
+                    None => true,
+                    Some(VertexSearch::Index { .. }) => false,
+                    Some(_) => exact,
+                };
+                if replace {
+                    best = Some(search);
+                }
+            }
+            Err(Some(miss)) => misses.push(miss),
+            Err(None) => {}
+        }
+    }
+    if best.is_none() {
+        advice.extend(misses);
+    }
+    best
+}
+
+/// Converts a property filter to a search, or returns the advice for why it can't be.
+fn property_search<'a, G>(
+    label: VertexLabel<G>,
+    index: &VertexIndex<G>,
+    predicate: &Predicate<'a>,
+    capabilities: &Capabilities,
+) -> Result<VertexSearch<'a, G>, Option<Advice>>
+where
+    G: Graph,
+{
+    let label = label.name();
+    let property = index.name();
+    let ty = index.ty();
+    let actual = index.index_type();
+    let require = |needed: IndexType| {
+        if actual != needed && !(needed == IndexType::Hash && actual == IndexType::Range) {
+            return Err(Some(Advice::IndexType {
+                label,
+                property,
+                needed,
+                actual,
+            }));
+        }
+        if !capabilities.supports(actual) {
+            return Err(Some(Advice::UnsupportedIndex {
+                label,
+                property,
+                index_type: actual,
+            }));
+        }
+        Ok(())
+    };
+    let coerce = |value: &Value<'a>| {
+        value
+            .coerce(ty)
+            .ok_or(Some(Advice::ValueType { label, property }))
+    };
+
+    match predicate {
+        Predicate::Eq(value) => {
+            require(IndexType::Hash)?;
+            Ok(VertexSearch::Index {
+                index: *index,
+                value: coerce(value)?,
+                limit: None,
+            })
+        }
+        Predicate::Range(range) => {
+            require(IndexType::Range)?;
+            Ok(VertexSearch::Range {
+                index: *index,
+                range: coerce(&range.start)?..coerce(&range.end)?,
+                limit: None,
+            })
+        }
+        Predicate::Lt(bound) => {
+            let Some(start) = minimum(ty) else {
+                return Err(None);
+            };
+            require(IndexType::Range)?;
+            Ok(VertexSearch::Range {
+                index: *index,
+                range: start..coerce(bound)?,
+                limit: None,
+            })
+        }
+        Predicate::Le(bound) => {
+            let Some(start) = minimum(ty) else {
+                return Err(None);
+            };
+            // Only integers have a next value to use as the exclusive end
+            let end = match bound.coerce(TypeId::of::<i128>()) {
+                Some(Value::I128(bound)) => bound.checked_add(1).map(Value::I128),
+                _ => None,
+            };
+            let Some(end) = end else {
+                return Err(None);
+            };
+            require(IndexType::Range)?;
+            Ok(VertexSearch::Range {
+                index: *index,
+                range: start..coerce(&end)?,
+                limit: None,
+            })
+        }
+        // Range searches have an exclusive end, so there is no end that includes every larger value
+        Predicate::Ne(_) | Predicate::Gt(_) | Predicate::Ge(_) => Err(None),
+    }
+}
+
+/// The smallest value of a type, used as the start of a range with no lower bound.
+fn minimum(ty: TypeId) -> Option<Value<'static>> {
+    macro_rules! min_value {
+        ($($ty: ty => $variant: ident),*) => {
+            $(
+                if ty == TypeId::of::<$ty>() {
+                    return Some(Value::$variant(<$ty>::MIN));
+                }
+            )*
+        };
+    }
+    min_value!(usize => USize, u128 => U128, u64 => U64, u32 => U32, u16 => U16, u8 => U8,
+        i128 => I128, i64 => I64, i32 => I32, i16 => I16, i8 => I8);
+    if ty == TypeId::of::<String>() {
+        return Some(Value::Str(""));
+    }
+    None
+}
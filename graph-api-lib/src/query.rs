@@ -0,0 +1,1118 @@
+//! Queries built at runtime.
+//!
+//! Walkers are assembled from closures at compile time, so a traversal that depends on user
+//! input would need a match over every combination of steps. A [`Query`] describes a traversal
+//! as plain data instead: where to start, a list of steps, and what to return. Labels and
+//! properties are referred to by name, so a query doesn't depend on the graph's types and can
+//! be stored, sent over the wire and replayed. With the `serde` feature enabled every type in
+//! this module can be serialized.
+//!
+//! Steps can name the element they are on with [`Step::As`], so that a later [`Step::Where`]
+//! or a [`Terminal::Select`] can refer to elements along the path. This covers the patterns of
+//! query languages such as Cypher and Gremlin, which compile to a `Query`.
+//!
+//! [`Query::execute`] resolves the names against the graph and drives boxed walkers from the
+//! steps. The start, along with the label and property filters right after it, goes through
+//! the [`plan`](crate::plan) optimizer, so index lookups are only used when the graph supports
+//! them.
+
+use crate::plan::{self, Capabilities, Plan};
+use crate::{
+    Direction, EdgeReference, EdgeSearch, EdgeWalkerBuilder, Element, ElementId, Graph, Index,
+    Label, RebindEdgeWalker, RebindVertexWalker, Value, VertexReference, VertexSearch,
+    VertexWalkerBuilder,
+};
+use include_doc::function_body;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+type VertexLabel<G> = <<G as Graph>::Vertex as Element>::Label;
+type EdgeLabel<G> = <<G as Graph>::Edge as Element>::Label;
+
+/// An owned value that a query compares properties against.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Literal {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+    Uuid(Uuid),
+}
+
+impl Literal {
+    /// Returns the literal as a [`Value`].
+    pub fn value(&self) -> Value<'_> {
+        match self {
+            Literal::Bool(value) => Value::Bool(*value),
+            Literal::Int(value) => Value::I64(*value),
+            Literal::UInt(value) => Value::U64(*value),
+            Literal::Float(value) => Value::F64(*value),
+            Literal::Str(value) => Value::Str(value),
+            Literal::Uuid(value) => Value::Uuid(*value),
+        }
+    }
+}
+
+macro_rules! literal {
+    ($ty: ty, $ident: ident) => {
+        impl From<$ty> for Literal {
+            fn from(value: $ty) -> Self {
+                Literal::$ident(value.into())
+            }
+        }
+    };
+}
+
+literal!(bool, Bool);
+literal!(i64, Int);
+literal!(i32, Int);
+literal!(u64, UInt);
+literal!(u32, UInt);
+literal!(f64, Float);
+literal!(String, Str);
+literal!(&str, Str);
+literal!(Uuid, Uuid);
+
+/// A test of a property. Every predicate except `Exists` is false if the property is missing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Predicate {
+    Exists,
+    Eq(Literal),
+    Ne(Literal),
+    Lt(Literal),
+    Le(Literal),
+    Gt(Literal),
+    Ge(Literal),
+    /// Between `start` inclusive and `end` exclusive.
+    Range {
+        start: Literal,
+        end: Literal,
+    },
+    Not(Box<Predicate>),
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+}
+
+impl Predicate {
+    /// Returns true if the value of a property, or `None` if it is missing, satisfies the
+    /// predicate.
+    pub fn test(&self, value: Option<&Value>) -> bool {
+        let compare = |literal: &Literal, test: fn(&Value, &Value) -> bool| {
+            value.is_some_and(|value| test(value, &literal.value()))
+        };
+        match self {
+            Predicate::Exists => value.is_some(),
+            Predicate::Eq(literal) => compare(literal, |value, literal| value == literal),
+            Predicate::Ne(literal) => compare(literal, |value, literal| value != literal),
+            Predicate::Lt(literal) => compare(literal, |value, literal| value < literal),
+            Predicate::Le(literal) => compare(literal, |value, literal| value <= literal),
+            Predicate::Gt(literal) => compare(literal, |value, literal| value > literal),
+            Predicate::Ge(literal) => compare(literal, |value, literal| value >= literal),
+            Predicate::Range { start, end } => {
+                compare(start, |value, start| value >= start)
+                    && compare(end, |value, end| value < end)
+            }
+            Predicate::Not(predicate) => !predicate.test(value),
+            Predicate::And(predicates) => predicates.iter().all(|p| p.test(value)),
+            Predicate::Or(predicates) => predicates.iter().any(|p| p.test(value)),
+        }
+    }
+
+    /// The predicate in the form the optimizer understands, if an index could look it up.
+    fn lookup(&self) -> Option<plan::Predicate<'_>> {
+        Some(match self {
+            Predicate::Eq(literal) => plan::Predicate::Eq(literal.value()),
+            Predicate::Ne(literal) => plan::Predicate::Ne(literal.value()),
+            Predicate::Lt(literal) => plan::Predicate::Lt(literal.value()),
+            Predicate::Le(literal) => plan::Predicate::Le(literal.value()),
+            Predicate::Gt(literal) => plan::Predicate::Gt(literal.value()),
+            Predicate::Ge(literal) => plan::Predicate::Ge(literal.value()),
+            Predicate::Range { start, end } => plan::Predicate::Range(start.value()..end.value()),
+            Predicate::Exists | Predicate::Not(_) | Predicate::And(_) | Predicate::Or(_) => {
+                return None;
+            }
+        })
+    }
+}
+
+/// A value that a condition or a column refers to.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Operand {
+    Literal(Literal),
+    /// A property of the element named by [`Step::As`].
+    Property {
+        variable: String,
+        property: String,
+    },
+    /// The label of the element named by [`Step::As`].
+    Label(String),
+}
+
+/// How a condition compares two operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    fn test(self, left: &Value, right: &Value) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+        }
+    }
+}
+
+/// A test of the elements named along the path.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Condition {
+    /// Compares two operands. False if either of them is missing.
+    Compare(Operand, Comparison, Operand),
+    /// True if the operand is `true`.
+    Is(Operand),
+    Not(Box<Condition>),
+    And(Vec<Condition>),
+    Or(Vec<Condition>),
+}
+
+/// A sort key of [`Terminal::Select`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order {
+    pub operand: Operand,
+    pub descending: bool,
+}
+
+/// Where a query starts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Start {
+    /// Every vertex.
+    Scan,
+    /// Vertices with a label.
+    Label(String),
+    /// Vertices with a label where an indexed property equals a value.
+    Index {
+        label: String,
+        property: String,
+        value: Literal,
+    },
+    /// Vertices with a label where an indexed property is between `start` inclusive and `end`
+    /// exclusive.
+    Range {
+        label: String,
+        property: String,
+        start: Literal,
+        end: Literal,
+    },
+    /// Every edge.
+    Edges,
+}
+
+/// A step of a query, applied to the vertices or edges produced by the previous step.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Step {
+    /// Keeps elements with the label.
+    FilterLabel(String),
+    /// Keeps elements with any of the labels.
+    FilterLabels(Vec<String>),
+    /// Keeps elements where the property satisfies the predicate.
+    Filter {
+        property: String,
+        predicate: Predicate,
+    },
+    /// Moves from vertices to their edges, optionally only those with a label.
+    Edges {
+        label: Option<String>,
+        direction: Direction,
+    },
+    /// Moves from edges to the vertices they point to.
+    Head,
+    /// Moves from edges to the vertices they come from.
+    Tail,
+    /// Keeps the first `n` elements.
+    Take(usize),
+    /// Removes elements that were already visited.
+    Dedup,
+    /// Names the element so that conditions and columns can refer to it. If the name was
+    /// already given to an element on the path, keeps only that element.
+    As(String),
+    /// Keeps elements where the condition holds for the elements named so far.
+    Where(Condition),
+}
+
+/// What a query returns.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Terminal {
+    /// The ids of the elements.
+    #[default]
+    Collect,
+    /// The number of elements.
+    Count,
+    /// A row of property values for each element.
+    Values(Vec<String>),
+    /// A row of named operands for each element, sorted by `order` and paged by `skip` and
+    /// `limit`. Missing values sort last.
+    Select {
+        columns: Vec<(String, Operand)>,
+        order: Vec<Order>,
+        skip: usize,
+        limit: Option<usize>,
+    },
+}
+
+/// A traversal described as data.
+///
+/// # Example
+///
+/// ```rust
+#[doc = function_body!("examples/query.rs", example, [])]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Query {
+    pub start: Start,
+    pub steps: Vec<Step>,
+    pub terminal: Terminal,
+}
+
+/// Returned by [`Query::execute`] when the query doesn't fit the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueryError {
+    /// A vertex or edge label that the graph doesn't have.
+    UnknownLabel(String),
+    /// A name that no earlier [`Step::As`] gave to an element.
+    UnknownVariable(String),
+    /// The start of the query looks up a property that isn't indexed.
+    NotIndexed { label: String, property: String },
+    /// A step that can't be applied to what the previous step produced, such as `Head` on
+    /// vertices, or `As` on a vertex with a name already given to an edge.
+    Misplaced { step: usize },
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryError::UnknownLabel(label) => write!(f, "unknown label {}", label),
+            QueryError::UnknownVariable(variable) => write!(f, "unknown variable {}", variable),
+            QueryError::NotIndexed { label, property } => {
+                write!(f, "{}.{} is not indexed", label, property)
+            }
+            QueryError::Misplaced { step } => write!(
+                f,
+                "step {} can't be applied to the output of the previous step",
+                step
+            ),
+        }
+    }
+}
+
+impl Error for QueryError {}
+
+/// The result of [`Query::execute`].
+pub enum Output<'g, G>
+where
+    G: Graph,
+{
+    /// The vertices a [`Terminal::Collect`] query ended on.
+    Vertices(Vec<G::VertexId>),
+    /// The edges a [`Terminal::Collect`] query ended on.
+    Edges(Vec<G::EdgeId>),
+    /// The result of [`Terminal::Count`].
+    Count(usize),
+    /// The result of [`Terminal::Values`] and [`Terminal::Select`].
+    Rows(Rows<'g, G>),
+}
+
+/// The values of the elements a query ended on.
+///
+/// Values are borrowed from the graph, so rows are visited with [`Rows::for_each`] rather
+/// than returned.
+pub struct Rows<'g, G>
+where
+    G: Graph,
+{
+    graph: &'g G,
+    matches: Vec<Match<G>>,
+    columns: Vec<(&'g str, Term<'g>)>,
+}
+
+impl<G> Rows<'_, G>
+where
+    G: Graph,
+{
+    /// The column names, in the order they appear in each row. These are the property names
+    /// for [`Terminal::Values`].
+    pub fn columns(&self) -> Vec<&str> {
+        self.columns.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Returns true if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Calls `f` with the values of each row, in order. A value is `None` if the element
+    /// doesn't have the property.
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&[Option<Value>]),
+    {
+        for found in &self.matches {
+            let elements = Elements::new(self.graph, found);
+            let row = self
+                .columns
+                .iter()
+                .map(|(_, term)| elements.value(term))
+                .collect::<Vec<_>>();
+            f(&row);
+        }
+    }
+}
+
+impl Query {
+    /// Starts a query.
+    pub fn new(start: Start) -> Self {
+        Query {
+            start,
+            steps: Vec::new(),
+            terminal: Terminal::Collect,
+        }
+    }
+
+    /// Adds a step.
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Sets what the query returns.
+    pub fn terminal(mut self, terminal: Terminal) -> Self {
+        self.terminal = terminal;
+        self
+    }
+
+    /// Runs the query against a graph.
+    ///
+    /// The capabilities describe the searches the graph supports. A start, or label and
+    /// property filters right after it, that the graph can't look up fall back to a scan and a
+    /// filter.
+    pub fn execute<'g, G>(
+        &'g self,
+        graph: &'g G,
+        capabilities: &Capabilities,
+    ) -> Result<Output<'g, G>, QueryError>
+    where
+        G: Graph,
+    {
+        let compiled = Compiled::new(self)?;
+        let find = match &self.terminal {
+            Terminal::Count => Find::Count,
+            // Without ordering the first rows are final, so only those need to be found
+            Terminal::Select {
+                order,
+                skip,
+                limit: Some(limit),
+                ..
+            } if order.is_empty() => Find::First(skip.saturating_add(*limit)),
+            _ => Find::First(usize::MAX),
+        };
+        let found = walk(graph, &compiled, find, capabilities);
+        let Compiled { columns, order, .. } = compiled;
+        let Some(columns) = columns else {
+            return Ok(match found {
+                Found::Vertices(vertices) => {
+                    Output::Vertices(vertices.into_iter().map(|(id, _)| id).collect())
+                }
+                Found::Edges(edges) => Output::Edges(edges.into_iter().map(|(id, _)| id).collect()),
+                Found::Count(count) => Output::Count(count),
+            });
+        };
+
+        let mut matches = match found {
+            Found::Vertices(vertices) => vertices
+                .into_iter()
+                .map(|(id, bindings)| (ElementId::Vertex(id), bindings))
+                .collect::<Vec<_>>(),
+            Found::Edges(edges) => edges
+                .into_iter()
+                .map(|(id, bindings)| (ElementId::Edge(id), bindings))
+                .collect(),
+            Found::Count(_) => unreachable!("only count queries return a count"),
+        };
+        if let Terminal::Select { skip, limit, .. } = &self.terminal {
+            sort(graph, &order, &mut matches);
+            matches.drain(..(*skip).min(matches.len()));
+            if let Some(limit) = limit {
+                matches.truncate(*limit);
+            }
+        }
+        Ok(Output::Rows(Rows {
+            graph,
+            matches,
+            columns,
+        }))
+    }
+}
+
+/// The elements named by [`Step::As`], in the order the names first appear. Carried by the
+/// walker as its context.
+type Bindings<G> = Vec<Option<Named<<G as Graph>::VertexId, <G as Graph>::EdgeId>>>;
+
+/// A named element. Unlike [`ElementId`] this isn't generic over the graph, which a walker's
+/// context can't be.
+#[derive(Clone, Copy, PartialEq)]
+enum Named<VertexId, EdgeId> {
+    Vertex(VertexId),
+    Edge(EdgeId),
+}
+
+/// An element that a query ended on, along with the elements named on the way.
+type Match<G> = (ElementId<G>, Bindings<G>);
+
+/// How much the walker should find.
+#[derive(Clone, Copy)]
+enum Find {
+    Count,
+    First(usize),
+}
+
+/// What the walker found, before it is turned into an [`Output`].
+enum Found<G>
+where
+    G: Graph,
+{
+    Vertices(Vec<(G::VertexId, Bindings<G>)>),
+    Edges(Vec<(G::EdgeId, Bindings<G>)>),
+    Count(usize),
+}
+
+/// The element that an operand reads from.
+#[derive(Clone, Copy)]
+enum Source {
+    /// The element the query ended on.
+    Current,
+    /// A named element, by its position in the bindings.
+    Variable(usize),
+}
+
+/// An operand with its variable resolved.
+enum Term<'q> {
+    Value(Value<'q>),
+    Property(Source, &'q str),
+    Label(Source),
+}
+
+/// A condition with its variables resolved.
+enum Check<'q> {
+    Compare(Term<'q>, Comparison, Term<'q>),
+    Is(Term<'q>),
+    Not(Box<Check<'q>>),
+    And(Vec<Check<'q>>),
+    Or(Vec<Check<'q>>),
+}
+
+impl Check<'_> {
+    fn holds<G>(&self, elements: &Elements<G>) -> bool
+    where
+        G: Graph,
+    {
+        match self {
+            Check::Compare(left, comparison, right) => {
+                match (elements.value(left), elements.value(right)) {
+                    (Some(left), Some(right)) => comparison.test(&left, &right),
+                    _ => false,
+                }
+            }
+            Check::Is(term) => matches!(elements.value(term), Some(Value::Bool(true))),
+            Check::Not(check) => !check.holds(elements),
+            Check::And(checks) => checks.iter().all(|check| check.holds(elements)),
+            Check::Or(checks) => checks.iter().any(|check| check.holds(elements)),
+        }
+    }
+}
+
+/// A vertex or an edge, which values can be borrowed from.
+enum Reference<'g, G>
+where
+    G: Graph + 'g,
+{
+    Vertex(G::VertexReference<'g>),
+    Edge(G::EdgeReference<'g>),
+}
+
+impl<'g, G> Reference<'g, G>
+where
+    G: Graph,
+{
+    fn new(graph: &'g G, id: ElementId<G>) -> Option<Self> {
+        match id {
+            ElementId::Vertex(id) => graph.vertex(id).map(Reference::Vertex),
+            ElementId::Edge(id) => graph.edge(id).map(Reference::Edge),
+        }
+    }
+
+    fn read(&self, name: &str) -> Option<Value<'_>> {
+        match self {
+            Reference::Vertex(vertex) => vertex.weight().read(name),
+            Reference::Edge(edge) => edge.weight().read(name),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Reference::Vertex(vertex) => vertex.weight().label().name(),
+            Reference::Edge(edge) => edge.weight().label().name(),
+        }
+    }
+}
+
+/// The references for an element and the elements named on the way to it.
+struct Elements<'g, G>
+where
+    G: Graph + 'g,
+{
+    current: Option<Reference<'g, G>>,
+    variables: Vec<Option<Reference<'g, G>>>,
+}
+
+impl<'g, G> Elements<'g, G>
+where
+    G: Graph,
+{
+    fn new(graph: &'g G, (current, bindings): &Match<G>) -> Self {
+        Elements {
+            current: Reference::new(graph, *current),
+            ..Elements::named(graph, bindings)
+        }
+    }
+
+    /// The named elements only, for conditions checked along the way.
+    fn named(graph: &'g G, bindings: &Bindings<G>) -> Self {
+        Elements {
+            current: None,
+            variables: bindings
+                .iter()
+                .map(|named| {
+                    named.and_then(|named| match named {
+                        Named::Vertex(id) => graph.vertex(id).map(Reference::Vertex),
+                        Named::Edge(id) => graph.edge(id).map(Reference::Edge),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    fn value<'a>(&'a self, term: &Term<'a>) -> Option<Value<'a>> {
+        let element = |source: &Source| match source {
+            Source::Current => self.current.as_ref(),
+            Source::Variable(slot) => self.variables[*slot].as_ref(),
+        };
+        match term {
+            Term::Value(value) => Some(value.clone()),
+            Term::Property(source, name) => element(source).and_then(|element| element.read(name)),
+            Term::Label(source) => element(source).map(|element| Value::Str(element.label())),
+        }
+    }
+}
+
+/// Sorts the matches of a `Select`, with missing values last.
+fn sort<G>(graph: &G, order: &[(Term, bool)], matches: &mut [Match<G>])
+where
+    G: Graph,
+{
+    if order.is_empty() {
+        return;
+    }
+    matches.sort_by(|a, b| {
+        let (a, b) = (Elements::new(graph, a), Elements::new(graph, b));
+        for (term, descending) in order {
+            let ordering = match (a.value(term), b.value(term)) {
+                (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            let ordering = if *descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+/// A step with its names resolved against the graph.
+enum Resolved<'q, G>
+where
+    G: Graph,
+{
+    VertexLabels(Vec<VertexLabel<G>>),
+    EdgeLabels(Vec<EdgeLabel<G>>),
+    Filter(&'q str, &'q Predicate),
+    /// The property lookup of the start, which is checked even when it was a lookup.
+    StartFilter(&'q str, plan::Predicate<'q>),
+    Edges(EdgeSearch<'static, G>),
+    Head,
+    Tail,
+    Take(usize),
+    Dedup,
+    /// The first step with a name, which records the element.
+    Bind(usize),
+    /// A later step with the same name, which must be on the recorded element.
+    Same(usize),
+    Where(Check<'q>),
+}
+
+enum CompiledStart<'q, G>
+where
+    G: Graph,
+{
+    Vertices(Plan<'q, G>),
+    Edges,
+}
+
+/// A query with its names resolved against the labels of a graph.
+struct Compiled<'q, G>
+where
+    G: Graph,
+{
+    start: CompiledStart<'q, G>,
+    steps: Vec<Resolved<'q, G>>,
+    variables: usize,
+    /// The columns of a query that returns rows.
+    columns: Option<Vec<(&'q str, Term<'q>)>>,
+    order: Vec<(Term<'q>, bool)>,
+}
+
+fn label<L>(name: &str) -> Result<L, QueryError>
+where
+    L: Label + 'static,
+{
+    L::variants()
+        .iter()
+        .find(|label| label.name() == name)
+        .copied()
+        .ok_or_else(|| QueryError::UnknownLabel(name.to_string()))
+}
+
+fn labels<L>(names: &[String]) -> Result<Vec<L>, QueryError>
+where
+    L: Label + 'static,
+{
+    names.iter().map(|name| label(name)).collect()
+}
+
+/// The names given by `As` steps, and whether each was given to a vertex.
+#[derive(Default)]
+struct Scope<'q> {
+    variables: Vec<(&'q str, bool)>,
+}
+
+impl<'q> Scope<'q> {
+    fn slot(&self, variable: &str) -> Result<usize, QueryError> {
+        self.variables
+            .iter()
+            .position(|(name, _)| *name == variable)
+            .ok_or_else(|| QueryError::UnknownVariable(variable.to_string()))
+    }
+
+    fn term(&self, operand: &'q Operand) -> Result<Term<'q>, QueryError> {
+        Ok(match operand {
+            Operand::Literal(literal) => Term::Value(literal.value()),
+            Operand::Property { variable, property } => {
+                Term::Property(Source::Variable(self.slot(variable)?), property)
+            }
+            Operand::Label(variable) => Term::Label(Source::Variable(self.slot(variable)?)),
+        })
+    }
+
+    fn check(&self, condition: &'q Condition) -> Result<Check<'q>, QueryError> {
+        let checks = |conditions: &'q [Condition]| {
+            conditions
+                .iter()
+                .map(|condition| self.check(condition))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match condition {
+            Condition::Compare(left, comparison, right) => {
+                Check::Compare(self.term(left)?, *comparison, self.term(right)?)
+            }
+            Condition::Is(operand) => Check::Is(self.term(operand)?),
+            Condition::Not(condition) => Check::Not(Box::new(self.check(condition)?)),
+            Condition::And(conditions) => Check::And(checks(conditions)?),
+            Condition::Or(conditions) => Check::Or(checks(conditions)?),
+        })
+    }
+}
+
+impl<'q, G> Compiled<'q, G>
+where
+    G: Graph,
+{
+    fn new(query: &'q Query) -> Result<Self, QueryError> {
+        let mut steps = Vec::new();
+        // The lookup becomes filters, which the start plan turns back into a lookup
+        let mut lookup = |name: &str, property: &'q str, predicate: plan::Predicate<'q>| {
+            let label = label::<VertexLabel<G>>(name)?;
+            if !label.indexes().iter().any(|index| index.name() == property) {
+                return Err(QueryError::NotIndexed {
+                    label: name.to_string(),
+                    property: property.to_string(),
+                });
+            }
+            steps.push(Resolved::VertexLabels(vec![label]));
+            steps.push(Resolved::StartFilter(property, predicate));
+            Ok(())
+        };
+        match &query.start {
+            Start::Scan | Start::Edges => {}
+            Start::Label(name) => steps.push(Resolved::VertexLabels(vec![label(name)?])),
+            Start::Index {
+                label,
+                property,
+                value,
+            } => lookup(label, property, plan::Predicate::Eq(value.value()))?,
+            Start::Range {
+                label,
+                property,
+                start,
+                end,
+            } => lookup(
+                label,
+                property,
+                plan::Predicate::Range(start.value()..end.value()),
+            )?,
+        }
+
+        let mut scope = Scope::default();
+        let mut on_vertices = query.start != Start::Edges;
+        for (position, step) in query.steps.iter().enumerate() {
+            let resolved = match (step, on_vertices) {
+                (Step::FilterLabel(name), true) => Resolved::VertexLabels(vec![label(name)?]),
+                (Step::FilterLabel(name), false) => Resolved::EdgeLabels(vec![label(name)?]),
+                (Step::FilterLabels(names), true) => Resolved::VertexLabels(labels(names)?),
+                (Step::FilterLabels(names), false) => Resolved::EdgeLabels(labels(names)?),
+                (
+                    Step::Filter {
+                        property,
+                        predicate,
+                    },
+                    _,
+                ) => Resolved::Filter(property, predicate),
+                (
+                    Step::Edges {
+                        label: name,
+                        direction,
+                    },
+                    true,
+                ) => {
+                    let mut search = EdgeSearch::scan().direction(*direction);
+                    search.label = name.as_deref().map(label).transpose()?;
+                    Resolved::Edges(search)
+                }
+                (Step::Head, false) => Resolved::Head,
+                (Step::Tail, false) => Resolved::Tail,
+                (Step::Take(n), _) => Resolved::Take(*n),
+                (Step::Dedup, _) => Resolved::Dedup,
+                (Step::As(name), _) => match scope.slot(name) {
+                    Ok(slot) if scope.variables[slot].1 == on_vertices => Resolved::Same(slot),
+                    Ok(_) => return Err(QueryError::Misplaced { step: position }),
+                    Err(_) => {
+                        scope.variables.push((name, on_vertices));
+                        Resolved::Bind(scope.variables.len() - 1)
+                    }
+                },
+                (Step::Where(condition), _) => Resolved::Where(scope.check(condition)?),
+                (Step::Edges { .. }, false) | (Step::Head | Step::Tail, true) => {
+                    return Err(QueryError::Misplaced { step: position });
+                }
+            };
+            on_vertices = match resolved {
+                Resolved::Edges(_) => false,
+                Resolved::Head | Resolved::Tail => true,
+                _ => on_vertices,
+            };
+            steps.push(resolved);
+        }
+
+        let (columns, order) = match &query.terminal {
+            Terminal::Collect | Terminal::Count => (None, Vec::new()),
+            Terminal::Values(properties) => {
+                let columns = properties
+                    .iter()
+                    .map(|property| {
+                        let property = property.as_str();
+                        (property, Term::Property(Source::Current, property))
+                    })
+                    .collect();
+                (Some(columns), Vec::new())
+            }
+            Terminal::Select { columns, order, .. } => {
+                let columns = columns
+                    .iter()
+                    .map(|(name, operand)| Ok((name.as_str(), scope.term(operand)?)))
+                    .collect::<Result<Vec<_>, QueryError>>()?;
+                let order = order
+                    .iter()
+                    .map(|order| Ok((scope.term(&order.operand)?, order.descending)))
+                    .collect::<Result<Vec<_>, QueryError>>()?;
+                (Some(columns), order)
+            }
+        };
+
+        let start = match query.start {
+            Start::Edges => CompiledStart::Edges,
+            _ => CompiledStart::Vertices(start_plan(&steps)),
+        };
+        Ok(Compiled {
+            start,
+            steps,
+            variables: scope.variables.len(),
+            columns,
+            order,
+        })
+    }
+}
+
+/// Builds a plan from the label and property filters at the start of a query, so that the
+/// optimizer can turn them into a lookup. The filters are still applied to what the lookup
+/// finds.
+fn start_plan<'q, G>(steps: &[Resolved<'q, G>]) -> Plan<'q, G>
+where
+    G: Graph,
+{
+    let mut plan = Plan::vertices(VertexSearch::scan());
+    let mut current: Option<VertexLabel<G>> = None;
+    for step in steps {
+        let (property, predicate) = match step {
+            Resolved::VertexLabels(labels) => {
+                let [label] = labels.as_slice() else {
+                    break;
+                };
+                current = Some(*label);
+                plan = plan.filter_label(*label);
+                continue;
+            }
+            Resolved::Filter(property, predicate) => match predicate.lookup() {
+                Some(predicate) => (*property, predicate),
+                None => continue,
+            },
+            Resolved::StartFilter(property, predicate) => (*property, predicate.clone()),
+            Resolved::Bind(_) => continue,
+            _ => break,
+        };
+        let index = current.and_then(|label| {
+            label
+                .indexes()
+                .iter()
+                .find(|index| index.name() == property)
+                .copied()
+        });
+        if let Some(index) = index {
+            plan = plan.filter_property(index, predicate);
+        }
+    }
+    plan
+}
+
+/// Walks the graph. The graph is borrowed for as long as the compiled query so that the
+/// walker's closures can refer to it.
+fn walk<'c, G>(
+    graph: &'c G,
+    compiled: &'c Compiled<'c, G>,
+    find: Find,
+    capabilities: &Capabilities,
+) -> Found<G>
+where
+    G: Graph,
+{
+    let bindings: Bindings<G> = vec![None; compiled.variables];
+    match &compiled.start {
+        CompiledStart::Vertices(plan) => {
+            let search = match plan.clone().optimize(capabilities).plan.start() {
+                plan::Start::Vertices(search) => search.clone(),
+                plan::Start::VerticesById(_) => VertexSearch::scan(),
+            };
+            let builder = graph.walk().push_context(bindings).vertices(search);
+            vertex_steps(graph, builder, &compiled.steps, find)
+        }
+        // Every edge is the outgoing edge of exactly one vertex
+        CompiledStart::Edges => {
+            let builder = graph
+                .walk()
+                .push_context(bindings)
+                .vertices(VertexSearch::scan())
+                .edges(EdgeSearch::scan().outgoing());
+            edge_steps(graph, builder, &compiled.steps, find)
+        }
+    }
+}
+
+/// Applies the steps to a walker on vertices. Each step is boxed, so this is only
+/// instantiated for a handful of walker types however long the query is.
+fn vertex_steps<'c, M, G, W>(
+    graph: &'c G,
+    builder: VertexWalkerBuilder<'c, M, G, W>,
+    steps: &'c [Resolved<'c, G>],
+    find: Find,
+) -> Found<G>
+where
+    G: Graph,
+    W: RebindVertexWalker<'c, Graph = G, Context = Bindings<G>> + 'c,
+{
+    let Some((step, rest)) = steps.split_first() else {
+        return match find {
+            Find::Count => Found::Count(builder.count()),
+            Find::First(n) => Found::Vertices(
+                builder
+                    .take(n)
+                    .map(|vertex, bindings| (vertex.id(), bindings))
+                    .collect(),
+            ),
+        };
+    };
+    match step {
+        Resolved::VertexLabels(labels) => {
+            let builder =
+                builder.filter(move |vertex, _| labels.contains(&vertex.weight().label()));
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Filter(property, predicate) => {
+            let builder = builder
+                .filter(move |vertex, _| predicate.test(vertex.weight().read(property).as_ref()));
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::StartFilter(property, predicate) => {
+            let builder = builder.filter(move |vertex, _| {
+                vertex
+                    .weight()
+                    .read(property)
+                    .is_some_and(|value| predicate.test(&value))
+            });
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Edges(search) => {
+            edge_steps(graph, builder.edges(search.clone()).boxed(), rest, find)
+        }
+        Resolved::Take(n) => vertex_steps(graph, builder.take(*n).boxed(), rest, find),
+        Resolved::Dedup => {
+            let seen = RefCell::new(HashSet::new());
+            let builder = builder.filter(move |vertex, _| seen.borrow_mut().insert(vertex.id()));
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Bind(slot) => {
+            let builder = builder.mutate_context(move |vertex, bindings| {
+                bindings[*slot] = Some(Named::Vertex(vertex.id()))
+            });
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Same(slot) => {
+            let builder = builder.filter(move |vertex, bindings| {
+                bindings[*slot] == Some(Named::Vertex(vertex.id()))
+            });
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Where(check) => {
+            let builder =
+                builder.filter(move |_, bindings| check.holds(&Elements::named(graph, bindings)));
+            vertex_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::EdgeLabels(_) | Resolved::Head | Resolved::Tail => {
+            unreachable!("edge steps are rejected when the query is compiled")
+        }
+    }
+}
+
+/// Applies the steps to a walker on edges.
+fn edge_steps<'c, M, G, W>(
+    graph: &'c G,
+    builder: EdgeWalkerBuilder<'c, M, G, W>,
+    steps: &'c [Resolved<'c, G>],
+    find: Find,
+) -> Found<G>
+where
+    G: Graph,
+    W: RebindEdgeWalker<'c, Graph = G, Context = Bindings<G>> + 'c,
+{
+    let Some((step, rest)) = steps.split_first() else {
+        return match find {
+            Find::Count => Found::Count(builder.count()),
+            Find::First(n) => Found::Edges(
+                builder
+                    .take(n)
+                    .map(|edge, bindings| (edge.id(), bindings))
+                    .collect(),
+            ),
+        };
+    };
+    match step {
+        Resolved::EdgeLabels(labels) => {
+            let builder = builder.filter(move |edge, _| labels.contains(&edge.weight().label()));
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Filter(property, predicate) => {
+            let builder = builder
+                .filter(move |edge, _| predicate.test(edge.weight().read(property).as_ref()));
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Head => vertex_steps(graph, builder.head().boxed(), rest, find),
+        Resolved::Tail => vertex_steps(graph, builder.tail().boxed(), rest, find),
+        Resolved::Take(n) => edge_steps(graph, builder.take(*n).boxed(), rest, find),
+        Resolved::Dedup => {
+            let seen = RefCell::new(HashSet::new());
+            let builder = builder.filter(move |edge, _| seen.borrow_mut().insert(edge.id()));
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Bind(slot) => {
+            let builder = builder.mutate_context(move |edge, bindings| {
+                bindings[*slot] = Some(Named::Edge(edge.id()))
+            });
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Same(slot) => {
+            let builder = builder
+                .filter(move |edge, bindings| bindings[*slot] == Some(Named::Edge(edge.id())));
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::Where(check) => {
+            let builder =
+                builder.filter(move |_, bindings| check.holds(&Elements::named(graph, bindings)));
+            edge_steps(graph, builder.boxed(), rest, find)
+        }
+        Resolved::VertexLabels(_) | Resolved::StartFilter(..) | Resolved::Edges(_) => {
+            unreachable!("vertex steps are rejected when the query is compiled")
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/// A small seeded pseudo random number generator (SplitMix64).
+///
+/// Randomised algorithms and steps take a caller provided seed so that results are reproducible.
+/// The sequence for a given seed is fixed and will not change between releases.
+#[derive(Debug, Clone)]
+pub(crate) struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        SeededRng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns a value in `[0, bound)`. `bound` must be greater than zero.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Shuffles the items in place using Fisher-Yates.
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for position in (1..items.len()).rev() {
+            let chosen = self.below(position + 1);
+            items.swap(position, chosen);
+        }
+    }
+
+    /// Picks `count` distinct values from `0..len` using a partial Fisher-Yates shuffle.
+    pub(crate) fn sample_indexes(&mut self, len: usize, count: usize) -> Vec<usize> {
+        let mut indexes: Vec<usize> = (0..len).collect();
+        let count = count.min(len);
+        for position in 0..count {
+            let chosen = position + self.below(len - position);
+            indexes.swap(position, chosen);
+        }
+        indexes.truncate(count);
+        indexes
+    }
+}
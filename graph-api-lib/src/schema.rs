@@ -0,0 +1,200 @@
+//! A description of a graph's model, built from the labels that the derive macros generate.
+//!
+//! [`Schema::of`] collects the vertex and edge labels of a graph, with their fields, indexes
+//! and edge endpoints. The schema can then be exported as JSON for tooling, or as a Mermaid
+//! entity relationship diagram for documentation.
+
+use crate::{Element, Endpoints, Graph, IndexType, Label};
+use include_doc::function_body;
+use std::fmt::Write;
+
+/// The vertex and edge labels of a graph.
+///
+/// ```rust
+#[doc = function_body!("examples/schema.rs", example, [])]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Schema {
+    /// The vertex labels, in ordinal order.
+    pub vertices: Vec<LabelSchema>,
+    /// The edge labels, in ordinal order.
+    pub edges: Vec<LabelSchema>,
+}
+
+/// A vertex or edge label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LabelSchema {
+    /// The name of the label.
+    pub name: &'static str,
+    /// The named fields of the label. Tuple and unit variants have none.
+    pub fields: &'static [FieldSchema],
+    /// The vertex labels that an edge label may join. Always empty for vertex labels.
+    pub endpoints: &'static [Endpoints],
+}
+
+/// A named field of a vertex or edge label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldSchema {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The Rust type of the field, as written in the model.
+    pub ty: &'static str,
+    /// The index on the field, if it has one.
+    pub index: Option<IndexType>,
+}
+
+impl Schema {
+    /// Returns the schema of a graph's vertices and edges.
+    pub fn of<G>() -> Self
+    where
+        G: Graph,
+    {
+        Schema {
+            vertices: labels::<G::Vertex>(),
+            edges: labels::<G::Edge>(),
+        }
+    }
+
+    /// Returns the label with the given name, looking at vertex labels first.
+    pub fn label(&self, name: &str) -> Option<&LabelSchema> {
+        self.vertices
+            .iter()
+            .chain(self.edges.iter())
+            .find(|label| label.name == name)
+    }
+
+    /// Exports the schema as JSON.
+    ///
+    /// The output has `vertices` and `edges` arrays of labels, each with a `name`, a `fields`
+    /// array and an `endpoints` array. Fields have a `name`, a `type` and an `index`, which is
+    /// `"hash"`, `"range"`, `"full_text"` or `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"vertices\":");
+        write_labels(&mut json, &self.vertices);
+        json.push_str(",\"edges\":");
+        write_labels(&mut json, &self.edges);
+        json.push('}');
+        json
+    }
+
+    /// Exports the schema as a Mermaid entity relationship diagram.
+    ///
+    /// Each vertex label is an entity with its fields as attributes, and each pair of edge
+    /// endpoints is a relationship named after the edge label. Edge labels without endpoints
+    /// don't appear, as there is nothing to connect them to.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("erDiagram\n");
+        for label in &self.vertices {
+            if label.fields.is_empty() {
+                let _ = writeln!(mermaid, "    {}", label.name);
+                continue;
+            }
+            let _ = writeln!(mermaid, "    {} {{", label.name);
+            for field in label.fields {
+                let _ = write!(mermaid, "        {} {}", mermaid_type(field.ty), field.name);
+                if let Some(index) = field.index {
+                    let _ = write!(mermaid, " \"{} index\"", index);
+                }
+                mermaid.push('\n');
+            }
+            mermaid.push_str("    }\n");
+        }
+        for label in &self.edges {
+            for endpoints in label.endpoints {
+                let _ = writeln!(
+                    mermaid,
+                    "    {} }}o--o{{ {} : {}",
+                    endpoints.from, endpoints.to, label.name
+                );
+            }
+        }
+        mermaid
+    }
+}
+
+fn labels<E>() -> Vec<LabelSchema>
+where
+    E: Element,
+{
+    <E::Label as Label>::variants()
+        .iter()
+        .map(|label| LabelSchema {
+            name: label.name(),
+            fields: label.fields(),
+            endpoints: label.endpoints(),
+        })
+        .collect()
+}
+
+fn write_labels(json: &mut String, labels: &[LabelSchema]) {
+    json.push('[');
+    for (idx, label) in labels.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+        json.push_str("{\"name\":");
+        write_string(json, label.name);
+        json.push_str(",\"fields\":[");
+        for (idx, field) in label.fields.iter().enumerate() {
+            if idx > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"name\":");
+            write_string(json, field.name);
+            json.push_str(",\"type\":");
+            write_string(json, field.ty);
+            json.push_str(",\"index\":");
+            match field.index {
+                Some(IndexType::Hash) => json.push_str("\"hash\""),
+                Some(IndexType::Range) => json.push_str("\"range\""),
+                Some(IndexType::FullText) => json.push_str("\"full_text\""),
+                None => json.push_str("null"),
+            }
+            json.push('}');
+        }
+        json.push_str("],\"endpoints\":[");
+        for (idx, endpoints) in label.endpoints.iter().enumerate() {
+            if idx > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"from\":");
+            write_string(json, endpoints.from);
+            json.push_str(",\"to\":");
+            write_string(json, endpoints.to);
+            json.push('}');
+        }
+        json.push_str("]}");
+    }
+    json.push(']');
+}
+
+fn write_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Mermaid attribute types are single words, with generics written as `Vec~String~`.
+fn mermaid_type(ty: &str) -> String {
+    ty.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '<' | '>' => '~',
+            c if c.is_alphanumeric() || c == '_' => c,
+            _ => '_',
+        })
+        .collect()
+}
@@ -1,3 +1,8 @@
+use crate::{
+    Element, Label, ProjectionMut, Value, VertexReference, VertexReferenceMut, VertexSearch,
+};
+use include_doc::function_body;
+
 /// Supports indexing of vertices by label
 pub trait SupportsVertexLabelIndex: crate::Graph {}
 
@@ -5,7 +10,59 @@ pub trait SupportsVertexLabelIndex: crate::Graph {}
 pub trait SupportsEdgeLabelIndex: crate::Graph {}
 
 /// Supports indexing of vertices by field using a hash index
-pub trait SupportsVertexHashIndex: crate::Graph {}
+pub trait SupportsVertexHashIndex: crate::Graph {
+    /// Finds the vertex with `key` in a hash `index`, updating it if it exists and adding it if
+    /// it doesn't.
+    ///
+    /// Returns the id of the vertex, and `true` if it was created. The vertex returned by
+    /// `create` should have `key` in the indexed field so that the next upsert finds it. If
+    /// several vertices share the key, the first one found is updated.
+    ///
+    /// `update` receives the mutable projection of the matched vertex, named by `Projection`
+    /// such as `PersonMut<_, _>`. Setters on the projection keep the graph's indexes up to date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matched vertex can't be projected to `Projection`, which happens when
+    /// `index` belongs to a different label.
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/upsert.rs", upsert_vertex_example, [])]
+    /// ```
+    fn upsert_vertex<'search, Projection>(
+        &mut self,
+        index: <<Self::Vertex as Element>::Label as Label>::Index,
+        key: impl Into<Value<'search>>,
+        create: impl FnOnce() -> Self::Vertex,
+        update: impl for<'graph, 'reference> FnOnce(
+            &mut Projection::Projection<
+                'reference,
+                VertexMutationListener<'graph, 'reference, Self>,
+            >,
+        ),
+    ) -> (Self::VertexId, bool)
+    where
+        Self: Sized,
+        Projection: ProjectionMut<Self::Vertex>,
+    {
+        let existing = self.walk().vertices(VertexSearch::get(index, key)).first();
+        match existing {
+            Some(id) => {
+                let mut vertex = self.vertex_mut(id).expect("vertex must exist");
+                let mut projection = vertex
+                    .project_mut()
+                    .expect("index must belong to the projected label");
+                update(&mut projection);
+                (id, false)
+            }
+            None => (self.add_vertex(create()), true),
+        }
+    }
+}
+
+/// The mutation listener of a graph's mutable vertex references.
+type VertexMutationListener<'graph, 'reference, Graph> =
+    <<Graph as crate::Graph>::VertexReferenceMut<'graph> as VertexReferenceMut<'graph, Graph>>::MutationListener<'reference>;
 
 /// Supports indexing of edges by field using a hash index
 pub trait SupportsEdgeHashIndex: crate::Graph {}
@@ -36,3 +93,39 @@ pub trait SupportsElementRemoval: crate::Graph {
     /// Removes an edge from the graph and returns the edge.
     fn remove_edge(&mut self, id: Self::EdgeId) -> Option<Self::Edge>;
 }
+
+/// Supports mapping vertex ids onto a dense range of array indexes.
+/// Algorithms can use this to return results as plain vectors rather than maps.
+pub trait SupportsDenseVertexIds: crate::Graph {
+    /// Returns an upper bound on the dense index of any vertex in the graph.
+    fn dense_vertex_bound(&self) -> usize;
+
+    /// Returns the dense index of a vertex, which is less than [`Self::dense_vertex_bound`].
+    fn dense_vertex_index(&self, id: Self::VertexId) -> usize;
+}
+
+/// A disjoint subset of the vertices of a graph that can be walked on its own thread.
+pub type VertexPartition<'graph, Graph> =
+    Box<dyn Iterator<Item = <Graph as crate::Graph>::VertexId> + Send + 'graph>;
+
+/// Supports splitting the vertices matching a search into partitions that can be walked in parallel.
+pub trait SupportsParallelScan: crate::Graph + Sync {
+    /// Splits the vertices matching `search` into roughly `partitions` disjoint partitions.
+    ///
+    /// The default implementation runs the search and divides the results evenly.
+    /// Graphs should override this to split their storage directly so that scans are not collected up front.
+    fn vertex_partitions<'graph>(
+        &'graph self,
+        search: &VertexSearch<'_, Self>,
+        partitions: usize,
+    ) -> Vec<VertexPartition<'graph, Self>>
+    where
+        Self::VertexId: Send,
+    {
+        let ids: Vec<Self::VertexId> = self.vertices(search).map(|vertex| vertex.id()).collect();
+        let size = ids.len().div_ceil(partitions.max(1)).max(1);
+        ids.chunks(size)
+            .map(|chunk| Box::new(Vec::from(chunk).into_iter()) as VertexPartition<'graph, Self>)
+            .collect()
+    }
+}
@@ -1,3 +1,6 @@
+use std::any::TypeId;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
 use std::ops::Range;
 use uuid::Uuid;
 
@@ -7,7 +10,10 @@ use uuid::Uuid;
 /// vertices and edges. It supports numeric types, boolean values, UUIDs, and strings.
 ///
 /// The lifetime parameter allows the enum to store borrowed string data.
-#[derive(Clone)]
+///
+/// Values can be compared with each other. Numbers compare by value regardless of their width or
+/// sign, other values only compare with values of the same variant.
+#[derive(Clone, Debug)]
 pub enum Value<'a> {
     /// An unsigned size value
     USize(usize),
@@ -49,7 +55,7 @@ pub enum Value<'a> {
 /// range-based lookups and queries in indexed graphs.
 ///
 /// The lifetime parameter corresponds to the lifetime of any string data in the range.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ValueRange<'a>(pub(crate) Range<Value<'a>>);
 
 macro_rules! value_coercion {
@@ -73,6 +79,7 @@ macro_rules! value_coercion {
 }
 value_coercion!(&'a String, Str);
 value_coercion!(&'a str, Str);
+value_coercion!(usize, USize);
 value_coercion!(u128, U128);
 value_coercion!(u64, U64);
 value_coercion!(u32, U32);
@@ -114,3 +121,247 @@ value_or_range_coercion!(f32);
 value_or_range_coercion!(bool);
 value_or_range_coercion!(Uuid);
 value_or_range_coercion!(&'a String);
+
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    fn as_f64(&self) -> f64 {
+        match self {
+            Number::Signed(value) => *value as f64,
+            Number::Unsigned(value) => *value as f64,
+            Number::Float(value) => *value,
+        }
+    }
+
+    fn partial_cmp(&self, other: &Number) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Signed(a), Number::Signed(b)) => Some(a.cmp(b)),
+            (Number::Unsigned(a), Number::Unsigned(b)) => Some(a.cmp(b)),
+            (Number::Signed(a), Number::Unsigned(b)) => match u128::try_from(*a) {
+                Ok(a) => Some(a.cmp(b)),
+                Err(_) => Some(Ordering::Less),
+            },
+            (Number::Unsigned(_), Number::Signed(_)) => {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+            _ => self.as_f64().partial_cmp(&other.as_f64()),
+        }
+    }
+}
+
+impl<'a> Value<'a> {
+    fn number(&self) -> Option<Number> {
+        Some(match self {
+            Value::USize(value) => Number::Unsigned(*value as u128),
+            Value::U128(value) => Number::Unsigned(*value),
+            Value::U64(value) => Number::Unsigned(*value as u128),
+            Value::U32(value) => Number::Unsigned(*value as u128),
+            Value::U16(value) => Number::Unsigned(*value as u128),
+            Value::U8(value) => Number::Unsigned(*value as u128),
+            Value::I128(value) => Number::Signed(*value),
+            Value::I64(value) => Number::Signed(*value as i128),
+            Value::I32(value) => Number::Signed(*value as i128),
+            Value::I16(value) => Number::Signed(*value as i128),
+            Value::I8(value) => Number::Signed(*value as i128),
+            Value::F64(value) => Number::Float(*value),
+            Value::F32(value) => Number::Float(*value as f64),
+            Value::Bool(_) | Value::Uuid(_) | Value::Str(_) => return None,
+        })
+    }
+
+    /// Converts this value to the variant that holds the type `ty`, as returned by
+    /// [`Index::ty`](crate::Index::ty).
+    ///
+    /// Integers are converted if they fit in the target type, and any number can become a float.
+    /// Strings convert to `String` fields. Returns `None` if the value can't be represented.
+    pub fn coerce(&self, ty: TypeId) -> Option<Value<'a>> {
+        macro_rules! integer {
+            ($ty: ty, $ident: ident) => {
+                if ty == TypeId::of::<$ty>() {
+                    return match self.number()? {
+                        Number::Signed(value) => <$ty>::try_from(value).ok(),
+                        Number::Unsigned(value) => <$ty>::try_from(value).ok(),
+                        Number::Float(_) => None,
+                    }
+                    .map(Value::$ident);
+                }
+            };
+        }
+        integer!(usize, USize);
+        integer!(u128, U128);
+        integer!(u64, U64);
+        integer!(u32, U32);
+        integer!(u16, U16);
+        integer!(u8, U8);
+        integer!(i128, I128);
+        integer!(i64, I64);
+        integer!(i32, I32);
+        integer!(i16, I16);
+        integer!(i8, I8);
+        if ty == TypeId::of::<f64>() {
+            return self.number().map(|number| Value::F64(number.as_f64()));
+        }
+        if ty == TypeId::of::<f32>() {
+            return self
+                .number()
+                .map(|number| Value::F32(number.as_f64() as f32));
+        }
+        match self {
+            Value::Str(_) if ty == TypeId::of::<String>() || ty == TypeId::of::<&str>() => {
+                Some(self.clone())
+            }
+            Value::Bool(_) if ty == TypeId::of::<bool>() => Some(self.clone()),
+            Value::Uuid(_) if ty == TypeId::of::<Uuid>() => Some(self.clone()),
+            _ => None,
+        }
+    }
+
+    /// Parses text as the type `ty`, as returned by [`Index::ty`](crate::Index::ty).
+    ///
+    /// Strings are borrowed from the text. Returns `None` if the text isn't valid for the type,
+    /// or the type isn't one that a `Value` can hold.
+    pub fn parse(text: &'a str, ty: TypeId) -> Option<Value<'a>> {
+        macro_rules! parse {
+            ($ty: ty, $ident: ident) => {
+                if ty == TypeId::of::<$ty>() {
+                    return text.parse::<$ty>().ok().map(Value::$ident);
+                }
+            };
+        }
+        parse!(usize, USize);
+        parse!(u128, U128);
+        parse!(u64, U64);
+        parse!(u32, U32);
+        parse!(u16, U16);
+        parse!(u8, U8);
+        parse!(i128, I128);
+        parse!(i64, I64);
+        parse!(i32, I32);
+        parse!(i16, I16);
+        parse!(i8, I8);
+        parse!(f64, F64);
+        parse!(f32, F32);
+        parse!(bool, Bool);
+        parse!(Uuid, Uuid);
+        if ty == TypeId::of::<String>() || ty == TypeId::of::<&str>() {
+            return Some(Value::Str(text));
+        }
+        None
+    }
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::USize(value) => write!(f, "{}", value),
+            Value::U128(value) => write!(f, "{}", value),
+            Value::U64(value) => write!(f, "{}", value),
+            Value::U32(value) => write!(f, "{}", value),
+            Value::U16(value) => write!(f, "{}", value),
+            Value::U8(value) => write!(f, "{}", value),
+            Value::I128(value) => write!(f, "{}", value),
+            Value::I64(value) => write!(f, "{}", value),
+            Value::I32(value) => write!(f, "{}", value),
+            Value::I16(value) => write!(f, "{}", value),
+            Value::I8(value) => write!(f, "{}", value),
+            Value::F64(value) => write!(f, "{}", value),
+            Value::F32(value) => write!(f, "{}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Uuid(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl PartialEq for Value<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Value<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.number(), other.number()) {
+            return a.partial_cmp(&b);
+        }
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+            (Value::Uuid(a), Value::Uuid(b)) => a.partial_cmp(b),
+            (Value::Str(a), Value::Str(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<Range<Value<'a>>> for ValueRange<'a> {
+    fn from(range: Range<Value<'a>>) -> Self {
+        ValueRange(range)
+    }
+}
+
+/// A [`Value`] that owns its string, so it can outlive the element it was read from.
+///
+/// Values read from a graph borrow from the element, use this to return them from a query.
+/// Owned values compare and display like the value they were made from.
+#[derive(Clone, Debug)]
+pub enum OwnedValue {
+    /// A string, owned rather than borrowed
+    String(String),
+    /// Any other value, none of which borrow
+    Value(Value<'static>),
+}
+
+impl OwnedValue {
+    /// Returns the owned value as a [`Value`] borrowing from it.
+    pub fn value(&self) -> Value<'_> {
+        match self {
+            OwnedValue::String(value) => Value::Str(value),
+            OwnedValue::Value(value) => value.clone(),
+        }
+    }
+}
+
+impl From<Value<'_>> for OwnedValue {
+    fn from(value: Value<'_>) -> Self {
+        OwnedValue::Value(match value {
+            Value::USize(value) => Value::USize(value),
+            Value::U128(value) => Value::U128(value),
+            Value::U64(value) => Value::U64(value),
+            Value::U32(value) => Value::U32(value),
+            Value::U16(value) => Value::U16(value),
+            Value::U8(value) => Value::U8(value),
+            Value::I128(value) => Value::I128(value),
+            Value::I64(value) => Value::I64(value),
+            Value::I32(value) => Value::I32(value),
+            Value::I16(value) => Value::I16(value),
+            Value::I8(value) => Value::I8(value),
+            Value::F64(value) => Value::F64(value),
+            Value::F32(value) => Value::F32(value),
+            Value::Bool(value) => Value::Bool(value),
+            Value::Uuid(value) => Value::Uuid(value),
+            Value::Str(value) => return OwnedValue::String(value.to_string()),
+        })
+    }
+}
+
+impl Display for OwnedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.value().fmt(f)
+    }
+}
+
+impl PartialEq for OwnedValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl PartialOrd for OwnedValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value().partial_cmp(&other.value())
+    }
+}
@@ -1,5 +1,6 @@
+use crate::walker::stable::StableSources;
 use crate::walker::steps::Empty;
-use crate::walker::{EdgeWalker, VertexWalker};
+use crate::walker::{EdgeWalker, RebindEdgeWalker, RebindVertexWalker, VertexWalker};
 use std::marker::PhantomData;
 
 /// A marker trait for types that can be mutated.
@@ -128,6 +129,157 @@ where
     }
 }
 
+/// The elements of a traversal that has been run to completion, along with the graph, which
+/// can now be written to.
+///
+/// Steps that change the graph can't do so while the traversal is running, as the walker reads
+/// from the graph as it goes. Instead they stage: the traversal is read in full, the walker is
+/// dropped, and only then are the changes made.
+pub(crate) struct Staged<'graph, Graph, Id, Context> {
+    /// The graph, free to be mutated.
+    pub(crate) graph: &'graph mut Graph,
+    /// Each element that was traversed, with the context that it was traversed with.
+    pub(crate) elements: Vec<(Id, Context)>,
+    /// The context of the walker once the traversal finished.
+    pub(crate) context: Context,
+}
+
+/// Reads a traversal to completion for [`Staged`].
+///
+/// The walker has been rebound to `'read`, a shared borrow of the graph that ends when this
+/// returns, so the graph can be taken mutably afterwards.
+fn read_all<'read, Walker, Id>(
+    graph: &'read Walker::Graph,
+    mut walker: Walker,
+    next: impl Fn(&mut Walker, &'read Walker::Graph) -> Option<Id>,
+) -> (Vec<(Id, Walker::Context)>, Walker::Context)
+where
+    Walker: crate::walker::Walker<'read>,
+{
+    let mut elements = Vec::new();
+    while let Some(id) = next(&mut walker, graph) {
+        elements.push((id, walker.ctx().clone()));
+    }
+    let context = walker.ctx().clone();
+    (elements, context)
+}
+
+/// Reads a traversal from a graph that is held mutably, pausing after every `batch` elements to
+/// `apply` changes before reading on. Elements that no longer `exist` by the time they would be
+/// applied are skipped. Returns the number of elements applied.
+///
+/// Only one batch is held at a time, but the walker stays alive while the graph is mutated, so
+/// its sources are made stable for the duration, see [`StableSources`].
+fn stage_batches<'graph, Walker, Id>(
+    graph: &'graph mut Walker::Graph,
+    mut walker: Walker,
+    batch: usize,
+    next: impl Fn(&mut Walker, &'graph Walker::Graph) -> Option<Id>,
+    exists: impl Fn(&Walker::Graph, Id) -> bool,
+    mut apply: impl FnMut(&mut Walker::Graph, Id, &Walker::Context),
+) -> usize
+where
+    Walker: crate::walker::Walker<'graph>,
+    Id: Copy + 'static,
+{
+    assert!(batch > 0, "batch size must be greater than zero");
+    let _stable = StableSources::start();
+    let mut elements = Vec::with_capacity(batch);
+    let mut count = 0;
+    loop {
+        {
+            // SAFETY: The shared reference is only used inside this block. While sources are
+            // stable, walkers keep ids rather than the graph's iterators between calls to `next`,
+            // so nothing they hold is invalidated by the changes made outside it.
+            let read: &'graph Walker::Graph = unsafe { &*(graph as *const Walker::Graph) };
+            while elements.len() < batch {
+                let Some(id) = next(&mut walker, read) else {
+                    break;
+                };
+                elements.push((id, walker.ctx().clone()));
+            }
+        }
+        let finished = elements.len() < batch;
+        for (id, ctx) in elements.drain(..) {
+            if exists(graph, id) {
+                apply(graph, id, &ctx);
+                count += 1;
+            }
+        }
+        if finished {
+            return count;
+        }
+    }
+}
+
+/// A builder whose walker can be moved to a borrow of the graph with a different lifetime.
+///
+/// A `detour` builds its path from a builder typed by the graph lifetime, so when the detour is
+/// rebound the path is built once and the walker it ends with is rebound in its place.
+pub trait RebindWalkerBuilder<'graph> {
+    /// The graph that the walker reads from.
+    type Graph: crate::graph::Graph;
+    /// The context of the walker.
+    type Context: Clone + 'static;
+    /// The walker, reading from a graph borrowed for `'read`.
+    type Walker<'read>: crate::walker::Walker<'read, Graph = Self::Graph, Context = Self::Context>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+
+    /// Takes the walker out of this builder and moves it to a borrow of the graph for `'read`.
+    fn rebind_walker<'read>(self) -> Self::Walker<'read>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+}
+
+impl<'graph, Mutability, Graph, Walker> RebindWalkerBuilder<'graph>
+    for VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: RebindVertexWalker<'graph, Graph = Graph>,
+{
+    type Graph = Graph;
+    type Context = Walker::Context;
+    type Walker<'read>
+        = Walker::Rebound<'read>
+    where
+        Graph: 'read,
+        'graph: 'read;
+
+    fn rebind_walker<'read>(self) -> Self::Walker<'read>
+    where
+        Graph: 'read,
+        'graph: 'read,
+    {
+        self.walker.rebind()
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> RebindWalkerBuilder<'graph>
+    for EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: RebindEdgeWalker<'graph, Graph = Graph>,
+{
+    type Graph = Graph;
+    type Context = Walker::Context;
+    type Walker<'read>
+        = Walker::Rebound<'read>
+    where
+        Graph: 'read,
+        'graph: 'read;
+
+    fn rebind_walker<'read>(self) -> Self::Walker<'read>
+    where
+        Graph: 'read,
+        'graph: 'read,
+    {
+        self.walker.rebind()
+    }
+}
+
 /// A builder for vertex-focused graph traversals.
 ///
 /// This builder constructs walkers that navigate graphs by moving from vertex to vertex.
@@ -161,7 +313,6 @@ where
     }
 }
 
-#[allow(dead_code)]
 pub(crate) fn new_mut<'graph, Graph, Start>(
     graph: &'graph mut Graph,
     start: Start,
@@ -274,6 +425,45 @@ where
     }
 }
 
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+    Mutability: Mutable,
+{
+    /// Runs the traversal to completion so that the graph can be mutated, see [`Staged`].
+    pub(crate) fn stage(mut self) -> Staged<'graph, Graph, Graph::VertexId, Walker::Context>
+    where
+        Walker: RebindVertexWalker<'graph>,
+    {
+        let graph = self.graph.take_mut();
+        let (elements, context) = read_all(&*graph, self.walker.rebind(), |walker, graph| {
+            walker.next(graph)
+        });
+        Staged {
+            graph,
+            elements,
+            context,
+        }
+    }
+
+    /// Runs the traversal in batches, mutating the graph between them, see [`stage_batches`].
+    pub(crate) fn stage_batches(
+        mut self,
+        batch: usize,
+        apply: impl FnMut(&mut Graph, Graph::VertexId, &Walker::Context),
+    ) -> usize {
+        stage_batches(
+            self.graph.take_mut(),
+            self.walker,
+            batch,
+            |walker, graph| walker.next(graph),
+            |graph, id| graph.vertex(id).is_some(),
+            apply,
+        )
+    }
+}
+
 /// A builder for edge-focused graph traversals.
 ///
 /// This builder constructs walkers that navigate graphs by moving along edges.
@@ -292,6 +482,21 @@ where
     graph: GraphAccess<'graph, Graph>,
 }
 
+pub(crate) fn new_edge_mut<'graph, Graph, Start>(
+    graph: &'graph mut Graph,
+    start: Start,
+) -> EdgeWalkerBuilder<'graph, MutableMarker, Graph, Start>
+where
+    Graph: crate::graph::Graph,
+    Start: EdgeWalker<'graph, Graph = Graph>,
+{
+    EdgeWalkerBuilder {
+        _phantom: Default::default(),
+        walker: start,
+        graph: GraphAccess::Mutable(graph),
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
@@ -390,6 +595,45 @@ where
     }
 }
 
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: EdgeWalker<'graph, Graph = Graph>,
+    Mutability: Mutable,
+{
+    /// Runs the traversal to completion so that the graph can be mutated, see [`Staged`].
+    pub(crate) fn stage(mut self) -> Staged<'graph, Graph, Graph::EdgeId, Walker::Context>
+    where
+        Walker: RebindEdgeWalker<'graph>,
+    {
+        let graph = self.graph.take_mut();
+        let (elements, context) = read_all(&*graph, self.walker.rebind(), |walker, graph| {
+            walker.next(graph)
+        });
+        Staged {
+            graph,
+            elements,
+            context,
+        }
+    }
+
+    /// Runs the traversal in batches, mutating the graph between them, see [`stage_batches`].
+    pub(crate) fn stage_batches(
+        mut self,
+        batch: usize,
+        apply: impl FnMut(&mut Graph, Graph::EdgeId, &Walker::Context),
+    ) -> usize {
+        stage_batches(
+            self.graph.take_mut(),
+            self.walker,
+            batch,
+            |walker, graph| walker.next(graph),
+            |graph, id| graph.edge(id).is_some(),
+            apply,
+        )
+    }
+}
+
 pub(crate) fn new_start<Graph>(graph: &Graph) -> StartWalkerBuilder<'_, ImmutableMarker, Graph, ()>
 where
     Graph: crate::graph::Graph,
@@ -1,15 +1,18 @@
 use crate::graph::Graph;
 use crate::search::vertex::VertexSearch;
 use crate::walker::builder::{ImmutableMarker, VertexWalkerBuilder};
+use crate::walker::profiler::{Profiler, StepInfo};
 use crate::walker::steps::{
-    Detour, EdgeContext, EdgeControlFlow, EdgeFilter, EdgeReduce, EdgeTake, Edges, End, Endpoints,
-    VertexContext, VertexControlFlow, VertexFilter, VertexIter, VertexReduce, VertexTake, Vertices,
-    Waypoint,
+    ControlFlowPredicate, Detour, EdgeContext, EdgeControlFlow, EdgeFilter, EdgeReduce, EdgeTake,
+    Edges, ElementReducer, End, Endpoints, VertexContext, VertexControlFlow, VertexFilter,
+    VertexIter, VertexReduce, VertexTake, Vertices, Waypoint,
 };
 use crate::{EdgeSearch, ElementId};
 
 pub mod builder;
 mod iter;
+pub(crate) mod profiler;
+pub(crate) mod stable;
 pub mod steps;
 
 /// A trait that defines the basic behavior of a graph walker.
@@ -42,6 +45,13 @@ where
 
     /// Returns the mutable current context of the walker.
     fn ctx_mut(&mut self) -> &mut Self::Context;
+
+    /// Describes the steps of the traversal, from the first step up to and including this one.
+    ///
+    /// Steps push a description of themselves after asking their parent to describe itself.
+    /// Walkers that do not override this are left out of `explain` output, along with the steps
+    /// before them, and are shown as `walker` in `profile` output.
+    fn explain(&self, _steps: &mut Vec<StepInfo>) {}
 }
 
 /// A trait that defines the basic behavior of a vertex walker, which is a specialized
@@ -82,14 +92,7 @@ pub trait VertexWalker<'graph>: Walker<'graph> {
         predicate: Predicate,
     ) -> VertexControlFlow<'graph, Self, Predicate>
     where
-        Self: 'graph,
-        for<'a> Predicate: Fn(
-            &'a <Self::Graph as Graph>::VertexReference<'graph>,
-            &mut Self::Context,
-        ) -> std::ops::ControlFlow<
-            Option<&'a <Self::Graph as Graph>::VertexReference<'graph>>,
-            Option<&'a <Self::Graph as Graph>::VertexReference<'graph>>,
-        >,
+        Predicate: for<'r> ControlFlowPredicate<<Self::Graph as Graph>::VertexReference<'r>, Self::Context>,
     {
         VertexControlFlow::new(self, predicate)
     }
@@ -128,17 +131,13 @@ pub trait VertexWalker<'graph>: Walker<'graph> {
         VertexContext::new(self, predicate)
     }
 
-    fn edges(self, search: EdgeSearch<Self::Graph>) -> Edges<'_, 'graph, Self> {
+    fn edges(self, search: EdgeSearch<'_, Self::Graph>) -> Edges<'_, 'graph, Self> {
         Edges::new(self, search)
     }
 
     fn reduce<Reducer>(self, reducer: Reducer) -> VertexReduce<'graph, Self, Reducer>
     where
-        Reducer: for<'a> Fn(
-            &'a <Self::Graph as Graph>::VertexReference<'graph>,
-            &'a <Self::Graph as Graph>::VertexReference<'graph>,
-            &Self::Context,
-        ) -> &'a <Self::Graph as Graph>::VertexReference<'graph>,
+        Reducer: for<'r> ElementReducer<<Self::Graph as Graph>::VertexReference<'r>, Self::Context>,
         <Self as Walker<'graph>>::Graph: 'graph,
     {
         VertexReduce::new(self, reducer)
@@ -189,14 +188,8 @@ pub trait EdgeWalker<'graph>: Walker<'graph> {
         predicate: Predicate,
     ) -> EdgeControlFlow<'graph, Self, Predicate>
     where
-        Self: 'graph,
-        for<'a> Predicate: Fn(
-            &'a <Self::Graph as Graph>::EdgeReference<'graph>,
-            &mut Self::Context,
-        ) -> std::ops::ControlFlow<
-            Option<&'a <Self::Graph as Graph>::EdgeReference<'graph>>,
-            Option<&'a <Self::Graph as Graph>::EdgeReference<'graph>>,
-        >,
+        Predicate:
+            for<'r> ControlFlowPredicate<<Self::Graph as Graph>::EdgeReference<'r>, Self::Context>,
     {
         EdgeControlFlow::new(self, predicate)
     }
@@ -215,11 +208,7 @@ pub trait EdgeWalker<'graph>: Walker<'graph> {
 
     fn reduce<Reducer>(self, reducer: Reducer) -> EdgeReduce<'graph, Self, Reducer>
     where
-        Reducer: for<'a> Fn(
-            &'a <Self::Graph as Graph>::EdgeReference<'graph>,
-            &'a <Self::Graph as Graph>::EdgeReference<'graph>,
-            &Self::Context,
-        ) -> &'a <Self::Graph as Graph>::EdgeReference<'graph>,
+        Reducer: for<'r> ElementReducer<<Self::Graph as Graph>::EdgeReference<'r>, Self::Context>,
         <Self as Walker<'graph>>::Graph: 'graph,
     {
         EdgeReduce::new(self, reducer)
@@ -237,3 +226,73 @@ pub trait EdgeWalker<'graph>: Walker<'graph> {
     /// The ID of the next edge, or None if the traversal is complete
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId>;
 }
+
+/// A vertex walker whose steps can be measured by `profile`.
+///
+/// Profiling is opt in, so traversals that aren't profiled pay nothing for it. When a traversal is
+/// profiled each step profiles its parent and then wraps itself with [`Profiler::count`], which
+/// counts and times the elements that the step emits. Steps that run a nested traversal, such as
+/// `detour`, leave it unprofiled, so it is measured as part of the step.
+///
+/// Every built-in step can be profiled. A walker added with `with_vertex_walker` must implement
+/// this trait to be profiled, and as it is required by [`RebindVertexWalker`], to be rebound.
+pub trait ProfileVertexWalker<'graph>: VertexWalker<'graph> {
+    /// This walker, with each of its steps counted.
+    type Profiled: VertexWalker<'graph, Graph = Self::Graph, Context = Self::Context>;
+
+    /// Wraps each step of this walker so that it reports to `profiler`.
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled;
+}
+
+/// An edge walker whose steps can be measured by `profile`.
+///
+/// See [`ProfileVertexWalker`] for how profiling works.
+pub trait ProfileEdgeWalker<'graph>: EdgeWalker<'graph> {
+    /// This walker, with each of its steps counted.
+    type Profiled: EdgeWalker<'graph, Graph = Self::Graph, Context = Self::Context>;
+
+    /// Wraps each step of this walker so that it reports to `profiler`.
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled;
+}
+
+/// A vertex walker that can be moved to a borrow of the graph with a different lifetime.
+///
+/// Walkers are typed by the lifetime of the graph that their builder holds. For `walk_mut()` that
+/// is the mutable borrow, so a walker can't read the graph and then hand it back for mutation.
+/// Steps that change the graph rebind the walker to a short shared borrow instead, read the whole
+/// traversal, and drop the walker before taking the graph mutably.
+///
+/// Walkers are only rebound once, before they have started, so implementations move their parent
+/// and configuration across and discard any iterators they hold. Every built-in step can be
+/// rebound. A walker added with `with_vertex_walker` must implement this trait, and
+/// [`ProfileVertexWalker`], to be followed by a step that mutates the graph, or to be boxed.
+pub trait RebindVertexWalker<'graph>: ProfileVertexWalker<'graph> {
+    /// This walker, reading from a graph borrowed for `'read`.
+    type Rebound<'read>: VertexWalker<'read, Graph = Self::Graph, Context = Self::Context>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+
+    /// Moves this walker to a borrow of the graph for `'read`.
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+}
+
+/// An edge walker that can be moved to a borrow of the graph with a different lifetime.
+///
+/// See [`RebindVertexWalker`] for why this is needed.
+pub trait RebindEdgeWalker<'graph>: ProfileEdgeWalker<'graph> {
+    /// This walker, reading from a graph borrowed for `'read`.
+    type Rebound<'read>: EdgeWalker<'read, Graph = Self::Graph, Context = Self::Context>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+
+    /// Moves this walker to a borrow of the graph for `'read`.
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Self::Graph: 'read,
+        'graph: 'read;
+}
@@ -0,0 +1,346 @@
+use crate::{Direction, EdgeSearch, ElementId, Label, VertexSearch};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// How a step finds the elements that it emits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Access {
+    /// Every vertex in the graph is visited.
+    VertexScan,
+    /// Vertices are looked up by label.
+    VertexLabel { label: &'static str },
+    /// Vertices are looked up by value in a hash index.
+    VertexIndex { index: String },
+    /// Vertices are looked up by a range of values in a range index.
+    VertexRange { index: String },
+    /// Vertices are looked up by text in a full text index.
+    VertexFullText { index: String },
+    /// Vertices are visited by id.
+    VertexIds,
+    /// The edges of each vertex are visited.
+    Edges {
+        label: Option<&'static str>,
+        adjacent_label: Option<&'static str>,
+        direction: Direction,
+    },
+}
+
+impl Access {
+    pub(crate) fn vertices<Graph>(search: &VertexSearch<'_, Graph>) -> Self
+    where
+        Graph: crate::Graph,
+    {
+        match search {
+            VertexSearch::Scan { .. } => Access::VertexScan,
+            VertexSearch::Label { label, .. } => Access::VertexLabel {
+                label: label.name(),
+            },
+            VertexSearch::Index { index, .. } => Access::VertexIndex {
+                index: format!("{:?}", index),
+            },
+            VertexSearch::Range { index, .. } => Access::VertexRange {
+                index: format!("{:?}", index),
+            },
+            VertexSearch::FullText { index, .. } => Access::VertexFullText {
+                index: format!("{:?}", index),
+            },
+        }
+    }
+
+    pub(crate) fn edges<Graph>(search: &EdgeSearch<'_, Graph>) -> Self
+    where
+        Graph: crate::Graph,
+    {
+        Access::Edges {
+            label: search.label.map(|label| label.name()),
+            adjacent_label: search.adjacent_label.map(|label| label.name()),
+            direction: search.direction,
+        }
+    }
+}
+
+impl Display for Access {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Access::VertexScan => write!(f, "scan"),
+            Access::VertexLabel { label } => write!(f, "label {}", label),
+            Access::VertexIndex { index } => write!(f, "hash index {}", index),
+            Access::VertexRange { index } => write!(f, "range index {}", index),
+            Access::VertexFullText { index } => write!(f, "full text index {}", index),
+            Access::VertexIds => write!(f, "ids"),
+            Access::Edges {
+                label,
+                adjacent_label,
+                direction,
+            } => {
+                write!(f, "{:?} edges", direction)?;
+                if let Some(label) = label {
+                    write!(f, " label {}", label)?;
+                }
+                if let Some(adjacent_label) = adjacent_label {
+                    write!(f, " adjacent {}", adjacent_label)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A description of a single step in a traversal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StepInfo {
+    /// The name of the step, matching the builder method that added it.
+    pub name: &'static str,
+    /// How the step finds elements, for steps that read from the graph.
+    pub access: Option<Access>,
+    /// The limit pushed down to the graph, if any.
+    pub limit: Option<usize>,
+}
+
+impl StepInfo {
+    pub(crate) fn new(name: &'static str) -> Self {
+        StepInfo {
+            name,
+            access: None,
+            limit: None,
+        }
+    }
+
+    pub(crate) fn vertices<Graph>(name: &'static str, search: &VertexSearch<'_, Graph>) -> Self
+    where
+        Graph: crate::Graph,
+    {
+        let limit = search.limit();
+        StepInfo {
+            name,
+            access: Some(Access::vertices(search)),
+            limit: (limit != usize::MAX).then_some(limit),
+        }
+    }
+
+    pub(crate) fn edges<Graph>(name: &'static str, search: &EdgeSearch<'_, Graph>) -> Self
+    where
+        Graph: crate::Graph,
+    {
+        StepInfo {
+            name,
+            access: Some(Access::edges(search)),
+            limit: search.limit,
+        }
+    }
+
+    pub(crate) fn with_access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+}
+
+impl Display for StepInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(access) = &self.access {
+            write!(f, " ({})", access)?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, " limit {}", limit)?;
+        }
+        Ok(())
+    }
+}
+
+/// The steps of a traversal, in the order that they were added, as returned by `explain`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Explain {
+    pub steps: Vec<StepInfo>,
+}
+
+impl Display for Explain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{}: {}", position, step)?;
+        }
+        Ok(())
+    }
+}
+
+/// The measurements for a single step of a profiled traversal.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct StepProfile {
+    /// The step that was measured.
+    pub step: StepInfo,
+    /// The number of elements the step received from the step before it.
+    pub elements_in: usize,
+    /// The number of elements the step emitted.
+    pub elements_out: usize,
+    /// The time spent in the step itself, not including the steps before it.
+    pub elapsed: Duration,
+}
+
+/// The measurements for each step of a traversal, as returned by `profile`.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    pub steps: Vec<StepProfile>,
+}
+
+impl Profile {
+    /// The total time spent in the traversal.
+    pub fn elapsed(&self) -> Duration {
+        self.steps.iter().map(|step| step.elapsed).sum()
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, step) in self.steps.iter().enumerate() {
+            writeln!(
+                f,
+                "{}: {} in: {} out: {} elapsed: {:?}",
+                position, step.step, step.elements_in, step.elements_out, step.elapsed
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Counter {
+    out: usize,
+    elapsed: Duration,
+}
+
+/// Collects the measurements of a traversal that is being profiled.
+///
+/// Each step of the traversal is wrapped in a [`Counted`] adapter that reports to the profiler,
+/// see [`ProfileVertexWalker`](crate::ProfileVertexWalker). Steps are registered from the first
+/// to the last, as each step profiles its parent before wrapping itself.
+#[derive(Clone, Default)]
+pub struct Profiler {
+    steps: Rc<RefCell<Vec<(StepInfo, Counter)>>>,
+}
+
+impl Profiler {
+    /// Wraps a step in an adapter that counts and times the elements that it emits.
+    ///
+    /// The step is described by the entry that it adds to `explain`, so its parent must already
+    /// have been counted.
+    pub fn count<'graph, Walker>(&self, walker: Walker) -> Counted<Walker>
+    where
+        Walker: crate::Walker<'graph>,
+    {
+        let mut steps = self.steps.borrow_mut();
+        let mut explain = Vec::new();
+        walker.explain(&mut explain);
+        let step = explain
+            .into_iter()
+            .nth(steps.len())
+            .unwrap_or_else(|| StepInfo::new("walker"));
+        steps.push((step, Counter::default()));
+        Counted {
+            walker,
+            profiler: self.clone(),
+            index: steps.len() - 1,
+        }
+    }
+
+    fn record<T>(&self, index: usize, next: impl FnOnce() -> Option<T>) -> Option<T> {
+        let start = Instant::now();
+        let result = next();
+        let elapsed = start.elapsed();
+        let counter = &mut self.steps.borrow_mut()[index].1;
+        counter.elapsed += elapsed;
+        counter.out += usize::from(result.is_some());
+        result
+    }
+
+    pub(crate) fn finish(self) -> Profile {
+        let steps = std::mem::take(&mut *self.steps.borrow_mut());
+        let mut previous = Counter::default();
+        let steps = steps
+            .into_iter()
+            .map(|(step, counter)| {
+                let profile = StepProfile {
+                    step,
+                    elements_in: previous.out,
+                    elements_out: counter.out,
+                    elapsed: counter.elapsed.saturating_sub(previous.elapsed),
+                };
+                previous = counter;
+                profile
+            })
+            .collect();
+        Profile { steps }
+    }
+}
+
+/// A step of a traversal that is being profiled, counting and timing the elements it emits.
+///
+/// Created by [`Profiler::count`].
+pub struct Counted<Walker> {
+    walker: Walker,
+    profiler: Profiler,
+    index: usize,
+}
+
+impl<'graph, Walker> crate::Walker<'graph> for Counted<Walker>
+where
+    Walker: crate::Walker<'graph>,
+{
+    type Graph = Walker::Graph;
+    type Context = Walker::Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.profiler
+            .record(self.index, || self.walker.next_element(graph))
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        self.walker.ctx()
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        self.walker.ctx_mut()
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.walker.explain(steps)
+    }
+}
+
+impl<'graph, Walker> crate::VertexWalker<'graph> for Counted<Walker>
+where
+    Walker: crate::VertexWalker<'graph>,
+{
+    fn next(
+        &mut self,
+        graph: &'graph Self::Graph,
+    ) -> Option<<Self::Graph as crate::Graph>::VertexId> {
+        self.profiler.record(self.index, || self.walker.next(graph))
+    }
+}
+
+impl<'graph, Walker> crate::EdgeWalker<'graph> for Counted<Walker>
+where
+    Walker: crate::EdgeWalker<'graph>,
+{
+    fn next(
+        &mut self,
+        graph: &'graph Self::Graph,
+    ) -> Option<<Self::Graph as crate::Graph>::EdgeId> {
+        self.profiler.record(self.index, || self.walker.next(graph))
+    }
+}
+
+/// Returns the steps of a walker.
+pub(crate) fn explain<'graph, Walker>(walker: &Walker) -> Explain
+where
+    Walker: crate::Walker<'graph>,
+{
+    let mut steps = Vec::new();
+    walker.explain(&mut steps);
+    Explain { steps }
+}
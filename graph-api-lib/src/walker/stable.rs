@@ -0,0 +1,60 @@
+use std::cell::Cell;
+
+thread_local! {
+    static STABLE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Makes the sources of traversals on this thread stable until it is dropped.
+///
+/// Walkers normally hold the graph's own iterators between calls to `next`, which would be
+/// invalidated if the graph was mutated part way through a traversal. While stable, the
+/// `vertices` and `edges` steps snapshot the ids they are about to visit instead, and skip ids
+/// whose element has since been removed.
+pub(crate) struct StableSources {
+    was_stable: bool,
+}
+
+impl StableSources {
+    pub(crate) fn start() -> Self {
+        StableSources {
+            was_stable: STABLE.replace(true),
+        }
+    }
+}
+
+impl Drop for StableSources {
+    fn drop(&mut self) {
+        STABLE.set(self.was_stable);
+    }
+}
+
+/// The elements that a `vertices` or `edges` step is visiting, either straight from the graph or
+/// from a snapshot of their ids.
+pub(crate) enum Source<Live, Id>
+where
+    Live: Iterator,
+{
+    Live(Live, fn(Live::Item) -> Id),
+    Snapshot(std::vec::IntoIter<Id>),
+}
+
+impl<Live, Id> Source<Live, Id>
+where
+    Live: Iterator,
+{
+    pub(crate) fn new(live: Live, id: fn(Live::Item) -> Id) -> Self {
+        if STABLE.get() {
+            Source::Snapshot(live.map(id).collect::<Vec<_>>().into_iter())
+        } else {
+            Source::Live(live, id)
+        }
+    }
+
+    /// Returns the next id, skipping snapshotted ids that no longer `exist`.
+    pub(crate) fn next(&mut self, exists: impl Fn(&Id) -> bool) -> Option<Id> {
+        match self {
+            Source::Live(live, id) => live.next().map(*id),
+            Source::Snapshot(ids) => ids.find(exists),
+        }
+    }
+}
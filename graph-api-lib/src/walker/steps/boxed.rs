@@ -1,7 +1,11 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use smallbox::{SmallBox, space};
 
 // Use a reasonable size for the SmallBox - can be tuned
@@ -12,6 +16,19 @@ trait BoxedVertexWalkerOps<'graph, G: Graph, Context> {
     fn next(&mut self, graph: &'graph G) -> Option<G::VertexId>;
     fn ctx(&self) -> &Context;
     fn ctx_mut(&mut self) -> &mut Context;
+    fn explain(&self, steps: &mut Vec<StepInfo>);
+}
+
+// Helper trait for boxed vertex walkers that can also be rebound or profiled, see RebindVertexWalker
+trait RebindBoxedVertexWalkerOps<'graph, G: Graph, Context>:
+    BoxedVertexWalkerOps<'graph, G, Context>
+{
+    fn rebind<'read>(self: Box<Self>) -> ReboundVertexWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn profiled(self: Box<Self>, profiler: &Profiler) -> ReboundVertexWalker<'graph, G, Context>;
 }
 
 // Helper trait for boxed edge walkers that provides both next() and context access
@@ -19,6 +36,19 @@ trait BoxedEdgeWalkerOps<'graph, G: Graph, Context> {
     fn next(&mut self, graph: &'graph G) -> Option<G::EdgeId>;
     fn ctx(&self) -> &Context;
     fn ctx_mut(&mut self) -> &mut Context;
+    fn explain(&self, steps: &mut Vec<StepInfo>);
+}
+
+// Helper trait for boxed edge walkers that can also be rebound or profiled, see RebindEdgeWalker
+trait RebindBoxedEdgeWalkerOps<'graph, G: Graph, Context>:
+    BoxedEdgeWalkerOps<'graph, G, Context>
+{
+    fn rebind<'read>(self: Box<Self>) -> ReboundEdgeWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn profiled(self: Box<Self>, profiler: &Profiler) -> ReboundEdgeWalker<'graph, G, Context>;
 }
 
 // Blanket implementation for any vertex walker
@@ -37,6 +67,32 @@ where
     fn ctx_mut(&mut self) -> &mut Context {
         Walker::ctx_mut(self)
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        Walker::explain(self, steps)
+    }
+}
+
+// Blanket implementation for any vertex walker that can be rebound
+impl<'graph, G: Graph, W, Context> RebindBoxedVertexWalkerOps<'graph, G, Context> for W
+where
+    W: RebindVertexWalker<'graph, Graph = G, Context = Context> + 'graph,
+{
+    fn rebind<'read>(self: Box<Self>) -> ReboundVertexWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        ReboundVertexWalker {
+            inner: Box::new(RebindVertexWalker::rebind(*self)),
+        }
+    }
+
+    fn profiled(self: Box<Self>, profiler: &Profiler) -> ReboundVertexWalker<'graph, G, Context> {
+        ReboundVertexWalker {
+            inner: Box::new(ProfileVertexWalker::profiled(*self, profiler)),
+        }
+    }
 }
 
 // Blanket implementation for any edge walker
@@ -55,22 +111,49 @@ where
     fn ctx_mut(&mut self) -> &mut Context {
         Walker::ctx_mut(self)
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        Walker::explain(self, steps)
+    }
+}
+
+// Blanket implementation for any edge walker that can be rebound
+impl<'graph, G: Graph, W, Context> RebindBoxedEdgeWalkerOps<'graph, G, Context> for W
+where
+    W: RebindEdgeWalker<'graph, Graph = G, Context = Context> + 'graph,
+{
+    fn rebind<'read>(self: Box<Self>) -> ReboundEdgeWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        ReboundEdgeWalker {
+            inner: Box::new(RebindEdgeWalker::rebind(*self)),
+        }
+    }
+
+    fn profiled(self: Box<Self>, profiler: &Profiler) -> ReboundEdgeWalker<'graph, G, Context> {
+        ReboundEdgeWalker {
+            inner: Box::new(ProfileEdgeWalker::profiled(*self, profiler)),
+        }
+    }
 }
 
 /// A boxed vertex walker that uses SmallBox for type erasure
 /// This helps reduce monomorphization by hiding concrete walker types
 pub struct BoxedVertexWalker<'graph, G: Graph, Context> {
     // We box the entire walker using a trait object that provides all operations
-    inner: SmallBox<Box<dyn BoxedVertexWalkerOps<'graph, G, Context> + 'graph>, BoxSpace>,
+    inner: SmallBox<Box<dyn RebindBoxedVertexWalkerOps<'graph, G, Context> + 'graph>, BoxSpace>,
 }
 
 impl<'graph, G: Graph, Context: Clone> BoxedVertexWalker<'graph, G, Context> {
     pub(crate) fn new<W>(walker: W) -> Self
     where
-        W: VertexWalker<'graph, Graph = G, Context = Context> + 'graph,
+        W: RebindVertexWalker<'graph, Graph = G, Context = Context> + 'graph,
     {
         // Box the walker as a trait object that implements our ops trait
-        let boxed: Box<dyn BoxedVertexWalkerOps<'graph, G, Context> + 'graph> = Box::new(walker);
+        let boxed: Box<dyn RebindBoxedVertexWalkerOps<'graph, G, Context> + 'graph> =
+            Box::new(walker);
 
         Self {
             inner: SmallBox::new(boxed),
@@ -97,6 +180,11 @@ impl<'graph, G: Graph, Context: Clone + 'static> Walker<'graph>
         // Delegate to the inner walker's context
         self.inner.as_mut().ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        // Boxing is transparent, describe the inner walker's steps
+        self.inner.as_ref().explain(steps)
+    }
 }
 
 impl<'graph, G: Graph, Context: Clone + 'static> VertexWalker<'graph>
@@ -108,20 +196,86 @@ impl<'graph, G: Graph, Context: Clone + 'static> VertexWalker<'graph>
     }
 }
 
+impl<'graph, G: Graph, Context: Clone + 'static> ProfileVertexWalker<'graph>
+    for BoxedVertexWalker<'graph, G, Context>
+{
+    // Boxing is transparent, the inner walker's steps are counted
+    type Profiled = ReboundVertexWalker<'graph, G, Context>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        self.inner.into_inner().profiled(profiler)
+    }
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> RebindVertexWalker<'graph>
+    for BoxedVertexWalker<'graph, G, Context>
+{
+    type Rebound<'read>
+        = ReboundVertexWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        self.inner.into_inner().rebind()
+    }
+}
+
+/// A boxed vertex walker that has been rebound or profiled.
+pub struct ReboundVertexWalker<'graph, G: Graph, Context> {
+    inner: Box<dyn BoxedVertexWalkerOps<'graph, G, Context> + 'graph>,
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> Walker<'graph>
+    for ReboundVertexWalker<'graph, G, Context>
+{
+    type Graph = G;
+    type Context = Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        VertexWalker::next(self, graph).map(ElementId::Vertex)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        self.inner.ctx()
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        self.inner.ctx_mut()
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.inner.explain(steps)
+    }
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> VertexWalker<'graph>
+    for ReboundVertexWalker<'graph, G, Context>
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
+        self.inner.next(graph)
+    }
+}
+
 /// A boxed edge walker that uses SmallBox for type erasure
 /// This helps reduce monomorphization by hiding concrete walker types
 pub struct BoxedEdgeWalker<'graph, G: Graph, Context> {
     // We box the entire walker using a trait object that provides all operations
-    inner: SmallBox<Box<dyn BoxedEdgeWalkerOps<'graph, G, Context> + 'graph>, BoxSpace>,
+    inner: SmallBox<Box<dyn RebindBoxedEdgeWalkerOps<'graph, G, Context> + 'graph>, BoxSpace>,
 }
 
 impl<'graph, G: Graph, Context: Clone> BoxedEdgeWalker<'graph, G, Context> {
     pub(crate) fn new<W>(walker: W) -> Self
     where
-        W: EdgeWalker<'graph, Graph = G, Context = Context> + 'graph,
+        W: RebindEdgeWalker<'graph, Graph = G, Context = Context> + 'graph,
     {
         // Box the walker as a trait object that implements our ops trait
-        let boxed: Box<dyn BoxedEdgeWalkerOps<'graph, G, Context> + 'graph> = Box::new(walker);
+        let boxed: Box<dyn RebindBoxedEdgeWalkerOps<'graph, G, Context> + 'graph> =
+            Box::new(walker);
 
         Self {
             inner: SmallBox::new(boxed),
@@ -148,6 +302,11 @@ impl<'graph, G: Graph, Context: Clone + 'static> Walker<'graph>
         // Delegate to the inner walker's context
         self.inner.as_mut().ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        // Boxing is transparent, describe the inner walker's steps
+        self.inner.as_ref().explain(steps)
+    }
 }
 
 impl<'graph, G: Graph, Context: Clone + 'static> EdgeWalker<'graph>
@@ -159,11 +318,76 @@ impl<'graph, G: Graph, Context: Clone + 'static> EdgeWalker<'graph>
     }
 }
 
+impl<'graph, G: Graph, Context: Clone + 'static> ProfileEdgeWalker<'graph>
+    for BoxedEdgeWalker<'graph, G, Context>
+{
+    // Boxing is transparent, the inner walker's steps are counted
+    type Profiled = ReboundEdgeWalker<'graph, G, Context>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        self.inner.into_inner().profiled(profiler)
+    }
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> RebindEdgeWalker<'graph>
+    for BoxedEdgeWalker<'graph, G, Context>
+{
+    type Rebound<'read>
+        = ReboundEdgeWalker<'read, G, Context>
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        self.inner.into_inner().rebind()
+    }
+}
+
+/// A boxed edge walker that has been rebound or profiled.
+pub struct ReboundEdgeWalker<'graph, G: Graph, Context> {
+    inner: Box<dyn BoxedEdgeWalkerOps<'graph, G, Context> + 'graph>,
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> Walker<'graph>
+    for ReboundEdgeWalker<'graph, G, Context>
+{
+    type Graph = G;
+    type Context = Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        EdgeWalker::next(self, graph).map(ElementId::Edge)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        self.inner.ctx()
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        self.inner.ctx_mut()
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.inner.explain(steps)
+    }
+}
+
+impl<'graph, G: Graph, Context: Clone + 'static> EdgeWalker<'graph>
+    for ReboundEdgeWalker<'graph, G, Context>
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
+        self.inner.next(graph)
+    }
+}
+
 // Extension methods for builders to add boxed() method
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
-    Walker: VertexWalker<'graph, Graph = Graph> + 'graph,
+    Walker: RebindVertexWalker<'graph, Graph = Graph> + 'graph,
     Walker::Context: Clone + 'static,
 {
     /// # Boxed Step
@@ -226,6 +450,10 @@ where
     /// # Note
     /// This method works with any context type that implements `Clone + 'static`.
     /// The context is preserved through the boxing operation and updated on each `next()` call.
+    ///
+    /// The boxed walker can be rebound so that steps that mutate the graph, such as `mutate`, can
+    /// follow it. A walker added with `with_vertex_walker` must implement
+    /// [`RebindVertexWalker`](crate::RebindVertexWalker) to be boxed.
     pub fn boxed(
         self,
     ) -> VertexWalkerBuilder<
@@ -241,7 +469,7 @@ where
 impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
-    Walker: EdgeWalker<'graph, Graph = Graph> + 'graph,
+    Walker: RebindEdgeWalker<'graph, Graph = Graph> + 'graph,
     Walker::Context: Clone + 'static,
 {
     /// # Boxed Step (Edge Walker)
@@ -291,6 +519,10 @@ where
     /// # Note
     /// This method works with any context type that implements `Clone + 'static`.
     /// The context is preserved through the boxing operation and updated on each `next()` call.
+    ///
+    /// The boxed walker can be rebound so that steps that mutate the graph, such as `mutate`, can
+    /// follow it. A walker added with `with_edge_walker` must implement
+    /// [`RebindEdgeWalker`](crate::RebindEdgeWalker) to be boxed.
     pub fn boxed(
         self,
     ) -> EdgeWalkerBuilder<'graph, Mutability, Graph, BoxedEdgeWalker<'graph, Graph, Walker::Context>>
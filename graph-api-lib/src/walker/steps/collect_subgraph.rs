@@ -0,0 +1,203 @@
+use crate::copy::{IdMap, copy_elements};
+use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::{EdgeWalker, VertexWalker};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+{
+    /// # Collect Subgraph Step
+    ///
+    /// The `collect_subgraph` step consumes the traversal and copies every vertex that it
+    /// reached into a new graph of type `Target`, which can be any graph implementation with the
+    /// same vertex and edge types. Only the vertices are copied; use
+    /// [`collect_induced_subgraph`](Self::collect_induced_subgraph) to bring the edges between
+    /// them along too.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before collect_subgraph step (with vertices in traversal):
+    /// ```text
+    ///   [Person A]* --- knows ---> [Person B]* --- created ---> [Project]
+    /// ```
+    ///
+    /// After collect_subgraph step (a new graph with the traversal vertices):
+    /// ```text
+    ///   [Person A]    [Person B]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None, but the type of the new graph must be given, as in `collect_subgraph::<SimpleGraph<_, _>>()`
+    ///
+    /// ## Return Value
+    ///
+    /// The new graph, and an [`IdMap`] from the id of each vertex in this graph to the id of
+    /// its copy.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/collect_subgraph.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `collect_subgraph` step is a terminal operation - it consumes the walker
+    /// - Only the vertices the traversal ends on are copied, not the vertices and edges it
+    ///   passed through on the way. To keep the path, end the traversal on edges and use the
+    ///   edge `collect_subgraph`, which copies their endpoints too
+    /// - A vertex reached more than once is copied once
+    /// - Vertices are copied in the order that the traversal reached them
+    pub fn collect_subgraph<Target>(self) -> (Target, IdMap<Graph, Target>)
+    where
+        Target: crate::graph::Graph<Vertex = Graph::Vertex, Edge = Graph::Edge> + Default,
+        Graph::Vertex: Clone,
+        Graph::Edge: Clone,
+    {
+        self.copy_vertices(false)
+    }
+
+    /// # Collect Induced Subgraph Step
+    ///
+    /// The `collect_induced_subgraph` step consumes the traversal and copies every vertex that
+    /// it reached into a new graph of type `Target`, along with every edge between two of those
+    /// vertices. This is the induced subgraph of the traversal.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before collect_induced_subgraph step (with vertices in traversal):
+    /// ```text
+    ///   [Person A]* --- knows ---> [Person B]* --- created ---> [Project]
+    /// ```
+    ///
+    /// After collect_induced_subgraph step (a new graph with the traversal vertices and the edges between them):
+    /// ```text
+    ///   [Person A] --- knows ---> [Person B]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None, but the type of the new graph must be given, as in `collect_induced_subgraph::<SimpleGraph<_, _>>()`
+    ///
+    /// ## Return Value
+    ///
+    /// The new graph, and an [`IdMap`] from the ids of the vertices and edges in this graph to
+    /// the ids of their copies.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/collect_subgraph.rs", induced_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `collect_induced_subgraph` step is a terminal operation - it consumes the walker
+    /// - The outgoing edges of every collected vertex are scanned, so this is proportional to
+    ///   their degree rather than the size of the subgraph
+    pub fn collect_induced_subgraph<Target>(self) -> (Target, IdMap<Graph, Target>)
+    where
+        Target: crate::graph::Graph<Vertex = Graph::Vertex, Edge = Graph::Edge> + Default,
+        Graph::Vertex: Clone,
+        Graph::Edge: Clone,
+    {
+        self.copy_vertices(true)
+    }
+
+    fn copy_vertices<Target>(mut self, induced: bool) -> (Target, IdMap<Graph, Target>)
+    where
+        Target: crate::graph::Graph<Vertex = Graph::Vertex, Edge = Graph::Edge> + Default,
+        Graph::Vertex: Clone,
+        Graph::Edge: Clone,
+    {
+        let graph = self.graph();
+        let mut walker = self.walker();
+        let mut vertices = Vec::new();
+        while let Some(vertex) = walker.next(graph) {
+            vertices.push(vertex);
+        }
+        let mut target = Target::default();
+        let ids = copy_elements(
+            graph,
+            &mut target,
+            vertices,
+            std::iter::empty(),
+            induced,
+            |vertex| vertex.clone(),
+            |edge| edge.clone(),
+        );
+        (target, ids)
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: EdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Collect Subgraph Step
+    ///
+    /// The `collect_subgraph` step consumes the traversal and copies every edge that it reached
+    /// into a new graph of type `Target`, together with the vertices at either end of them.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before collect_subgraph step (with edges in traversal):
+    /// ```text
+    ///   [Person A] --- knows* ---> [Person B] --- created ---> [Project]
+    /// ```
+    ///
+    /// After collect_subgraph step (a new graph with the traversal edges and their endpoints):
+    /// ```text
+    ///   [Person A] --- knows ---> [Person B]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None, but the type of the new graph must be given, as in `collect_subgraph::<SimpleGraph<_, _>>()`
+    ///
+    /// ## Return Value
+    ///
+    /// The new graph, and an [`IdMap`] from the ids of the edges and their endpoints in this
+    /// graph to the ids of their copies.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/collect_subgraph.rs", edge_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `collect_subgraph` step is a terminal operation - it consumes the walker
+    /// - Only the traversal's edges are copied, even if other edges join the same vertices
+    /// - Only the last hop of a traversal is copied. Edges and vertices from earlier steps
+    ///   are not recorded
+    pub fn collect_subgraph<Target>(mut self) -> (Target, IdMap<Graph, Target>)
+    where
+        Target: crate::graph::Graph<Vertex = Graph::Vertex, Edge = Graph::Edge> + Default,
+        Graph::Vertex: Clone,
+        Graph::Edge: Clone,
+    {
+        let graph = self.graph();
+        let mut walker = self.walker();
+        let mut edges = Vec::new();
+        while let Some(edge) = walker.next(graph) {
+            edges.push(edge);
+        }
+        let mut target = Target::default();
+        let ids = copy_elements(
+            graph,
+            &mut target,
+            std::iter::empty(),
+            edges,
+            false,
+            |vertex| vertex.clone(),
+            |edge| edge.clone(),
+        );
+        (target, ids)
+    }
+}
@@ -1,8 +1,12 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, StartWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
 use crate::walker::steps::Empty;
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
@@ -127,6 +131,11 @@ where
             .as_mut()
             .expect("context cannot be retrieved before call to next")
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("push_context"));
+    }
 }
 
 impl<'graph, Parent, Predicate, Context> VertexWalker<'graph>
@@ -147,6 +156,45 @@ where
     }
 }
 
+impl<'graph, Parent, Callback, Context> ProfileVertexWalker<'graph>
+    for VertexContext<'graph, Parent, Callback, Context>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context) -> Context,
+    Context: Clone + 'static,
+{
+    type Profiled = Counted<VertexContext<'graph, Parent::Profiled, Callback, Context>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexContext::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback, Context> RebindVertexWalker<'graph>
+    for VertexContext<'graph, Parent, Callback, Context>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context) -> Context,
+    Context: Clone + 'static,
+{
+    type Rebound<'read>
+        = VertexContext<'read, Parent::Rebound<'read>, Callback, Context>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexContext::new(self.parent.rebind(), self.callback)
+    }
+}
+
 pub struct EdgeContext<'graph, Parent, Callback, Context>
 where
     Parent: EdgeWalker<'graph>,
@@ -198,6 +246,11 @@ where
             .as_mut()
             .expect("context cannot be retrieved before call to next")
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("push_context"));
+    }
 }
 
 impl<'graph, Parent, Predicate, Context> EdgeWalker<'graph>
@@ -208,16 +261,55 @@ where
     Context: Clone + 'static,
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
-        if let Some(next) = self.parent.next(graph) {
-            if let Some(edge) = graph.edge(next) {
-                self.context = Some((self.callback)(&edge, self.parent.ctx()));
-                return Some(next);
-            }
+        if let Some(next) = self.parent.next(graph)
+            && let Some(edge) = graph.edge(next)
+        {
+            self.context = Some((self.callback)(&edge, self.parent.ctx()));
+            return Some(next);
         }
         None
     }
 }
 
+impl<'graph, Parent, Callback, Context> ProfileEdgeWalker<'graph>
+    for EdgeContext<'graph, Parent, Callback, Context>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context) -> Context,
+    Context: Clone + 'static,
+{
+    type Profiled = Counted<EdgeContext<'graph, Parent::Profiled, Callback, Context>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeContext::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback, Context> RebindEdgeWalker<'graph>
+    for EdgeContext<'graph, Parent, Callback, Context>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context) -> Context,
+    Context: Clone + 'static,
+{
+    type Rebound<'read>
+        = EdgeContext<'read, Parent::Rebound<'read>, Callback, Context>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeContext::new(self.parent.rebind(), self.callback)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
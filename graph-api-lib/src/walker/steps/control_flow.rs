@@ -1,11 +1,34 @@
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use crate::{EdgeReference, ElementId, VertexReference};
 use include_doc::function_body;
 use std::marker::PhantomData;
 use std::ops::ControlFlow;
 
+/// A `control_flow` predicate for element references of type `Reference`.
+///
+/// This is implemented for closures of the form
+/// `Fn(&Reference, &mut Context) -> ControlFlow<Option<&Reference>, Option<&Reference>>`. It lets
+/// the predicate be required for references borrowing the graph for any lifetime, so that
+/// `control_flow` steps can be rebound before the graph is mutated.
+pub trait ControlFlowPredicate<Reference, Context>:
+    for<'a> Fn(&'a Reference, &mut Context) -> ControlFlow<Option<&'a Reference>, Option<&'a Reference>>
+{
+}
+
+impl<Reference, Context, Predicate> ControlFlowPredicate<Reference, Context> for Predicate where
+    Predicate: for<'a> Fn(
+        &'a Reference,
+        &mut Context,
+    ) -> ControlFlow<Option<&'a Reference>, Option<&'a Reference>>
+{
+}
+
 pub struct VertexControlFlow<'graph, Parent, Predicate> {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
@@ -24,15 +47,8 @@ impl<Parent, Predicate> VertexControlFlow<'_, Parent, Predicate> {
 
 impl<'graph, Parent, Predicate> Walker<'graph> for VertexControlFlow<'graph, Parent, Predicate>
 where
-    Self: 'graph,
     Parent: VertexWalker<'graph>,
-    for<'a> Predicate: Fn(
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &mut Parent::Context,
-    ) -> ControlFlow<
-        Option<&'a <Parent::Graph as Graph>::VertexReference<'graph>>,
-        Option<&'a <Parent::Graph as Graph>::VertexReference<'graph>>,
-    >,
+    Predicate: for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
 {
     type Graph = Parent::Graph;
     type Context = Parent::Context;
@@ -48,20 +64,18 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("control_flow"));
+    }
 }
 
 impl<'graph, Parent, Predicate> VertexWalker<'graph>
     for VertexControlFlow<'graph, Parent, Predicate>
 where
-    Self: 'graph,
     Parent: VertexWalker<'graph>,
-    for<'a> Predicate: Fn(
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &mut Parent::Context,
-    ) -> ControlFlow<
-        Option<&'a <Parent::Graph as Graph>::VertexReference<'graph>>,
-        Option<&'a <Parent::Graph as Graph>::VertexReference<'graph>>,
-    >,
+    Predicate: for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
         while let Some(next) = self.parent.next(graph) {
@@ -79,6 +93,43 @@ where
     }
 }
 
+impl<'graph, Parent, Predicate> ProfileVertexWalker<'graph>
+    for VertexControlFlow<'graph, Parent, Predicate>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Predicate: for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
+{
+    type Profiled = Counted<VertexControlFlow<'graph, Parent::Profiled, Predicate>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexControlFlow::new(
+            self.parent.profiled(profiler),
+            self.predicate,
+        ))
+    }
+}
+
+impl<'graph, Parent, Predicate> RebindVertexWalker<'graph>
+    for VertexControlFlow<'graph, Parent, Predicate>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Predicate: for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
+{
+    type Rebound<'read>
+        = VertexControlFlow<'read, Parent::Rebound<'read>, Predicate>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexControlFlow::new(self.parent.rebind(), self.predicate)
+    }
+}
+
 pub struct EdgeControlFlow<'graph, Parent, Predicate> {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
@@ -97,15 +148,9 @@ impl<Parent, Predicate> EdgeControlFlow<'_, Parent, Predicate> {
 
 impl<'graph, Parent, Predicate> Walker<'graph> for EdgeControlFlow<'graph, Parent, Predicate>
 where
-    Self: 'graph,
     Parent: EdgeWalker<'graph>,
-    for<'a> Predicate: Fn(
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &mut Parent::Context,
-    ) -> ControlFlow<
-        Option<&'a <Parent::Graph as Graph>::EdgeReference<'graph>>,
-        Option<&'a <Parent::Graph as Graph>::EdgeReference<'graph>>,
-    >,
+    Predicate:
+        for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
 {
     type Graph = Parent::Graph;
     type Context = Parent::Context;
@@ -121,19 +166,18 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("control_flow"));
+    }
 }
 
 impl<'graph, Parent, Predicate> EdgeWalker<'graph> for EdgeControlFlow<'graph, Parent, Predicate>
 where
-    Self: 'graph,
     Parent: EdgeWalker<'graph>,
-    for<'a> Predicate: Fn(
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &mut Parent::Context,
-    ) -> ControlFlow<
-        Option<&'a <Parent::Graph as Graph>::EdgeReference<'graph>>,
-        Option<&'a <Parent::Graph as Graph>::EdgeReference<'graph>>,
-    >,
+    Predicate:
+        for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
         while let Some(next) = self.parent.next(graph) {
@@ -151,6 +195,45 @@ where
     }
 }
 
+impl<'graph, Parent, Predicate> ProfileEdgeWalker<'graph>
+    for EdgeControlFlow<'graph, Parent, Predicate>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Predicate:
+        for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
+{
+    type Profiled = Counted<EdgeControlFlow<'graph, Parent::Profiled, Predicate>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeControlFlow::new(
+            self.parent.profiled(profiler),
+            self.predicate,
+        ))
+    }
+}
+
+impl<'graph, Parent, Predicate> RebindEdgeWalker<'graph>
+    for EdgeControlFlow<'graph, Parent, Predicate>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Predicate:
+        for<'r> ControlFlowPredicate<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
+{
+    type Rebound<'read>
+        = EdgeControlFlow<'read, Parent::Rebound<'read>, Predicate>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeControlFlow::new(self.parent.rebind(), self.predicate)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
@@ -217,14 +300,7 @@ where
         predicate: Predicate,
     ) -> VertexWalkerBuilder<'graph, Mutability, Graph, VertexControlFlow<'graph, Walker, Predicate>>
     where
-        Walker: 'graph,
-        for<'a> Predicate: Fn(
-                &'a Graph::VertexReference<'graph>,
-                &mut Walker::Context,
-            ) -> ControlFlow<
-                Option<&'a Graph::VertexReference<'graph>>,
-                Option<&'a Graph::VertexReference<'graph>>,
-            > + 'graph,
+        Predicate: for<'r> ControlFlowPredicate<Graph::VertexReference<'r>, Walker::Context>,
     {
         self.with_vertex_walker(|walker| VertexControlFlow::new(walker, predicate))
     }
@@ -296,14 +372,7 @@ where
         predicate: Predicate,
     ) -> EdgeWalkerBuilder<'graph, Mutability, Graph, EdgeControlFlow<'graph, Walker, Predicate>>
     where
-        Walker: 'graph,
-        for<'a> Predicate: Fn(
-                &'a Graph::EdgeReference<'graph>,
-                &mut Walker::Context,
-            ) -> ControlFlow<
-                Option<&'a Graph::EdgeReference<'graph>>,
-                Option<&'a Graph::EdgeReference<'graph>>,
-            > + 'graph,
+        Predicate: for<'r> ControlFlowPredicate<Graph::EdgeReference<'r>, Walker::Context>,
     {
         self.with_edge_walker(|walker| EdgeControlFlow::new(walker, predicate))
     }
@@ -0,0 +1,154 @@
+use crate::walker::RebindVertexWalker;
+use crate::walker::builder::{EdgeWalkerBuilder, Mutable, MutableMarker, VertexWalkerBuilder};
+use crate::walker::steps::{StagedEdges, StagedVertices};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: RebindVertexWalker<'graph, Graph = Graph>,
+{
+    /// # Add Edge To Step
+    ///
+    /// The `add_edge_to` step adds an edge from each vertex in the traversal to a target vertex,
+    /// then continues the traversal on the new edges.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before add_edge_to step (traversal position on Person vertices):
+    /// ```text
+    ///   [Person A]*        [Project X]
+    ///
+    ///   [Person B]*
+    /// ```
+    ///
+    /// After add_edge_to step (traversal position on the new edges):
+    /// ```text
+    ///   [Person A] --- created* ---> [Project X]
+    ///                                    ^
+    ///   [Person B] --- created* ---------+
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `target`: A function that receives the context of the current vertex and returns the id of the vertex to link to
+    /// - `edge`: A function that receives the context of the current vertex and returns the new edge
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    ///
+    /// ## Return Value
+    ///
+    /// Returns an edge traversal over the new edges, each with the context of the vertex it was added from.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/create.rs", add_edge_to_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Like `mutate`, the traversal is completed before any edges are added
+    /// - The current vertex is the tail of each new edge and the target is its head
+    /// - A vertex that is traversed more than once gets an edge each time
+    /// - `explain` and `profile` only report the steps from this one onwards, as the earlier steps have already run
+    pub fn add_edge_to<Target, EdgeFn>(
+        self,
+        target: Target,
+        edge: EdgeFn,
+    ) -> EdgeWalkerBuilder<'graph, MutableMarker, Graph, StagedEdges<Graph, Walker::Context>>
+    where
+        Target: Fn(&Walker::Context) -> Graph::VertexId,
+        EdgeFn: Fn(&Walker::Context) -> Graph::Edge,
+        Mutability: Mutable,
+    {
+        let staged = self.stage();
+        let graph = staged.graph;
+        let created = staged
+            .elements
+            .into_iter()
+            .map(|(vertex_id, ctx)| (graph.add_edge(vertex_id, target(&ctx), edge(&ctx)), ctx))
+            .collect();
+
+        crate::walker::builder::new_edge_mut(
+            graph,
+            StagedEdges::new("add_edge_to", created, staged.context),
+        )
+    }
+
+    /// # Add Vertex Linked Step
+    ///
+    /// The `add_vertex_linked` step adds a new vertex for each vertex in the traversal, joined
+    /// to it by a new edge, then continues the traversal on the new vertices.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before add_vertex_linked step (traversal position on Person vertices):
+    /// ```text
+    ///   [Person A]*
+    ///
+    ///   [Person B]*
+    /// ```
+    ///
+    /// After add_vertex_linked step (traversal position on the new Project vertices):
+    /// ```text
+    ///   [Person A] --- created ---> [Project X]*
+    ///
+    ///   [Person B] --- created ---> [Project Y]*
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `vertex`: A function that receives the context of the current vertex and returns the new vertex
+    /// - `edge`: A function that receives the context of the current vertex and returns the edge that links to the new vertex
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a vertex traversal over the new vertices, each with the context of the vertex it was added from.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/create.rs", add_vertex_linked_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Like `mutate`, the traversal is completed before any vertices are added
+    /// - The current vertex is the tail of each new edge and the new vertex is its head
+    /// - Use `edges(EdgeSearch::scan().incoming())` on the result to reach the new edges
+    /// - `explain` and `profile` only report the steps from this one onwards, as the earlier steps have already run
+    pub fn add_vertex_linked<VertexFn, EdgeFn>(
+        self,
+        vertex: VertexFn,
+        edge: EdgeFn,
+    ) -> VertexWalkerBuilder<'graph, MutableMarker, Graph, StagedVertices<Graph, Walker::Context>>
+    where
+        VertexFn: Fn(&Walker::Context) -> Graph::Vertex,
+        EdgeFn: Fn(&Walker::Context) -> Graph::Edge,
+        Mutability: Mutable,
+    {
+        let staged = self.stage();
+        let graph = staged.graph;
+        let created = staged
+            .elements
+            .into_iter()
+            .map(|(vertex_id, ctx)| {
+                let linked = graph.add_vertex(vertex(&ctx));
+                graph.add_edge(vertex_id, linked, edge(&ctx));
+                (linked, ctx)
+            })
+            .collect();
+
+        crate::walker::builder::new_mut(
+            graph,
+            StagedVertices::new("add_vertex_linked", created, staged.context),
+        )
+    }
+}
@@ -1,7 +1,10 @@
 use crate::ElementId;
 use crate::graph::Graph;
-use crate::walker::builder::{GraphAccess, ImmutableMarker, VertexWalkerBuilder, WalkerBuilder};
-use crate::walker::{VertexWalker, Walker};
+use crate::walker::builder::{
+    GraphAccess, ImmutableMarker, RebindWalkerBuilder, VertexWalkerBuilder, WalkerBuilder,
+};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{ProfileVertexWalker, RebindVertexWalker, VertexWalker, Walker};
 use include_doc::function_body;
 use std::cell::Cell;
 use std::marker::PhantomData;
@@ -69,6 +72,44 @@ where
     }
 }
 
+impl<'graph, Graph, Context> ProfileVertexWalker<'graph> for Waypoint<'graph, Graph, Context>
+where
+    Graph: crate::graph::Graph,
+    Context: 'static + Clone,
+{
+    // The waypoint is where a detour's path starts, not a step of its own
+    type Profiled = Self;
+
+    fn profiled(self, _profiler: &Profiler) -> Self::Profiled {
+        self
+    }
+}
+
+impl<'graph, Graph, Context> RebindVertexWalker<'graph> for Waypoint<'graph, Graph, Context>
+where
+    Graph: crate::graph::Graph,
+    Context: 'static + Clone,
+{
+    type Rebound<'read>
+        = Waypoint<'read, Graph, Context>
+    where
+        Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Graph: 'read,
+        'graph: 'read,
+    {
+        Waypoint {
+            _phantom: Default::default(),
+            next: self.next,
+            context: self.context,
+            current_context: None,
+        }
+    }
+}
+
 /// Detour creates a sub-traversal for each element in the main traversal.
 ///
 /// It allows exploring connected elements without losing the current position
@@ -153,6 +194,11 @@ where
             .as_mut()
             .expect("context cannot be retrieved before call to next")
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("detour"));
+    }
 }
 
 impl<'graph, Parent, Path, Terminal, WalkerBuilder> VertexWalker<'graph>
@@ -192,34 +238,200 @@ where
             );
         }
         let walker = self.walker.as_mut().expect("walker must be set").walker();
+        advance(
+            graph,
+            &mut self.parent,
+            walker,
+            &mut self.next,
+            &mut self.context,
+            &self.waypoint_next,
+            &self.waypoint_context,
+        )
+    }
+}
 
-        loop {
-            match walker.next_element(graph) {
-                None => {
-                    // The detour traversal is exhausted, get the next vertex from parent
-                    match self.parent.next(graph) {
-                        None => {
-                            // No more vertices in parent traversal
-                            return None;
-                        }
-                        Some(next) => {
-                            // Found a new vertex from parent, set up for next detour
-                            self.next = Some(next);
-                            // Share the next vertex ID with the waypoint
-                            self.waypoint_next.replace(Some(next));
-                            // Share the context with the waypoint
-                            self.waypoint_context
-                                .replace(Some(self.parent.ctx().clone()));
-                        }
+impl<'graph, Parent, Path, Terminal, WalkerBuilder> ProfileVertexWalker<'graph>
+    for Detour<'graph, Parent, Path, Terminal>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Path: Fn(
+        VertexWalkerBuilder<
+            'graph,
+            ImmutableMarker,
+            Parent::Graph,
+            Waypoint<'graph, Parent::Graph, Parent::Context>,
+        >,
+    ) -> WalkerBuilder,
+    WalkerBuilder: Into<
+        crate::walker::builder::WalkerBuilder<'graph, ImmutableMarker, Parent::Graph, Terminal>,
+    >,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    // The path is left unprofiled, so it is measured as part of the detour
+    type Profiled = Counted<Detour<'graph, Parent::Profiled, Path, Terminal>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(Detour::new(self.parent.profiled(profiler), self.path))
+    }
+}
+
+impl<'graph, Parent, Path, Terminal, WalkerBuilder> RebindVertexWalker<'graph>
+    for Detour<'graph, Parent, Path, Terminal>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Path: Fn(
+        VertexWalkerBuilder<
+            'graph,
+            ImmutableMarker,
+            Parent::Graph,
+            Waypoint<'graph, Parent::Graph, Parent::Context>,
+        >,
+    ) -> WalkerBuilder,
+    WalkerBuilder: Into<
+            crate::walker::builder::WalkerBuilder<'graph, ImmutableMarker, Parent::Graph, Terminal>,
+        > + RebindWalkerBuilder<'graph, Graph = Parent::Graph, Context = Terminal::Context>,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Rebound<'read>
+        = ReboundDetour<'read, Parent::Rebound<'read>, WalkerBuilder::Walker<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        // The path is built without a graph, it only chains steps onto the waypoint
+        let walker = (self.path)(crate::walker::builder::new(
+            GraphAccess::Taken,
+            Waypoint {
+                _phantom: Default::default(),
+                next: self.waypoint_next.clone(),
+                context: self.waypoint_context.clone(),
+                current_context: None,
+            },
+        ))
+        .rebind_walker();
+        ReboundDetour {
+            _phantom_data: Default::default(),
+            parent: self.parent.rebind(),
+            walker,
+            next: None,
+            context: None,
+            waypoint_next: self.waypoint_next,
+            waypoint_context: self.waypoint_context,
+        }
+    }
+}
+
+/// A [`Detour`] that has been rebound, with its path already built.
+pub struct ReboundDetour<'graph, Parent, Terminal>
+where
+    Parent: VertexWalker<'graph>,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+{
+    _phantom_data: PhantomData<&'graph ()>,
+    parent: Parent,
+    walker: Terminal,
+    next: Option<<Parent::Graph as Graph>::VertexId>,
+    context: Option<Terminal::Context>,
+    waypoint_next: Rc<Cell<Option<<Parent::Graph as Graph>::VertexId>>>,
+    waypoint_context: Rc<Cell<Option<Parent::Context>>>,
+}
+
+impl<'graph, Parent, Terminal> Walker<'graph> for ReboundDetour<'graph, Parent, Terminal>
+where
+    Parent: VertexWalker<'graph>,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+{
+    type Graph = Parent::Graph;
+    type Context = Terminal::Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Vertex)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        self.context
+            .as_ref()
+            .expect("next must be called before trying to get context")
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        self.context
+            .as_mut()
+            .expect("context cannot be retrieved before call to next")
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("detour"));
+    }
+}
+
+impl<'graph, Parent, Terminal> VertexWalker<'graph> for ReboundDetour<'graph, Parent, Terminal>
+where
+    Parent: VertexWalker<'graph>,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
+        advance(
+            graph,
+            &mut self.parent,
+            &mut self.walker,
+            &mut self.next,
+            &mut self.context,
+            &self.waypoint_next,
+            &self.waypoint_context,
+        )
+    }
+}
+
+/// Runs the detour walker until it finds an element, moving the parent on to the next vertex
+/// each time the detour from the current one is exhausted.
+fn advance<'graph, Parent, Terminal>(
+    graph: &'graph Parent::Graph,
+    parent: &mut Parent,
+    walker: &mut Terminal,
+    next: &mut Option<<Parent::Graph as Graph>::VertexId>,
+    context: &mut Option<Terminal::Context>,
+    waypoint_next: &Cell<Option<<Parent::Graph as Graph>::VertexId>>,
+    waypoint_context: &Cell<Option<Parent::Context>>,
+) -> Option<<Parent::Graph as Graph>::VertexId>
+where
+    Parent: VertexWalker<'graph>,
+    Terminal: Walker<'graph, Graph = Parent::Graph>,
+{
+    loop {
+        // Steps inside the detour are measured as part of the detour step
+        match walker.next_element(graph) {
+            None => {
+                // The detour traversal is exhausted, get the next vertex from parent
+                match parent.next(graph) {
+                    None => {
+                        // No more vertices in parent traversal
+                        return None;
+                    }
+                    Some(vertex) => {
+                        // Found a new vertex from parent, set up for next detour
+                        *next = Some(vertex);
+                        // Share the next vertex ID with the waypoint
+                        waypoint_next.replace(Some(vertex));
+                        // Share the context with the waypoint
+                        waypoint_context.replace(Some(parent.ctx().clone()));
                     }
                 }
-                Some(_ctx) => {
-                    // The detour found an element, save its context
-                    self.context = Some(walker.ctx().clone());
-                    // Return the original vertex from parent traversal
-                    // (detour only provides context, doesn't change the traversal elements)
-                    return self.next;
-                }
+            }
+            Some(_ctx) => {
+                // The detour found an element, save its context
+                *context = Some(walker.ctx().clone());
+                // Return the original vertex from parent traversal
+                // (detour only provides context, doesn't change the traversal elements)
+                return *next;
             }
         }
     }
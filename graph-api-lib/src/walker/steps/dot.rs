@@ -0,0 +1,121 @@
+use crate::dot::Dot;
+use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::{EdgeWalker, VertexWalker};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+{
+    /// # Dot Step
+    ///
+    /// The `dot` step consumes the traversal and returns a [`Dot`] writer for the graph with
+    /// every vertex that the traversal reached highlighted. Writing it with `to_string` gives
+    /// Graphviz DOT source that can be rendered to see what a traversal did.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before dot step (with vertices in traversal):
+    /// ```text
+    ///   [Person A]* --- knows ---> [Person B]* --- created ---> [Project]
+    /// ```
+    ///
+    /// After dot step (the whole graph, with traversal vertices highlighted):
+    /// ```text
+    ///   digraph {
+    ///       v0 [label="Person", style=filled, fillcolor=gold];
+    ///       v1 [label="Person", style=filled, fillcolor=gold];
+    ///       v2 [label="Project"];
+    ///       v0 -> v1 [label="Knows"];
+    ///       v1 -> v2 [label="Created"];
+    ///   }
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Return Value
+    ///
+    /// A [`Dot`] writer, which can be configured further before it is written, for instance
+    /// with [`Dot::only_highlighted`] to leave out the rest of the graph.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/dot.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `dot` step is a terminal operation - it consumes the walker
+    /// - Vertices are labelled with their label name and field values by default
+    /// - The output is intended for small graphs, as the whole graph is scanned when written
+    pub fn dot(mut self) -> Dot<'graph, Graph> {
+        let graph = self.graph();
+        let mut walker = self.walker();
+        let mut vertices = Vec::new();
+        while let Some(vertex) = walker.next(graph) {
+            vertices.push(vertex);
+        }
+        Dot::new(graph).highlight_vertices(vertices)
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: EdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Dot Step
+    ///
+    /// The `dot` step consumes the traversal and returns a [`Dot`] writer for the graph with
+    /// every edge that the traversal reached highlighted.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before dot step (with edges in traversal):
+    /// ```text
+    ///   [Person A] --- knows* ---> [Person B] --- created ---> [Project]
+    /// ```
+    ///
+    /// After dot step (the whole graph, with traversal edges highlighted):
+    /// ```text
+    ///   digraph {
+    ///       v0 [label="Person"];
+    ///       v1 [label="Person"];
+    ///       v2 [label="Project"];
+    ///       v0 -> v1 [label="Knows", color=red, penwidth=2];
+    ///       v1 -> v2 [label="Created"];
+    ///   }
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Return Value
+    ///
+    /// A [`Dot`] writer, which can be configured further before it is written.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/dot.rs", edge_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `dot` step is a terminal operation - it consumes the walker
+    /// - With [`Dot::only_highlighted`] the output keeps the edges and the vertices at either end
+    pub fn dot(mut self) -> Dot<'graph, Graph> {
+        let graph = self.graph();
+        let mut walker = self.walker();
+        let mut edges = Vec::new();
+        while let Some(edge) = walker.next(graph) {
+            edges.push(edge);
+        }
+        Dot::new(graph).highlight_edges(edges)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::graph::EdgeReference;
+use crate::walker::builder::{EdgeWalkerBuilder, Mutable, VertexWalkerBuilder};
+use crate::walker::{RebindEdgeWalker, RebindVertexWalker};
+use crate::{EdgeSearch, SupportsElementRemoval};
+use include_doc::function_body;
+
+/// The number of elements removed by a `drop` step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Dropped {
+    /// The number of vertices removed.
+    pub vertices: usize,
+    /// The number of edges removed, including the edges of removed vertices.
+    pub edges: usize,
+}
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: SupportsElementRemoval,
+    Walker: RebindVertexWalker<'graph, Graph = Graph>,
+{
+    /// # Drop Step
+    ///
+    /// The `drop` step removes every vertex in the traversal from the graph, along with any
+    /// edges attached to those vertices.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before drop step (traversal position on Person B):
+    /// ```text
+    ///   [Person A] --- knows ---> [Person B]* --- created ---> [Project X]
+    /// ```
+    ///
+    /// After drop step:
+    /// ```text
+    ///   [Person A]                                              [Project X]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    /// - The graph must implement `SupportsElementRemoval`
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a [`Dropped`](crate::Dropped) with the number of vertices and edges that were removed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/drop.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Like `mutate`, the traversal is completed before anything is removed
+    /// - Vertices that are traversed more than once are only removed and counted once
+    /// - The edges of each vertex are removed before the vertex itself, so they are included in the count
+    pub fn drop(self) -> Dropped
+    where
+        Mutability: Mutable,
+    {
+        let staged = self.stage();
+        let graph = staged.graph;
+        let mut dropped = Dropped::default();
+        for (vertex_id, _) in staged.elements {
+            // A vertex traversed more than once has already been removed
+            if graph.vertex(vertex_id).is_none() {
+                continue;
+            }
+            let edge_ids: Vec<_> = graph
+                .edges(vertex_id, &EdgeSearch::scan())
+                .map(|edge| edge.id())
+                .collect();
+            for edge_id in edge_ids {
+                // Self loops are seen from both ends, but only removed once
+                if graph.remove_edge(edge_id).is_some() {
+                    dropped.edges += 1;
+                }
+            }
+            if graph.remove_vertex(vertex_id).is_some() {
+                dropped.vertices += 1;
+            }
+        }
+        dropped
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: SupportsElementRemoval,
+    Walker: RebindEdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Drop Step
+    ///
+    /// The `drop` step removes every edge in the traversal from the graph. The vertices at
+    /// either end of the edges are left in place.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before drop step (traversal position on the knows edge):
+    /// ```text
+    ///   [Person A] --- knows* ---> [Person B] --- created ---> [Project X]
+    /// ```
+    ///
+    /// After drop step:
+    /// ```text
+    ///   [Person A]                 [Person B] --- created ---> [Project X]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    /// - The graph must implement `SupportsElementRemoval`
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a [`Dropped`](crate::Dropped) with the number of edges that were removed. The vertex count is always zero.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/drop.rs", edge_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Like `mutate`, the traversal is completed before anything is removed
+    /// - Edges that are traversed more than once are only removed and counted once
+    pub fn drop(self) -> Dropped
+    where
+        Mutability: Mutable,
+    {
+        let staged = self.stage();
+        let graph = staged.graph;
+        let mut dropped = Dropped::default();
+        for (edge_id, _) in staged.elements {
+            if graph.remove_edge(edge_id).is_some() {
+                dropped.edges += 1;
+            }
+        }
+        dropped
+    }
+}
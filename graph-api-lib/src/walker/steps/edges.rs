@@ -1,6 +1,10 @@
 use crate::graph::{EdgeReference, Graph};
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::stable::Source;
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, RebindEdgeWalker, RebindVertexWalker, VertexWalker, Walker,
+};
 use crate::{EdgeSearch, ElementId};
 use include_doc::function_body;
 
@@ -12,7 +16,12 @@ where
     <Parent as Walker<'graph>>::Graph: 'graph,
 {
     parent: Parent,
-    current_iter: Option<<Parent::Graph as Graph>::EdgeIter<'search, 'graph>>,
+    current_iter: Option<
+        Source<
+            <Parent::Graph as Graph>::EdgeIter<'search, 'graph>,
+            <Parent::Graph as Graph>::EdgeId,
+        >,
+    >,
     edge_search: EdgeSearch<'search, Parent::Graph>,
     current: Option<<Parent::Graph as Graph>::VertexId>,
 }
@@ -54,6 +63,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::edges("edges", &self.edge_search));
+    }
 }
 
 impl<'graph, Parent> EdgeWalker<'graph> for Edges<'_, 'graph, Parent>
@@ -64,13 +78,16 @@ where
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
         loop {
             if let Some(ref mut iter) = self.current_iter {
-                if let Some(edge) = iter.next() {
-                    return Some(edge.id());
+                if let Some(edge) = iter.next(|id| graph.edge(*id).is_some()) {
+                    return Some(edge);
                 }
                 self.current_iter = None;
             } else if let Some(vertex) = self.parent.next(graph) {
                 self.current = Some(vertex);
-                self.current_iter = Some(graph.edges(vertex, &self.edge_search));
+                self.current_iter = Some(Source::new(
+                    graph.edges(vertex, &self.edge_search),
+                    |edge| edge.id(),
+                ));
             } else {
                 return None;
             }
@@ -78,6 +95,38 @@ where
     }
 }
 
+impl<'search, 'graph, Parent> ProfileEdgeWalker<'graph> for Edges<'search, 'graph, Parent>
+where
+    Parent: RebindVertexWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Profiled = Counted<Edges<'search, 'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(Edges::new(self.parent.profiled(profiler), self.edge_search))
+    }
+}
+
+impl<'search, 'graph, Parent> RebindEdgeWalker<'graph> for Edges<'search, 'graph, Parent>
+where
+    Parent: RebindVertexWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Rebound<'read>
+        = Edges<'search, 'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        Edges::new(self.parent.rebind(), self.edge_search)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
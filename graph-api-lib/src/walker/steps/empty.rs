@@ -1,6 +1,7 @@
 use crate::ElementId;
 use crate::graph::Graph;
-use crate::walker::{VertexWalker, Walker};
+use crate::walker::profiler::Profiler;
+use crate::walker::{ProfileVertexWalker, RebindVertexWalker, VertexWalker, Walker};
 use std::marker::PhantomData;
 
 /// # Empty Walker
@@ -68,3 +69,34 @@ where
         None
     }
 }
+
+impl<'graph, G: Graph, Context> ProfileVertexWalker<'graph> for Empty<G, Context>
+where
+    Context: Clone + 'static,
+{
+    // The empty walker is where a traversal starts, not a step of its own
+    type Profiled = Self;
+
+    fn profiled(self, _profiler: &Profiler) -> Self::Profiled {
+        self
+    }
+}
+
+impl<'graph, G: Graph, Context> RebindVertexWalker<'graph> for Empty<G, Context>
+where
+    Context: Clone + 'static,
+{
+    type Rebound<'read>
+        = Self
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        self
+    }
+}
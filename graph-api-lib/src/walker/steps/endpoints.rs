@@ -1,6 +1,9 @@
 use crate::ElementId;
 use crate::graph::{EdgeReference, Graph};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker, VertexWalker, Walker,
+};
 use std::marker::PhantomData;
 
 /// # Endpoint Type
@@ -86,6 +89,14 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new(match self.end {
+            End::Head => "head",
+            End::Tail => "tail",
+        }));
+    }
 }
 
 impl<'search, 'graph, Parent> VertexWalker<'graph> for Endpoints<'graph, Parent>
@@ -103,3 +114,35 @@ where
         })
     }
 }
+
+impl<'graph, Parent> ProfileVertexWalker<'graph> for Endpoints<'graph, Parent>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Profiled = Counted<Endpoints<'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(Endpoints::new(self.parent.profiled(profiler), self.end))
+    }
+}
+
+impl<'graph, Parent> RebindVertexWalker<'graph> for Endpoints<'graph, Parent>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Rebound<'read>
+        = Endpoints<'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        Endpoints::new(self.parent.rebind(), self.end)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{self, Explain};
+use crate::walker::{EdgeWalker, VertexWalker};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+{
+    /// # Explain Step
+    ///
+    /// The `explain` step describes the steps of a traversal without running it. For steps that
+    /// read from the graph it reports which `VertexSearch` variant or edge search was chosen and
+    /// any limit that has been pushed down to the graph.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Traversal being explained (nothing is visited):
+    /// ```text
+    ///   vertices(label Person) -> filter -> edges(Outgoing) -> head
+    ///
+    ///   Result:
+    ///   0: vertices (label Person)
+    ///   1: filter
+    ///   2: edges (Outgoing edges)
+    ///   3: head
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Return Value
+    ///
+    /// An `Explain` listing each step in the order that it was added. It implements `Display`
+    /// for printing.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/explain.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `explain` step is a terminal operation, but the graph is not read
+    /// - Steps inside a `detour` are shown as the single `detour` step
+    /// - `boxed` is transparent and does not appear in the output
+    /// - Custom steps appear only if their walker overrides `Walker::explain`
+    pub fn explain(self) -> Explain {
+        profiler::explain(&self.walker())
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: EdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Explain Step
+    ///
+    /// The `explain` step describes the steps of an edge traversal without running it.
+    ///
+    /// See the documentation for [`VertexWalkerBuilder::explain`] for more details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/explain.rs", edge_example, [])]
+    /// ```
+    pub fn explain(self) -> Explain {
+        profiler::explain(&self.walker())
+    }
+}
@@ -1,7 +1,11 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
 
@@ -41,6 +45,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("filter"));
+    }
 }
 
 impl<'graph, Parent, Predicate> VertexWalker<'graph> for VertexFilter<'graph, Parent, Predicate>
@@ -50,16 +59,53 @@ where
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
         while let Some(next) = self.parent.next(graph) {
-            if let Some(vertex) = graph.vertex(next) {
-                if (self.predicate)(&vertex, self.parent.ctx()) {
-                    return Some(next);
-                }
+            if let Some(vertex) = graph.vertex(next)
+                && (self.predicate)(&vertex, self.parent.ctx())
+            {
+                return Some(next);
             }
         }
         None
     }
 }
 
+impl<'graph, Parent, Predicate> ProfileVertexWalker<'graph>
+    for VertexFilter<'graph, Parent, Predicate>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Predicate: Fn(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context) -> bool,
+{
+    type Profiled = Counted<VertexFilter<'graph, Parent::Profiled, Predicate>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexFilter::new(
+            self.parent.profiled(profiler),
+            self.predicate,
+        ))
+    }
+}
+
+impl<'graph, Parent, Predicate> RebindVertexWalker<'graph>
+    for VertexFilter<'graph, Parent, Predicate>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Predicate: Fn(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context) -> bool,
+{
+    type Rebound<'read>
+        = VertexFilter<'read, Parent::Rebound<'read>, Predicate>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexFilter::new(self.parent.rebind(), self.predicate)
+    }
+}
+
 pub struct EdgeFilter<'graph, Parent, Predicate> {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
@@ -93,6 +139,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("filter"));
+    }
 }
 
 impl<'graph, Parent, Predicate> EdgeWalker<'graph> for EdgeFilter<'graph, Parent, Predicate>
@@ -111,6 +162,41 @@ where
     }
 }
 
+impl<'graph, Parent, Predicate> ProfileEdgeWalker<'graph> for EdgeFilter<'graph, Parent, Predicate>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Predicate: Fn(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context) -> bool,
+{
+    type Profiled = Counted<EdgeFilter<'graph, Parent::Profiled, Predicate>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeFilter::new(
+            self.parent.profiled(profiler),
+            self.predicate,
+        ))
+    }
+}
+
+impl<'graph, Parent, Predicate> RebindEdgeWalker<'graph> for EdgeFilter<'graph, Parent, Predicate>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Predicate: Fn(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context) -> bool,
+{
+    type Rebound<'read>
+        = EdgeFilter<'read, Parent::Rebound<'read>, Predicate>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeFilter::new(self.parent.rebind(), self.predicate)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
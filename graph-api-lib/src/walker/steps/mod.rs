@@ -2,31 +2,41 @@
 pub use self::context::{
     ContextRef, DefaultEdgeContext, DefaultVertexContext, EdgeContext, VertexContext,
 };
-pub use self::control_flow::{EdgeControlFlow, VertexControlFlow};
+pub use self::control_flow::{ControlFlowPredicate, EdgeControlFlow, VertexControlFlow};
 pub use self::detour::{Detour, Waypoint};
+pub use self::drop::Dropped;
 pub use self::edges::Edges;
 pub use self::empty::Empty;
 pub use self::endpoints::{End, Endpoints};
 pub use self::filter::{EdgeFilter, VertexFilter};
+#[cfg(feature = "parallel")]
+pub use self::par_vertices::{ParallelVertices, PartitionWalkerBuilder};
 pub use self::take::{EdgeTake, VertexTake};
 // No need to re-export the mutate_context types as they're not used externally
 pub use self::probe::{EdgeProbe, VertexProbe};
-pub use self::reduce::{EdgeReduce, VertexReduce};
+pub use self::random_walk::RandomWalk;
+pub use self::reduce::{EdgeReduce, ElementReducer, VertexReduce};
+pub use self::staged::{StagedEdges, StagedVertices};
 pub use self::vertices::Vertices;
 pub use self::vertices_by_id::VertexIter;
 
 // These are the implementations for the builder methods
 mod boxed;
 mod collect;
+mod collect_subgraph;
 mod context;
 mod control_flow;
 mod count;
+mod create;
 mod dbg;
 mod default_context;
 mod detour;
+mod dot;
+mod drop;
 mod edges;
 mod empty;
 mod endpoints;
+mod explain;
 mod filter;
 mod first;
 mod fold;
@@ -35,8 +45,15 @@ mod into_iter;
 mod map;
 mod mutate;
 mod mutate_context;
+mod mutate_each;
+#[cfg(feature = "parallel")]
+mod par_vertices;
 mod probe;
+mod profile;
+mod random_walk;
 mod reduce;
+mod sample;
+mod staged;
 mod tail;
 mod take;
 mod vertices;
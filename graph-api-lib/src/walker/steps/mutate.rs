@@ -1,11 +1,11 @@
 use crate::walker::builder::{EdgeWalkerBuilder, Mutable, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker};
+use crate::walker::{RebindEdgeWalker, RebindVertexWalker};
 use include_doc::function_body;
 
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
-    Walker: VertexWalker<'graph, Graph = Graph>,
+    Walker: RebindVertexWalker<'graph, Graph = Graph>,
 {
     /// # Mutate Step
     ///
@@ -61,62 +61,102 @@ where
     /// - For better performance with large traversals, consider using `limit()` before `mutate()`
     /// - Can be used to implement complex graph algorithms like community detection, path finding, or graph rewriting
     /// - Related steps: `filter()` for conditional traversal, `collect()` for materializing results
-    pub fn mutate<Callback>(mut self, callback: Callback) -> usize
+    pub fn mutate<Callback>(self, callback: Callback) -> usize
     where
         Callback: Fn(&mut Walker::Graph, Graph::VertexId, &Walker::Context),
         Mutability: Mutable,
-        'graph: 'graph,
     {
-        let graph = self.graph_mut();
-        let graph_copy: &Graph = unsafe { std::mem::transmute(&*graph) };
-        let mut walker = self.walker();
-
-        let mut contexts = Vec::new();
-        while let Some(vertex_id) = walker.next(graph_copy) {
-            let ctx = walker.ctx().clone();
-            contexts.push((vertex_id, ctx));
+        let staged = self.stage();
+        for (vertex_id, ctx) in &staged.elements {
+            callback(staged.graph, *vertex_id, ctx);
         }
+        staged.elements.len()
+    }
 
-        let mut count = 0;
-        for (vertex_id, ctx) in contexts {
-            callback(graph, vertex_id, &ctx);
-            count += 1;
-        }
-        count
+    /// # Mutate Batched Step
+    ///
+    /// The `mutate_batched` step modifies the graph like `mutate`, but only holds `batch_size`
+    /// elements at a time. The traversal is read until a batch is full, the callback runs for
+    /// each element in the batch, and then the traversal resumes.
+    ///
+    /// ## Parameters
+    ///
+    /// - `batch_size`: The number of elements to read before applying the callback
+    /// - `callback`: A function that receives:
+    ///   - A mutable reference to the graph
+    ///   - The vertex ID
+    ///   - The context for the current vertex
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    ///
+    /// ## Return Value
+    ///
+    /// Returns the number of elements that were passed to the callback.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/mutate.rs", batched_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - `mutate` holds every `(id, context)` pair in memory, which for millions of vertices can be as large as the graph
+    /// - While the traversal runs, the `vertices` and `edges` steps visit a snapshot of ids taken when they start, so vertices and edges added by the callback are not visited
+    /// - Elements removed by the callback are skipped by the rest of the traversal, including the rest of the batch that removed them
+    /// - Steps that see the whole traversal, such as `sample`, still hold it in memory
+    /// - A walker added with `with_vertex_walker` must not hold references into the graph between calls to `next`, as the graph changes between batches
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn mutate_batched<Callback>(self, batch_size: usize, callback: Callback) -> usize
+    where
+        Callback: Fn(&mut Walker::Graph, Graph::VertexId, &Walker::Context),
+        Mutability: Mutable,
+    {
+        self.stage_batches(batch_size, callback)
     }
 }
 
 impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
-    Walker: EdgeWalker<'graph, Graph = Graph>,
+    Walker: RebindEdgeWalker<'graph, Graph = Graph>,
 {
     /// # Mutate Step
     ///
     /// Allows you to modify the graph during edge traversal.
     ///
     /// See the documentation for [`VertexWalkerBuilder::mutate`] for more details.
-    pub fn mutate<Callback>(mut self, callback: Callback) -> usize
+    pub fn mutate<Callback>(self, callback: Callback) -> usize
     where
         Callback: Fn(&mut Walker::Graph, Graph::EdgeId, &Walker::Context),
         Mutability: Mutable,
-        'graph: 'graph,
     {
-        let graph = self.graph_mut();
-        let graph_copy: &Graph = unsafe { std::mem::transmute(&*graph) };
-        let mut walker = self.walker();
-
-        let mut contexts = Vec::new();
-        while let Some(edge) = walker.next(graph_copy) {
-            let ctx = walker.ctx().clone();
-            contexts.push((edge, ctx));
+        let staged = self.stage();
+        for (edge_id, ctx) in &staged.elements {
+            callback(staged.graph, *edge_id, ctx);
         }
+        staged.elements.len()
+    }
 
-        let mut count = 0;
-        for (edge_id, ctx) in contexts {
-            callback(graph, edge_id, &ctx);
-            count += 1;
-        }
-        count
+    /// # Mutate Batched Step
+    ///
+    /// Modifies the graph during edge traversal, applying the callback `batch_size` edges at a time.
+    ///
+    /// See the documentation for [`VertexWalkerBuilder::mutate_batched`] for more details.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn mutate_batched<Callback>(self, batch_size: usize, callback: Callback) -> usize
+    where
+        Callback: Fn(&mut Walker::Graph, Graph::EdgeId, &Walker::Context),
+        Mutability: Mutable,
+    {
+        self.stage_batches(batch_size, callback)
     }
 }
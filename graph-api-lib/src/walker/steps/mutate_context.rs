@@ -1,6 +1,10 @@
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
 
@@ -164,6 +168,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("mutate_context"));
+    }
 }
 
 impl<'graph, Parent, Callback> VertexWalker<'graph>
@@ -177,16 +186,55 @@ where
         &mut self,
         graph: &'graph Self::Graph,
     ) -> Option<<Self::Graph as crate::graph::Graph>::VertexId> {
-        if let Some(next) = self.parent.next(graph) {
-            if let Some(vertex) = graph.vertex(next) {
-                (self.callback)(&vertex, self.parent.ctx_mut());
-                return Some(next);
-            }
+        if let Some(next) = self.parent.next(graph)
+            && let Some(vertex) = graph.vertex(next)
+        {
+            (self.callback)(&vertex, self.parent.ctx_mut());
+            return Some(next);
         }
         None
     }
 }
 
+impl<'graph, Parent, Callback> ProfileVertexWalker<'graph>
+    for VertexMutateContext<'graph, Parent, Callback>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Callback:
+        Fn(&<Parent::Graph as crate::graph::Graph>::VertexReference<'_>, &mut Parent::Context),
+{
+    type Profiled = Counted<VertexMutateContext<'graph, Parent::Profiled, Callback>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexMutateContext::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback> RebindVertexWalker<'graph>
+    for VertexMutateContext<'graph, Parent, Callback>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Callback:
+        Fn(&<Parent::Graph as crate::graph::Graph>::VertexReference<'_>, &mut Parent::Context),
+{
+    type Rebound<'read>
+        = VertexMutateContext<'read, Parent::Rebound<'read>, Callback>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexMutateContext::new(self.parent.rebind(), self.callback)
+    }
+}
+
 pub struct EdgeMutateContext<'graph, Parent, Callback>
 where
     Parent: EdgeWalker<'graph>,
@@ -233,6 +281,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("mutate_context"));
+    }
 }
 
 impl<'graph, Parent, Callback> EdgeWalker<'graph> for EdgeMutateContext<'graph, Parent, Callback>
@@ -244,12 +297,49 @@ where
         &mut self,
         graph: &'graph Self::Graph,
     ) -> Option<<Self::Graph as crate::graph::Graph>::EdgeId> {
-        if let Some(next) = self.parent.next(graph) {
-            if let Some(edge) = graph.edge(next) {
-                (self.callback)(&edge, self.parent.ctx_mut());
-                return Some(next);
-            }
+        if let Some(next) = self.parent.next(graph)
+            && let Some(edge) = graph.edge(next)
+        {
+            (self.callback)(&edge, self.parent.ctx_mut());
+            return Some(next);
         }
         None
     }
 }
+
+impl<'graph, Parent, Callback> ProfileEdgeWalker<'graph>
+    for EdgeMutateContext<'graph, Parent, Callback>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as crate::graph::Graph>::EdgeReference<'_>, &mut Parent::Context),
+{
+    type Profiled = Counted<EdgeMutateContext<'graph, Parent::Profiled, Callback>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeMutateContext::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback> RebindEdgeWalker<'graph>
+    for EdgeMutateContext<'graph, Parent, Callback>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Callback: Fn(&<Parent::Graph as crate::graph::Graph>::EdgeReference<'_>, &mut Parent::Context),
+{
+    type Rebound<'read>
+        = EdgeMutateContext<'read, Parent::Rebound<'read>, Callback>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeMutateContext::new(self.parent.rebind(), self.callback)
+    }
+}
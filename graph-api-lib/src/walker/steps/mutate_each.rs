@@ -0,0 +1,123 @@
+use crate::walker::builder::{EdgeWalkerBuilder, Mutable, MutableMarker, VertexWalkerBuilder};
+use crate::walker::steps::{StagedEdges, StagedVertices};
+use crate::walker::{RebindEdgeWalker, RebindVertexWalker};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: RebindVertexWalker<'graph, Graph = Graph>,
+{
+    /// # Mutate Each Step
+    ///
+    /// The `mutate_each` step modifies the graph for each vertex in the traversal, like `mutate`,
+    /// but then continues the traversal on the same vertices instead of ending it.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before mutate_each step (traversal position on Person vertices):
+    /// ```text
+    ///   [Person A]*        [Project X]
+    ///
+    ///   [Person B]*
+    /// ```
+    ///
+    /// After mutate_each step (adding 'Created' edges, traversal position unchanged):
+    /// ```text
+    ///   [Person A]* ---- Created ----> [Project X]
+    ///                                      ^
+    ///   [Person B]* ---- Created ----------+
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `callback`: A function that receives:
+    ///   - A mutable reference to the graph
+    ///   - The vertex ID
+    ///   - The context for the current vertex
+    ///
+    /// ## Requirements
+    ///
+    /// - Must use `walk_mut()` instead of `walk()` to get a mutable graph reference
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a traversal over the same vertices, with the same contexts, so that further steps
+    /// see the changes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/mutate_each.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The traversal is read in full before the first change is made, then the callback runs for each vertex in order
+    /// - Vertices that no longer exist after the callbacks have run are left out of the rest of the traversal
+    /// - `explain` and `profile` only report the steps from this one onwards, as the earlier steps have already run
+    /// - Use `mutate()` when nothing follows the changes
+    pub fn mutate_each<Callback>(
+        self,
+        callback: Callback,
+    ) -> VertexWalkerBuilder<'graph, MutableMarker, Graph, StagedVertices<Graph, Walker::Context>>
+    where
+        Callback: Fn(&mut Graph, Graph::VertexId, &Walker::Context),
+        Mutability: Mutable,
+    {
+        let mut staged = self.stage();
+        for (vertex_id, ctx) in &staged.elements {
+            callback(staged.graph, *vertex_id, ctx);
+        }
+        let graph = staged.graph;
+        staged
+            .elements
+            .retain(|(vertex_id, _)| graph.vertex(*vertex_id).is_some());
+
+        crate::walker::builder::new_mut(
+            graph,
+            StagedVertices::new("mutate_each", staged.elements, staged.context),
+        )
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: RebindEdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Mutate Each Step
+    ///
+    /// Modifies the graph for each edge in the traversal, then continues the traversal on the
+    /// same edges.
+    ///
+    /// See the documentation for [`VertexWalkerBuilder::mutate_each`] for more details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/mutate_each.rs", edge_example, [])]
+    /// ```
+    pub fn mutate_each<Callback>(
+        self,
+        callback: Callback,
+    ) -> EdgeWalkerBuilder<'graph, MutableMarker, Graph, StagedEdges<Graph, Walker::Context>>
+    where
+        Callback: Fn(&mut Graph, Graph::EdgeId, &Walker::Context),
+        Mutability: Mutable,
+    {
+        let mut staged = self.stage();
+        for (edge_id, ctx) in &staged.elements {
+            callback(staged.graph, *edge_id, ctx);
+        }
+        let graph = staged.graph;
+        staged
+            .elements
+            .retain(|(edge_id, _)| graph.edge(*edge_id).is_some());
+
+        crate::walker::builder::new_edge_mut(
+            graph,
+            StagedEdges::new("mutate_each", staged.elements, staged.context),
+        )
+    }
+}
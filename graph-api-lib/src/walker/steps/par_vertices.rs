@@ -0,0 +1,130 @@
+use crate::search::vertex::VertexSearch;
+use crate::walker::builder::{ImmutableMarker, StartWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::steps::{Empty, VertexIter};
+use crate::{SupportsParallelScan, VertexPartition};
+use include_doc::function_body;
+use rayon::prelude::*;
+
+// ================ PARALLEL VERTICES IMPLEMENTATION ================
+
+/// The walker builder that each partition of a parallel traversal starts from.
+pub type PartitionWalkerBuilder<'graph, Graph> = VertexWalkerBuilder<
+    'graph,
+    ImmutableMarker,
+    Graph,
+    VertexIter<'graph, Empty<Graph, ()>, VertexPartition<'graph, Graph>>,
+>;
+
+/// A vertex search that will be split into partitions and walked on the rayon thread pool.
+///
+/// Created by the `par_vertices` step.
+pub struct ParallelVertices<'search, 'graph, Graph>
+where
+    Graph: crate::Graph,
+{
+    graph: &'graph Graph,
+    search: VertexSearch<'search, Graph>,
+    partitions: usize,
+}
+
+impl<'graph, Graph> ParallelVertices<'_, 'graph, Graph>
+where
+    Graph: SupportsParallelScan,
+    Graph::VertexId: Send,
+{
+    /// Sets the number of partitions to split the search into.
+    /// Defaults to the number of threads in the current rayon thread pool.
+    pub fn partitions(mut self, partitions: usize) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    /// Runs `traversal` once per partition in parallel, returning a parallel iterator over the
+    /// results.
+    ///
+    /// Each call receives a walker builder positioned on the vertices of one partition and can
+    /// apply any steps and a terminal operation. The results are then combined with rayon, for
+    /// instance with `sum()` for counts or `reduce()` for folds.
+    pub fn map_partitions<R, Traversal>(
+        self,
+        traversal: Traversal,
+    ) -> impl ParallelIterator<Item = R>
+    where
+        R: Send,
+        Traversal: Fn(PartitionWalkerBuilder<'graph, Graph>) -> R + Send + Sync,
+    {
+        let graph = self.graph;
+        graph
+            .vertex_partitions(&self.search, self.partitions)
+            .into_par_iter()
+            .map(move |partition| traversal(graph.walk().vertices_by_id(partition)))
+    }
+}
+
+impl<'graph, Graph> StartWalkerBuilder<'graph, ImmutableMarker, Graph, ()>
+where
+    Graph: SupportsParallelScan,
+    Graph::VertexId: Send,
+{
+    /// # Parallel Vertices Step
+    ///
+    /// The `par_vertices` step splits the vertices matching a search into partitions and walks
+    /// each partition on its own thread using rayon. The rest of the traversal is supplied as a
+    /// closure that runs once per partition, and the per partition results are combined with
+    /// rayon's parallel iterator methods.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before par_vertices step (empty traversal):
+    /// ```text
+    ///   [A] --- edge1 ---> [B] --- edge2 ---> [C]
+    ///    ^
+    ///    |
+    ///   edge3
+    ///    |
+    ///   [D]
+    /// ```
+    ///
+    /// After par_vertices step with two partitions (each partition walked on its own thread):
+    /// ```text
+    ///   [A]¹ --- edge1 ---> [B]¹ --- edge2 ---> [C]²
+    ///    ^
+    ///    |
+    ///   edge3
+    ///    |
+    ///   [D]²
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `vertex_search`: A `VertexSearch` that defines which vertices to include
+    ///
+    /// ## Return Value
+    ///
+    /// A `ParallelVertices` that runs a traversal per partition with `map_partitions`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/par_vertices.rs", example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Requires the `parallel` feature
+    /// - Only available on immutable walks, the graph is shared between threads
+    /// - Steps that look at one element at a time (`filter`, `map`, `edges`, `count`, `fold`) give the same combined result as a sequential walk
+    /// - Steps that look across elements, such as `take`, `first` or `dedup`, apply per partition
+    /// - Scans and label searches are split by the graph's storage, index lookups and searches with a limit run as a single partition
+    /// - The order of results across partitions is not defined
+    pub fn par_vertices<'search, T: Into<VertexSearch<'search, Graph>>>(
+        mut self,
+        vertex_search: T,
+    ) -> ParallelVertices<'search, 'graph, Graph> {
+        ParallelVertices {
+            graph: self.graph.take(),
+            search: vertex_search.into(),
+            partitions: rayon::current_num_threads(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
 
@@ -41,6 +45,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("probe"));
+    }
 }
 
 impl<'graph, Parent, Callback> VertexWalker<'graph> for VertexProbe<'graph, Parent, Callback>
@@ -50,15 +59,50 @@ where
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
         let next = self.parent.next(graph);
-        if let Some(id) = next {
-            if let Some(vertex) = graph.vertex(id) {
-                (self.callback)(&vertex, self.parent.ctx());
-            }
+        if let Some(id) = next
+            && let Some(vertex) = graph.vertex(id)
+        {
+            (self.callback)(&vertex, self.parent.ctx());
         }
         next
     }
 }
 
+impl<'graph, Parent, Callback> ProfileVertexWalker<'graph> for VertexProbe<'graph, Parent, Callback>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Callback: FnMut(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context),
+{
+    type Profiled = Counted<VertexProbe<'graph, Parent::Profiled, Callback>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexProbe::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback> RebindVertexWalker<'graph> for VertexProbe<'graph, Parent, Callback>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Callback: FnMut(&<Parent::Graph as Graph>::VertexReference<'_>, &Parent::Context),
+{
+    type Rebound<'read>
+        = VertexProbe<'read, Parent::Rebound<'read>, Callback>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexProbe::new(self.parent.rebind(), self.callback)
+    }
+}
+
 pub struct EdgeProbe<'graph, Parent, Callback> {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
@@ -93,6 +137,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("probe"));
+    }
 }
 
 impl<'graph, Parent, Callback> EdgeWalker<'graph> for EdgeProbe<'graph, Parent, Callback>
@@ -110,6 +159,41 @@ where
     }
 }
 
+impl<'graph, Parent, Callback> ProfileEdgeWalker<'graph> for EdgeProbe<'graph, Parent, Callback>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Callback: FnMut(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context),
+{
+    type Profiled = Counted<EdgeProbe<'graph, Parent::Profiled, Callback>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeProbe::new(
+            self.parent.profiled(profiler),
+            self.callback,
+        ))
+    }
+}
+
+impl<'graph, Parent, Callback> RebindEdgeWalker<'graph> for EdgeProbe<'graph, Parent, Callback>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Callback: FnMut(&<Parent::Graph as Graph>::EdgeReference<'_>, &Parent::Context),
+{
+    type Rebound<'read>
+        = EdgeProbe<'read, Parent::Rebound<'read>, Callback>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeProbe::new(self.parent.rebind(), self.callback)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
@@ -0,0 +1,86 @@
+use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{Profile, Profiler};
+use crate::walker::{EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, VertexWalker};
+use include_doc::function_body;
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: ProfileVertexWalker<'graph, Graph = Graph>,
+{
+    /// # Profile Step
+    ///
+    /// The `profile` step runs the traversal to completion and measures each step, reporting the
+    /// number of elements that went in and out of the step, the time spent in it, and how the
+    /// `vertices` and `edges` steps found their elements.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Traversal being profiled (all elements are visited):
+    /// ```text
+    ///   [A]* --- edge1 ---> [B]* --- edge2 ---> [C]*
+    ///
+    ///   Result:
+    ///   0: vertices (scan) in: 0 out: 3 elapsed: 2µs
+    ///   1: filter in: 3 out: 1 elapsed: 1µs
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// None
+    ///
+    /// ## Return Value
+    ///
+    /// A `Profile` with a `StepProfile` for each step in the order that it was added. It
+    /// implements `Display` for printing.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/profile.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The `profile` step consumes the entire traversal and discards the elements
+    /// - Elapsed time excludes the steps before, so the slowest step stands out
+    /// - Timing every step adds overhead, use `profile` to compare steps rather than to measure a traversal
+    /// - Steps inside a `detour` are measured as part of the `detour` step
+    /// - Each step is wrapped in a counting adapter only while it is profiled, traversals that aren't profiled are not slowed down
+    /// - A walker added with `with_vertex_walker` must implement `ProfileVertexWalker` to be profiled
+    pub fn profile(mut self) -> Profile
+    where
+        'graph: 'graph,
+    {
+        let graph = self.graph();
+        let profiler = Profiler::default();
+        let mut walker = self.walker().profiled(&profiler);
+        while walker.next(graph).is_some() {}
+        profiler.finish()
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: ProfileEdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Profile Step
+    ///
+    /// The `profile` step runs an edge traversal to completion and measures each step.
+    ///
+    /// See the documentation for [`VertexWalkerBuilder::profile`] for more details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/profile.rs", edge_example, [])]
+    /// ```
+    pub fn profile(mut self) -> Profile {
+        let graph = self.graph();
+        let profiler = Profiler::default();
+        let mut walker = self.walker().profiled(&profiler);
+        while walker.next(graph).is_some() {}
+        profiler.finish()
+    }
+}
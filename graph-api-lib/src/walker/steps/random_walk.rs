@@ -0,0 +1,445 @@
+use crate::graph::{EdgeReference, Graph};
+use crate::rng::SeededRng;
+use crate::walker::builder::VertexWalkerBuilder;
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{ProfileVertexWalker, RebindVertexWalker, VertexWalker, Walker};
+use crate::{EdgeSearch, Element, ElementId};
+use include_doc::function_body;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+// ================ RANDOM WALK IMPLEMENTATION ================
+
+/// Configuration for the `random_walk` and `weighted_random_walk` steps.
+///
+/// By default walks are unbiased and seeded with zero. Setting the return or in-out
+/// parameter to something other than `1.0` biases the walk in the style of node2vec.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomWalk {
+    length: usize,
+    seed: u64,
+    return_parameter: f64,
+    in_out_parameter: f64,
+}
+
+impl RandomWalk {
+    /// Creates a walk of at most `length` steps from each vertex.
+    pub fn new(length: usize) -> Self {
+        RandomWalk {
+            length,
+            seed: 0,
+            return_parameter: 1.0,
+            in_out_parameter: 1.0,
+        }
+    }
+
+    /// Sets the seed, the same seed always produces the same walks over the same graph.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the node2vec return parameter `p`.
+    ///
+    /// Values above `1.0` make the walk less likely to step straight back to the vertex it
+    /// just came from, values below `1.0` make it more likely.
+    pub fn return_parameter(mut self, p: f64) -> Self {
+        self.return_parameter = p;
+        self
+    }
+
+    /// Sets the node2vec in-out parameter `q`.
+    ///
+    /// Values above `1.0` keep the walk close to where it came from (breadth first), values
+    /// below `1.0` push it further away (depth first).
+    pub fn in_out_parameter(mut self, q: f64) -> Self {
+        self.in_out_parameter = q;
+        self
+    }
+
+    fn biased(&self) -> bool {
+        self.return_parameter != 1.0 || self.in_out_parameter != 1.0
+    }
+}
+
+/// Assigns a relative weight to each edge that a random walk may follow.
+///
+/// This is implemented for closures of the form `Fn(&EdgeReference) -> f64`.
+pub trait EdgeWeight<Graph>
+where
+    Graph: crate::Graph,
+{
+    /// Returns the weight of the edge. Edges with a weight of zero or less are never followed.
+    fn weigh(&self, edge: &Graph::EdgeReference<'_>) -> f64;
+}
+
+/// Gives every edge the same weight.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uniform;
+
+impl<Graph> EdgeWeight<Graph> for Uniform
+where
+    Graph: crate::Graph,
+{
+    fn weigh(&self, _edge: &Graph::EdgeReference<'_>) -> f64 {
+        1.0
+    }
+}
+
+impl<Graph, Weight> EdgeWeight<Graph> for Weight
+where
+    Graph: crate::Graph,
+    Weight: Fn(&Graph::EdgeReference<'_>) -> f64,
+{
+    fn weigh(&self, edge: &Graph::EdgeReference<'_>) -> f64 {
+        self(edge)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Walk<VertexId> {
+    previous: Option<VertexId>,
+    current: VertexId,
+    remaining: usize,
+}
+
+pub struct VertexRandomWalk<'search, 'graph, Parent, Weight>
+where
+    Parent: VertexWalker<'graph>,
+{
+    _phantom_data: PhantomData<&'graph ()>,
+    parent: Parent,
+    search: EdgeSearch<'search, Parent::Graph>,
+    config: RandomWalk,
+    weight: Weight,
+    rng: SeededRng,
+    walk: Option<Walk<<Parent::Graph as Graph>::VertexId>>,
+    candidates: Vec<(<Parent::Graph as Graph>::VertexId, f64)>,
+    previous_neighbours: HashSet<<Parent::Graph as Graph>::VertexId>,
+}
+
+impl<'search, 'graph, Parent, Weight> VertexRandomWalk<'search, 'graph, Parent, Weight>
+where
+    Parent: VertexWalker<'graph>,
+    Weight: EdgeWeight<Parent::Graph>,
+{
+    pub(crate) fn new(
+        parent: Parent,
+        search: EdgeSearch<'search, Parent::Graph>,
+        config: RandomWalk,
+        weight: Weight,
+    ) -> Self {
+        Self {
+            _phantom_data: Default::default(),
+            parent,
+            search,
+            rng: SeededRng::new(config.seed),
+            config,
+            weight,
+            walk: None,
+            candidates: Vec::new(),
+            previous_neighbours: HashSet::new(),
+        }
+    }
+
+    /// Whether the walk may follow `edge`. Graphs can return edges that don't match the search,
+    /// so the label is checked again.
+    fn follows(&self, edge: &<Parent::Graph as Graph>::EdgeReference<'_>) -> bool {
+        self.search
+            .label
+            .is_none_or(|label| edge.weight().label() == label)
+    }
+
+    /// Picks the next vertex of the walk, or `None` if the walk is at a dead end.
+    fn choose(
+        &mut self,
+        graph: &'graph Parent::Graph,
+        walk: &Walk<<Parent::Graph as Graph>::VertexId>,
+    ) -> Option<<Parent::Graph as Graph>::VertexId> {
+        self.candidates.clear();
+        for edge in graph.edges(walk.current, &self.search) {
+            if !self.follows(&edge) {
+                continue;
+            }
+            let weight = self.weight.weigh(&edge);
+            if weight > 0.0 {
+                self.candidates
+                    .push((other_end::<Parent::Graph>(&edge, walk.current), weight));
+            }
+        }
+
+        // node2vec: bias each candidate by its distance from the previous vertex
+        if let Some(previous) = walk.previous.filter(|_| self.config.biased()) {
+            self.previous_neighbours.clear();
+            for edge in graph.edges(previous, &self.search) {
+                if self.follows(&edge) {
+                    self.previous_neighbours
+                        .insert(other_end::<Parent::Graph>(&edge, previous));
+                }
+            }
+            for (candidate, weight) in &mut self.candidates {
+                if *candidate == previous {
+                    *weight /= self.config.return_parameter;
+                } else if !self.previous_neighbours.contains(candidate) {
+                    *weight /= self.config.in_out_parameter;
+                }
+            }
+        }
+
+        let total: f64 = self.candidates.iter().map(|(_, weight)| weight).sum();
+        let mut target = self.rng.next_f64() * total;
+        for (candidate, weight) in &self.candidates {
+            if target < *weight {
+                return Some(*candidate);
+            }
+            target -= weight;
+        }
+        // Guards against rounding error in the running total
+        self.candidates.last().map(|(candidate, _)| *candidate)
+    }
+}
+
+fn other_end<Graph>(edge: &Graph::EdgeReference<'_>, vertex: Graph::VertexId) -> Graph::VertexId
+where
+    Graph: crate::Graph,
+{
+    if edge.tail() == vertex {
+        edge.head()
+    } else {
+        edge.tail()
+    }
+}
+
+impl<'graph, Parent, Weight> Walker<'graph> for VertexRandomWalk<'_, 'graph, Parent, Weight>
+where
+    Parent: VertexWalker<'graph>,
+    Weight: EdgeWeight<Parent::Graph>,
+{
+    type Graph = Parent::Graph;
+
+    type Context = Parent::Context;
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Vertex)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        self.parent.ctx()
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        self.parent.ctx_mut()
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::edges("random_walk", &self.search));
+    }
+}
+
+impl<'graph, Parent, Weight> VertexWalker<'graph> for VertexRandomWalk<'_, 'graph, Parent, Weight>
+where
+    Parent: VertexWalker<'graph>,
+    Weight: EdgeWeight<Parent::Graph>,
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
+        loop {
+            if let Some(mut walk) = self.walk.take() {
+                let next = match walk.remaining {
+                    0 => None,
+                    _ => self.choose(graph, &walk),
+                };
+                if let Some(next) = next {
+                    walk.previous = Some(walk.current);
+                    walk.current = next;
+                    walk.remaining -= 1;
+                    self.walk = Some(walk);
+                    return Some(next);
+                }
+            }
+            let start = self.parent.next(graph)?;
+            self.walk = Some(Walk {
+                previous: None,
+                current: start,
+                remaining: self.config.length,
+            });
+        }
+    }
+}
+
+impl<'search, 'graph, Parent, Weight> ProfileVertexWalker<'graph>
+    for VertexRandomWalk<'search, 'graph, Parent, Weight>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Weight: EdgeWeight<Parent::Graph>,
+{
+    type Profiled = Counted<VertexRandomWalk<'search, 'graph, Parent::Profiled, Weight>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexRandomWalk::new(
+            self.parent.profiled(profiler),
+            self.search,
+            self.config,
+            self.weight,
+        ))
+    }
+}
+
+impl<'search, 'graph, Parent, Weight> RebindVertexWalker<'graph>
+    for VertexRandomWalk<'search, 'graph, Parent, Weight>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Weight: EdgeWeight<Parent::Graph>,
+{
+    type Rebound<'read>
+        = VertexRandomWalk<'search, 'read, Parent::Rebound<'read>, Weight>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexRandomWalk::new(self.parent.rebind(), self.search, self.config, self.weight)
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+{
+    /// # Random Walk Step
+    ///
+    /// The `random_walk` step takes a seeded random walk from each vertex in the traversal,
+    /// emitting every vertex that the walk visits. At each step the walk follows one of the
+    /// edges matching the search, chosen uniformly at random.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before random_walk step (traversal position on A):
+    /// ```text
+    ///   [A]* --- knows ---> [B] --- knows ---> [C]
+    ///    |
+    ///   knows
+    ///    |
+    ///    v
+    ///   [D]
+    /// ```
+    ///
+    /// After random_walk(3) step (one possible walk, A -> B -> C):
+    /// ```text
+    ///   [A] --- knows ---> [B]* --- knows ---> [C]*
+    ///    |
+    ///   knows
+    ///    |
+    ///    v
+    ///   [D]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `search`: An `EdgeSearch` that defines which edges the walk may follow, including label and direction
+    /// - `walk`: A `RandomWalk` specifying the length of the walk, the seed and the node2vec parameters
+    ///
+    /// ## Return Value
+    ///
+    /// A new walker where the traversal position is on each vertex visited by the walks, in order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/random_walk.rs", example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The starting vertex is not emitted, only the vertices stepped to
+    /// - A walk ends early if it reaches a vertex with no matching edges
+    /// - Vertices may be emitted many times, a walk can revisit vertices
+    /// - The context of each walk is the context of the vertex it started from
+    /// - The same seed over the same graph always produces the same walks
+    /// - Use `RandomWalk::return_parameter` and `RandomWalk::in_out_parameter` for node2vec-style walks
+    /// - Use `weighted_random_walk` to prefer some edges over others
+    pub fn random_walk<'search, T: Into<EdgeSearch<'search, Graph>>>(
+        self,
+        search: T,
+        walk: RandomWalk,
+    ) -> VertexWalkerBuilder<
+        'graph,
+        Mutability,
+        Graph,
+        VertexRandomWalk<'search, 'graph, Walker, Uniform>,
+    > {
+        self.with_vertex_walker(|walker| {
+            VertexRandomWalk::new(walker, search.into(), walk, Uniform)
+        })
+    }
+
+    /// # Weighted Random Walk Step
+    ///
+    /// The `weighted_random_walk` step is a `random_walk` where the chance of following each
+    /// edge is proportional to its weight.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before weighted_random_walk step (traversal position on A):
+    /// ```text
+    ///   [A]* --- weight 9 ---> [B]
+    ///    |
+    ///   weight 1
+    ///    |
+    ///    v
+    ///   [C]
+    /// ```
+    ///
+    /// After weighted_random_walk(1) step (B is chosen nine times out of ten):
+    /// ```text
+    ///   [A] --- weight 9 ---> [B]*
+    ///    |
+    ///   weight 1
+    ///    |
+    ///    v
+    ///   [C]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `search`: An `EdgeSearch` that defines which edges the walk may follow, including label and direction
+    /// - `walk`: A `RandomWalk` specifying the length of the walk, the seed and the node2vec parameters
+    /// - `weight`: A function that takes an edge reference and returns its weight
+    ///
+    /// ## Return Value
+    ///
+    /// A new walker where the traversal position is on each vertex visited by the walks, in order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/random_walk.rs", weighted_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - Edges with a weight of zero or less are never followed
+    /// - When node2vec parameters are set they are applied on top of the edge weights
+    /// - All other behaviour is the same as `random_walk`
+    pub fn weighted_random_walk<'search, T, Weight>(
+        self,
+        search: T,
+        walk: RandomWalk,
+        weight: Weight,
+    ) -> VertexWalkerBuilder<
+        'graph,
+        Mutability,
+        Graph,
+        VertexRandomWalk<'search, 'graph, Walker, Weight>,
+    >
+    where
+        T: Into<EdgeSearch<'search, Graph>>,
+        Weight: Fn(&Graph::EdgeReference<'_>) -> f64,
+    {
+        self.with_vertex_walker(|walker| VertexRandomWalk::new(walker, search.into(), walk, weight))
+    }
+}
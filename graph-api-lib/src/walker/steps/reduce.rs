@@ -3,9 +3,30 @@ use crate::graph::{
     EdgeReference as GraphEdgeReference, Graph, VertexReference as GraphVertexReference,
 };
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
+
+/// A `reduce` function for element references of type `Reference`.
+///
+/// This is implemented for closures of the form
+/// `Fn(&Reference, &Reference, &Context) -> &Reference`. It lets the reducer be required for
+/// references borrowing the graph for any lifetime, so that `reduce` steps can be rebound before
+/// the graph is mutated.
+pub trait ElementReducer<Reference, Context>:
+    for<'a> Fn(&'a Reference, &'a Reference, &Context) -> &'a Reference
+{
+}
+
+impl<Reference, Context, Reducer> ElementReducer<Reference, Context> for Reducer where
+    Reducer: for<'a> Fn(&'a Reference, &'a Reference, &Context) -> &'a Reference
+{
+}
+
 // ================ REDUCE IMPLEMENTATION ================
 
 pub struct VertexReduce<'graph, Parent, Reducer>
@@ -34,11 +55,7 @@ impl<'graph, Parent, Reducer> Walker<'graph> for VertexReduce<'graph, Parent, Re
 where
     Parent: VertexWalker<'graph>,
     Parent::Graph: 'graph,
-    Reducer: for<'a> Fn(
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &Parent::Context,
-    ) -> &'a <Parent::Graph as Graph>::VertexReference<'graph>,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
 {
     type Graph = Parent::Graph;
     type Context = Parent::Context;
@@ -54,17 +71,18 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("reduce"));
+    }
 }
 
 impl<'graph, Parent, Reducer> VertexWalker<'graph> for VertexReduce<'graph, Parent, Reducer>
 where
     Parent: VertexWalker<'graph>,
     Parent::Graph: 'graph,
-    Reducer: for<'a> Fn(
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &'a <Parent::Graph as Graph>::VertexReference<'graph>,
-        &Parent::Context,
-    ) -> &'a <Parent::Graph as Graph>::VertexReference<'graph>,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
     Parent::Context: Clone,
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
@@ -90,6 +108,43 @@ where
     }
 }
 
+impl<'graph, Parent, Reducer> ProfileVertexWalker<'graph> for VertexReduce<'graph, Parent, Reducer>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Parent::Graph: 'graph,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
+{
+    type Profiled = Counted<VertexReduce<'graph, Parent::Profiled, Reducer>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexReduce::new(
+            self.parent.profiled(profiler),
+            self.reducer,
+        ))
+    }
+}
+
+impl<'graph, Parent, Reducer> RebindVertexWalker<'graph> for VertexReduce<'graph, Parent, Reducer>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Parent::Graph: 'graph,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::VertexReference<'r>, Parent::Context>,
+{
+    type Rebound<'read>
+        = VertexReduce<'read, Parent::Rebound<'read>, Reducer>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexReduce::new(self.parent.rebind(), self.reducer)
+    }
+}
+
 pub struct EdgeReduce<'graph, Parent, Reducer>
 where
     Parent: Walker<'graph>,
@@ -116,11 +171,7 @@ impl<'graph, Parent, Reducer> Walker<'graph> for EdgeReduce<'graph, Parent, Redu
 where
     Parent: EdgeWalker<'graph>,
     Parent::Graph: 'graph,
-    Reducer: for<'a> Fn(
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &Parent::Context,
-    ) -> &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
 {
     type Graph = Parent::Graph;
     type Context = Parent::Context;
@@ -136,17 +187,18 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("reduce"));
+    }
 }
 
 impl<'graph, Parent, Reducer> EdgeWalker<'graph> for EdgeReduce<'graph, Parent, Reducer>
 where
     Parent: EdgeWalker<'graph>,
     Parent::Graph: 'graph,
-    Reducer: for<'a> Fn(
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
-        &Parent::Context,
-    ) -> &'a <Parent::Graph as Graph>::EdgeReference<'graph>,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
     Parent::Context: Clone,
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
@@ -173,6 +225,43 @@ where
 
 // ================ BUILDER METHODS ================
 
+impl<'graph, Parent, Reducer> ProfileEdgeWalker<'graph> for EdgeReduce<'graph, Parent, Reducer>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+    Parent::Graph: 'graph,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
+{
+    type Profiled = Counted<EdgeReduce<'graph, Parent::Profiled, Reducer>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeReduce::new(
+            self.parent.profiled(profiler),
+            self.reducer,
+        ))
+    }
+}
+
+impl<'graph, Parent, Reducer> RebindEdgeWalker<'graph> for EdgeReduce<'graph, Parent, Reducer>
+where
+    Parent: RebindEdgeWalker<'graph>,
+    Parent::Graph: 'graph,
+    Reducer: for<'r> ElementReducer<<Parent::Graph as Graph>::EdgeReference<'r>, Parent::Context>,
+{
+    type Rebound<'read>
+        = EdgeReduce<'read, Parent::Rebound<'read>, Reducer>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeReduce::new(self.parent.rebind(), self.reducer)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph + 'graph,
@@ -234,11 +323,7 @@ where
         reducer: Reducer,
     ) -> VertexWalkerBuilder<'graph, Mutability, Graph, VertexReduce<'graph, Walker, Reducer>>
     where
-        Reducer: for<'a> Fn(
-            &'a Graph::VertexReference<'graph>,
-            &'a Graph::VertexReference<'graph>,
-            &Walker::Context,
-        ) -> &'a Graph::VertexReference<'graph>,
+        Reducer: for<'r> ElementReducer<Graph::VertexReference<'r>, Walker::Context>,
     {
         self.with_vertex_walker(|walker| walker.reduce(reducer))
     }
@@ -302,11 +387,7 @@ where
         reducer: Reducer,
     ) -> EdgeWalkerBuilder<'graph, Mutability, Graph, EdgeReduce<'graph, Walker, Reducer>>
     where
-        Reducer: for<'a> Fn(
-            &'a Graph::EdgeReference<'graph>,
-            &'a Graph::EdgeReference<'graph>,
-            &Walker::Context,
-        ) -> &'a Graph::EdgeReference<'graph>,
+        Reducer: for<'r> ElementReducer<Graph::EdgeReference<'r>, Walker::Context>,
     {
         self.with_edge_walker(|walker| walker.reduce(reducer))
     }
@@ -0,0 +1,404 @@
+use crate::ElementId;
+use crate::graph::Graph;
+use crate::rng::SeededRng;
+use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
+use include_doc::function_body;
+use std::marker::PhantomData;
+
+// ================ SAMPLE IMPLEMENTATION ================
+
+/// Reservoir sampling (algorithm R), every element has an equal chance of being kept.
+fn reservoir<Id, Context>(
+    rng: &mut SeededRng,
+    limit: usize,
+    mut next: impl FnMut() -> Option<(Id, Context)>,
+) -> Vec<(Id, Context)> {
+    let mut reservoir = Vec::with_capacity(limit);
+    let mut seen = 0;
+    while let Some(element) = next() {
+        if reservoir.len() < limit {
+            reservoir.push(element);
+        } else {
+            let chosen = rng.below(seen + 1);
+            if chosen < limit {
+                reservoir[chosen] = element;
+            }
+        }
+        seen += 1;
+    }
+    reservoir
+}
+
+pub struct VertexSample<'graph, Parent>
+where
+    Parent: VertexWalker<'graph>,
+{
+    _phantom_data: PhantomData<&'graph ()>,
+    parent: Parent,
+    limit: usize,
+    rng: SeededRng,
+    sampled: Option<std::vec::IntoIter<(<Parent::Graph as Graph>::VertexId, Parent::Context)>>,
+    context: Option<Parent::Context>,
+}
+
+impl<'graph, Parent> VertexSample<'graph, Parent>
+where
+    Parent: VertexWalker<'graph>,
+{
+    pub(crate) fn new(parent: Parent, limit: usize, seed: u64) -> Self {
+        VertexSample {
+            _phantom_data: Default::default(),
+            parent,
+            limit,
+            rng: SeededRng::new(seed),
+            sampled: None,
+            context: None,
+        }
+    }
+}
+
+impl<'graph, Parent> Walker<'graph> for VertexSample<'graph, Parent>
+where
+    Parent: VertexWalker<'graph>,
+{
+    type Graph = Parent::Graph;
+    type Context = Parent::Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Vertex)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        match &self.context {
+            Some(context) => context,
+            None => self.parent.ctx(),
+        }
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        match &mut self.context {
+            Some(context) => context,
+            None => self.parent.ctx_mut(),
+        }
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("sample"));
+    }
+}
+
+impl<'graph, Parent> VertexWalker<'graph> for VertexSample<'graph, Parent>
+where
+    Parent: VertexWalker<'graph>,
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
+        if self.sampled.is_none() {
+            let parent = &mut self.parent;
+            let sampled = reservoir(&mut self.rng, self.limit, || {
+                let id = parent.next(graph)?;
+                Some((id, parent.ctx().clone()))
+            });
+            self.sampled = Some(sampled.into_iter());
+        }
+        let (id, context) = self.sampled.as_mut().and_then(Iterator::next)?;
+        self.context = Some(context);
+        Some(id)
+    }
+}
+
+impl<'graph, Parent> ProfileVertexWalker<'graph> for VertexSample<'graph, Parent>
+where
+    Parent: ProfileVertexWalker<'graph>,
+{
+    type Profiled = Counted<VertexSample<'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexSample {
+            _phantom_data: Default::default(),
+            parent: self.parent.profiled(profiler),
+            limit: self.limit,
+            rng: self.rng,
+            sampled: None,
+            context: None,
+        })
+    }
+}
+
+impl<'graph, Parent> RebindVertexWalker<'graph> for VertexSample<'graph, Parent>
+where
+    Parent: RebindVertexWalker<'graph>,
+{
+    type Rebound<'read>
+        = VertexSample<'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexSample {
+            _phantom_data: Default::default(),
+            parent: self.parent.rebind(),
+            limit: self.limit,
+            rng: self.rng,
+            sampled: None,
+            context: None,
+        }
+    }
+}
+
+pub struct EdgeSample<'graph, Parent>
+where
+    Parent: EdgeWalker<'graph>,
+{
+    _phantom_data: PhantomData<&'graph ()>,
+    parent: Parent,
+    limit: usize,
+    rng: SeededRng,
+    sampled: Option<std::vec::IntoIter<(<Parent::Graph as Graph>::EdgeId, Parent::Context)>>,
+    context: Option<Parent::Context>,
+}
+
+impl<'graph, Parent> EdgeSample<'graph, Parent>
+where
+    Parent: EdgeWalker<'graph>,
+{
+    pub(crate) fn new(parent: Parent, limit: usize, seed: u64) -> Self {
+        EdgeSample {
+            _phantom_data: Default::default(),
+            parent,
+            limit,
+            rng: SeededRng::new(seed),
+            sampled: None,
+            context: None,
+        }
+    }
+}
+
+impl<'graph, Parent> Walker<'graph> for EdgeSample<'graph, Parent>
+where
+    Parent: EdgeWalker<'graph>,
+{
+    type Graph = Parent::Graph;
+    type Context = Parent::Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Edge)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        match &self.context {
+            Some(context) => context,
+            None => self.parent.ctx(),
+        }
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        match &mut self.context {
+            Some(context) => context,
+            None => self.parent.ctx_mut(),
+        }
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("sample"));
+    }
+}
+
+impl<'graph, Parent> EdgeWalker<'graph> for EdgeSample<'graph, Parent>
+where
+    Parent: EdgeWalker<'graph>,
+{
+    fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::EdgeId> {
+        if self.sampled.is_none() {
+            let parent = &mut self.parent;
+            let sampled = reservoir(&mut self.rng, self.limit, || {
+                let id = parent.next(graph)?;
+                Some((id, parent.ctx().clone()))
+            });
+            self.sampled = Some(sampled.into_iter());
+        }
+        let (id, context) = self.sampled.as_mut().and_then(Iterator::next)?;
+        self.context = Some(context);
+        Some(id)
+    }
+}
+
+impl<'graph, Parent> ProfileEdgeWalker<'graph> for EdgeSample<'graph, Parent>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+{
+    type Profiled = Counted<EdgeSample<'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeSample {
+            _phantom_data: Default::default(),
+            parent: self.parent.profiled(profiler),
+            limit: self.limit,
+            rng: self.rng,
+            sampled: None,
+            context: None,
+        })
+    }
+}
+
+impl<'graph, Parent> RebindEdgeWalker<'graph> for EdgeSample<'graph, Parent>
+where
+    Parent: RebindEdgeWalker<'graph>,
+{
+    type Rebound<'read>
+        = EdgeSample<'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeSample {
+            _phantom_data: Default::default(),
+            parent: self.parent.rebind(),
+            limit: self.limit,
+            rng: self.rng,
+            sampled: None,
+            context: None,
+        }
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: VertexWalker<'graph, Graph = Graph>,
+{
+    /// # Sample Step
+    ///
+    /// The `sample` step picks `n` vertices uniformly at random from the traversal using
+    /// reservoir sampling. Every vertex in the traversal has the same chance of being kept.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before sample step (with multiple vertices in traversal):
+    /// ```text
+    ///   [A]* --- edge1 ---> [B]* --- edge2 ---> [C]*
+    ///    ^
+    ///    |
+    ///   edge3
+    ///    |
+    ///   [D]*
+    /// ```
+    ///
+    /// After sample(2) step (two vertices chosen at random remain in traversal):
+    /// ```text
+    ///   [A] --- edge1 ---> [B]* --- edge2 ---> [C]
+    ///    ^
+    ///    |
+    ///   edge3
+    ///    |
+    ///   [D]*
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `n`: The number of vertices to keep
+    /// - `seed`: The seed for the random choice, the same seed always produces the same sample
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a traversal containing at most `n` vertices chosen at random.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/sample.rs", vertex_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The whole of the traversal is consumed before the first vertex is returned
+    /// - Memory use is proportional to `n`, not to the size of the traversal
+    /// - If the traversal contains fewer than `n` vertices, all of them are returned
+    /// - The context of each vertex is preserved
+    /// - Unlike `take`, which vertices are kept does not depend on the order of the traversal
+    pub fn sample(
+        self,
+        n: usize,
+        seed: u64,
+    ) -> VertexWalkerBuilder<'graph, Mutability, Graph, VertexSample<'graph, Walker>> {
+        self.with_vertex_walker(|walker| VertexSample::new(walker, n, seed))
+    }
+}
+
+impl<'graph, Mutability, Graph, Walker> EdgeWalkerBuilder<'graph, Mutability, Graph, Walker>
+where
+    Graph: crate::graph::Graph,
+    Walker: EdgeWalker<'graph, Graph = Graph>,
+{
+    /// # Sample Step
+    ///
+    /// The `sample` step picks `n` edges uniformly at random from the traversal using
+    /// reservoir sampling. This is useful for sampling the neighbourhood of a vertex.
+    ///
+    /// ## Visual Diagram
+    ///
+    /// Before sample step (with multiple edges in traversal):
+    /// ```text
+    ///   [Person A] --- knows* ---> [Person B] --- created* ---> [Project]
+    ///    ^
+    ///    |
+    ///   owns*
+    ///    |
+    ///   [Company]
+    /// ```
+    ///
+    /// After sample(1) step (one edge chosen at random remains in traversal):
+    /// ```text
+    ///   [Person A] --- knows ---> [Person B] --- created* ---> [Project]
+    ///    ^
+    ///    |
+    ///   owns
+    ///    |
+    ///   [Company]
+    /// ```
+    ///
+    /// ## Parameters
+    ///
+    /// - `n`: The number of edges to keep
+    /// - `seed`: The seed for the random choice, the same seed always produces the same sample
+    ///
+    /// ## Return Value
+    ///
+    /// Returns a traversal containing at most `n` edges chosen at random.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    #[doc = function_body!("examples/sample.rs", edge_example, [])]
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// - The whole of the traversal is consumed before the first edge is returned
+    /// - The sample is taken across all edges in the traversal, not per vertex
+    /// - If the traversal contains fewer than `n` edges, all of them are returned
+    /// - The context of each edge is preserved
+    pub fn sample(
+        self,
+        n: usize,
+        seed: u64,
+    ) -> EdgeWalkerBuilder<'graph, Mutability, Graph, EdgeSample<'graph, Walker>> {
+        self.with_edge_walker(|walker| EdgeSample::new(walker, n, seed))
+    }
+}
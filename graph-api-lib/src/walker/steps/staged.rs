@@ -0,0 +1,214 @@
+use crate::ElementId;
+use crate::graph::Graph;
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
+use std::marker::PhantomData;
+
+// ================ STAGED IMPLEMENTATION ================
+
+/// Walks vertices that were read before a step changed the graph, restoring the context that
+/// each one was read with.
+///
+/// Steps such as `mutate_each` and `add_vertex_linked` continue the traversal with this walker.
+/// The steps before them have already run, so `explain` starts from the step that staged the
+/// traversal.
+pub struct StagedVertices<Graph, Context>
+where
+    Graph: crate::graph::Graph,
+{
+    _phantom_data: PhantomData<Graph>,
+    step: &'static str,
+    elements: std::vec::IntoIter<(Graph::VertexId, Context)>,
+    context: Context,
+}
+
+impl<G, Context> StagedVertices<G, Context>
+where
+    G: Graph,
+{
+    pub(crate) fn new(
+        step: &'static str,
+        elements: Vec<(G::VertexId, Context)>,
+        context: Context,
+    ) -> Self {
+        Self {
+            _phantom_data: PhantomData,
+            step,
+            elements: elements.into_iter(),
+            context,
+        }
+    }
+}
+
+impl<'graph, G, Context> Walker<'graph> for StagedVertices<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Graph = G;
+    type Context = Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Vertex)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        &self.context
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        &mut self.context
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        steps.push(StepInfo::new(self.step));
+    }
+}
+
+impl<'graph, G, Context> VertexWalker<'graph> for StagedVertices<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    fn next(&mut self, _graph: &'graph Self::Graph) -> Option<G::VertexId> {
+        let (id, context) = self.elements.next()?;
+        self.context = context;
+        Some(id)
+    }
+}
+
+impl<'graph, G, Context> ProfileVertexWalker<'graph> for StagedVertices<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Profiled = Counted<Self>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(self)
+    }
+}
+
+impl<'graph, G, Context> RebindVertexWalker<'graph> for StagedVertices<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Rebound<'read>
+        = Self
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        self
+    }
+}
+
+/// Walks edges that were read before a step changed the graph, restoring the context that
+/// each one was read with.
+///
+/// See [`StagedVertices`].
+pub struct StagedEdges<Graph, Context>
+where
+    Graph: crate::graph::Graph,
+{
+    _phantom_data: PhantomData<Graph>,
+    step: &'static str,
+    elements: std::vec::IntoIter<(Graph::EdgeId, Context)>,
+    context: Context,
+}
+
+impl<G, Context> StagedEdges<G, Context>
+where
+    G: Graph,
+{
+    pub(crate) fn new(
+        step: &'static str,
+        elements: Vec<(G::EdgeId, Context)>,
+        context: Context,
+    ) -> Self {
+        Self {
+            _phantom_data: PhantomData,
+            step,
+            elements: elements.into_iter(),
+            context,
+        }
+    }
+}
+
+impl<'graph, G, Context> Walker<'graph> for StagedEdges<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Graph = G;
+    type Context = Context;
+
+    fn next_element(&mut self, graph: &'graph Self::Graph) -> Option<ElementId<Self::Graph>> {
+        self.next(graph).map(ElementId::Edge)
+    }
+
+    fn ctx(&self) -> &Self::Context {
+        &self.context
+    }
+
+    fn ctx_mut(&mut self) -> &mut Self::Context {
+        &mut self.context
+    }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        steps.push(StepInfo::new(self.step));
+    }
+}
+
+impl<'graph, G, Context> EdgeWalker<'graph> for StagedEdges<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    fn next(&mut self, _graph: &'graph Self::Graph) -> Option<G::EdgeId> {
+        let (id, context) = self.elements.next()?;
+        self.context = context;
+        Some(id)
+    }
+}
+
+impl<'graph, G, Context> ProfileEdgeWalker<'graph> for StagedEdges<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Profiled = Counted<Self>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(self)
+    }
+}
+
+impl<'graph, G, Context> RebindEdgeWalker<'graph> for StagedEdges<G, Context>
+where
+    G: Graph,
+    Context: Clone + 'static,
+{
+    type Rebound<'read>
+        = Self
+    where
+        G: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        G: 'read,
+        'graph: 'read,
+    {
+        self
+    }
+}
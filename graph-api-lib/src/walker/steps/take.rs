@@ -1,7 +1,11 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{EdgeWalkerBuilder, VertexWalkerBuilder};
-use crate::walker::{EdgeWalker, VertexWalker, Walker};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::{
+    EdgeWalker, ProfileEdgeWalker, ProfileVertexWalker, RebindEdgeWalker, RebindVertexWalker,
+    VertexWalker, Walker,
+};
 use include_doc::function_body;
 use std::marker::PhantomData;
 
@@ -40,6 +44,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("take"));
+    }
 }
 
 impl<'graph, Parent> VertexWalker<'graph> for VertexTake<'graph, Parent>
@@ -56,6 +65,36 @@ where
     }
 }
 
+impl<'graph, Parent> ProfileVertexWalker<'graph> for VertexTake<'graph, Parent>
+where
+    Parent: ProfileVertexWalker<'graph>,
+{
+    type Profiled = Counted<VertexTake<'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexTake::new(self.parent.profiled(profiler), self.limit))
+    }
+}
+
+impl<'graph, Parent> RebindVertexWalker<'graph> for VertexTake<'graph, Parent>
+where
+    Parent: RebindVertexWalker<'graph>,
+{
+    type Rebound<'read>
+        = VertexTake<'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexTake::new(self.parent.rebind(), self.limit)
+    }
+}
+
 pub struct EdgeTake<'graph, Parent> {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
@@ -88,6 +127,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("take"));
+    }
 }
 
 impl<'graph, Parent> EdgeWalker<'graph> for EdgeTake<'graph, Parent>
@@ -104,6 +148,36 @@ where
     }
 }
 
+impl<'graph, Parent> ProfileEdgeWalker<'graph> for EdgeTake<'graph, Parent>
+where
+    Parent: ProfileEdgeWalker<'graph>,
+{
+    type Profiled = Counted<EdgeTake<'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(EdgeTake::new(self.parent.profiled(profiler), self.limit))
+    }
+}
+
+impl<'graph, Parent> RebindEdgeWalker<'graph> for EdgeTake<'graph, Parent>
+where
+    Parent: RebindEdgeWalker<'graph>,
+{
+    type Rebound<'read>
+        = EdgeTake<'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        EdgeTake::new(self.parent.rebind(), self.limit)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
@@ -1,8 +1,10 @@
 use crate::graph::Graph;
 use crate::search::vertex::VertexSearch;
 use crate::walker::builder::{StartWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{Counted, Profiler, StepInfo};
+use crate::walker::stable::Source;
 use crate::walker::steps::Empty;
-use crate::walker::{VertexWalker, Walker};
+use crate::walker::{ProfileVertexWalker, RebindVertexWalker, VertexWalker, Walker};
 use crate::{ElementId, VertexReference};
 use include_doc::function_body;
 use std::marker::PhantomData;
@@ -15,7 +17,12 @@ where
 {
     _phantom_data: PhantomData<&'graph ()>,
     parent: Parent,
-    current_iter: Option<<Parent::Graph as Graph>::VertexIter<'search, 'graph>>,
+    current_iter: Option<
+        Source<
+            <Parent::Graph as Graph>::VertexIter<'search, 'graph>,
+            <Parent::Graph as Graph>::VertexId,
+        >,
+    >,
     vertex_search: VertexSearch<'search, Parent::Graph>,
 }
 
@@ -51,6 +58,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::vertices("vertices", &self.vertex_search));
+    }
 }
 
 impl<'graph, Parent> VertexWalker<'graph> for Vertices<'_, 'graph, Parent>
@@ -60,14 +72,50 @@ where
 {
     fn next(&mut self, graph: &'graph Self::Graph) -> Option<<Self::Graph as Graph>::VertexId> {
         if self.current_iter.is_none() {
-            self.current_iter = Some(graph.vertices(&self.vertex_search));
+            self.current_iter = Some(Source::new(graph.vertices(&self.vertex_search), |vertex| {
+                vertex.id()
+            }));
         }
 
         self.current_iter
             .as_mut()
             .expect("iterator must be populated")
-            .next()
-            .map(|next| next.id())
+            .next(|id| graph.vertex(*id).is_some())
+    }
+}
+
+impl<'search, 'graph, Parent> ProfileVertexWalker<'graph> for Vertices<'search, 'graph, Parent>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Profiled = Counted<Vertices<'search, 'graph, Parent::Profiled>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(Vertices::new(
+            self.parent.profiled(profiler),
+            self.vertex_search,
+        ))
+    }
+}
+
+impl<'search, 'graph, Parent> RebindVertexWalker<'graph> for Vertices<'search, 'graph, Parent>
+where
+    Parent: RebindVertexWalker<'graph>,
+    <Parent as Walker<'graph>>::Graph: 'graph,
+{
+    type Rebound<'read>
+        = Vertices<'search, 'read, Parent::Rebound<'read>>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        Vertices::new(self.parent.rebind(), self.vertex_search)
     }
 }
 
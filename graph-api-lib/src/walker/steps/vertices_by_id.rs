@@ -1,8 +1,9 @@
 use crate::ElementId;
 use crate::graph::Graph;
 use crate::walker::builder::{StartWalkerBuilder, VertexWalkerBuilder};
+use crate::walker::profiler::{Access, Counted, Profiler, StepInfo};
 use crate::walker::steps::Empty;
-use crate::walker::{VertexWalker, Walker};
+use crate::walker::{ProfileVertexWalker, RebindVertexWalker, VertexWalker, Walker};
 use include_doc::function_body;
 use std::marker::PhantomData;
 // ================ VERTEX_ITER IMPLEMENTATION ================
@@ -48,6 +49,11 @@ where
     fn ctx_mut(&mut self) -> &mut Self::Context {
         self.parent.ctx_mut()
     }
+
+    fn explain(&self, steps: &mut Vec<StepInfo>) {
+        self.parent.explain(steps);
+        steps.push(StepInfo::new("vertices_by_id").with_access(Access::VertexIds));
+    }
 }
 
 impl<'graph, Parent, Iter> VertexWalker<'graph> for VertexIter<'graph, Parent, Iter>
@@ -60,6 +66,38 @@ where
     }
 }
 
+impl<'graph, Parent, Iter> ProfileVertexWalker<'graph> for VertexIter<'graph, Parent, Iter>
+where
+    Parent: ProfileVertexWalker<'graph>,
+    Iter: Iterator<Item = <Parent::Graph as Graph>::VertexId>,
+{
+    type Profiled = Counted<VertexIter<'graph, Parent::Profiled, Iter>>;
+
+    fn profiled(self, profiler: &Profiler) -> Self::Profiled {
+        profiler.count(VertexIter::new(self.parent.profiled(profiler), self.start))
+    }
+}
+
+impl<'graph, Parent, Iter> RebindVertexWalker<'graph> for VertexIter<'graph, Parent, Iter>
+where
+    Parent: RebindVertexWalker<'graph>,
+    Iter: Iterator<Item = <Parent::Graph as Graph>::VertexId>,
+{
+    type Rebound<'read>
+        = VertexIter<'read, Parent::Rebound<'read>, Iter>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read;
+
+    fn rebind<'read>(self) -> Self::Rebound<'read>
+    where
+        Parent::Graph: 'read,
+        'graph: 'read,
+    {
+        VertexIter::new(self.parent.rebind(), self.start)
+    }
+}
+
 impl<'graph, Mutability, Graph, Walker> VertexWalkerBuilder<'graph, Mutability, Graph, Walker>
 where
     Graph: crate::graph::Graph,
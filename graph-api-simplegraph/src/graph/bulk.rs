@@ -0,0 +1,264 @@
+use crate::graph::label::Adjacency;
+use crate::{EdgeId, SimpleGraph, VertexId};
+use graph_api_lib::{AddEdgeError, Element, Label};
+
+/// Loads vertices and edges into a new [`SimpleGraph`] in batches.
+///
+/// Adding elements one at a time with `add_vertex` and `add_edge` updates the indexes and
+/// adjacency lists on every insert. The loader instead stores elements as they arrive and
+/// builds the indexes and adjacency lists in one pass when [`BulkLoader::finish`] is called.
+///
+/// Create one with [`SimpleGraph::bulk_load`].
+///
+/// ```rust
+/// use graph_api_lib::Graph;
+/// use graph_api_simplegraph::SimpleGraph;
+/// use graph_api_test::{Edge, Vertex, Project};
+///
+/// let mut loader = SimpleGraph::<Vertex, Edge>::bulk_load();
+/// let projects = loader.add_vertices(vec![
+///     Vertex::Project(Project { name: "GraphApi".to_string() }),
+///     Vertex::Rust,
+/// ]);
+/// loader
+///     .add_edges(vec![(projects[0], projects[1], Edge::Created)])
+///     .expect("vertices must exist");
+/// let graph = loader.finish();
+///
+/// assert_eq!(graph.walk().vertices(Vertex::rust()).count(), 1);
+/// ```
+pub struct BulkLoader<Vertex, Edge>
+where
+    Vertex: Element,
+    Edge: Element,
+{
+    graph: SimpleGraph<Vertex, Edge>,
+    // The adjacencies of every edge added so far, keyed by the vertex that they belong to
+    adjacencies: Vec<(VertexId, Adjacency)>,
+}
+
+impl<Vertex, Edge> SimpleGraph<Vertex, Edge>
+where
+    Vertex: Element,
+    Edge: Element,
+{
+    /// Creates a [`BulkLoader`] for loading a large number of elements into a new graph.
+    pub fn bulk_load() -> BulkLoader<Vertex, Edge> {
+        BulkLoader {
+            graph: SimpleGraph::new(),
+            adjacencies: Vec::new(),
+        }
+    }
+}
+
+impl<Vertex, Edge> BulkLoader<Vertex, Edge>
+where
+    Vertex: Element,
+    Edge: Element,
+{
+    /// Adds a batch of vertices, returning their ids in the same order.
+    ///
+    /// The vertices can't be found by an index search until the graph is finished.
+    pub fn add_vertices<I>(&mut self, vertices: I) -> Vec<VertexId>
+    where
+        I: IntoIterator<Item = Vertex>,
+    {
+        let vertices: Vec<_> = vertices.into_iter().collect();
+        let mut counts = vec![0; self.graph.vertices.len()];
+        for vertex in &vertices {
+            counts[vertex.label().ordinal()] += 1;
+        }
+        for (labelled_vertices, count) in self.graph.vertices.iter_mut().zip(counts) {
+            labelled_vertices.reserve(count);
+        }
+
+        vertices
+            .into_iter()
+            .map(|vertex| {
+                let label_idx = vertex.label().ordinal();
+                let vertex_idx = self.graph.vertices[label_idx].add_unindexed(vertex);
+                VertexId::new(label_idx as u16, vertex_idx)
+            })
+            .collect()
+    }
+
+    /// Adds a batch of edges, each given as its tail, head and weight. Returns the edge ids in
+    /// the same order.
+    ///
+    /// The edges can't be traversed until the graph is finished.
+    ///
+    /// Fails with [`AddEdgeError::MissingVertex`] if the tail or head of any edge hasn't been
+    /// added, in which case none of the batch is added.
+    pub fn add_edges<I>(&mut self, edges: I) -> Result<Vec<EdgeId>, AddEdgeError>
+    where
+        I: IntoIterator<Item = (VertexId, VertexId, Edge)>,
+    {
+        let edges: Vec<_> = edges.into_iter().collect();
+        let exists = |id: VertexId| {
+            self.graph
+                .vertices
+                .get(id.label() as usize)
+                .and_then(|labelled_vertices| labelled_vertices.get(id.vertex()))
+                .is_some()
+        };
+        if !edges
+            .iter()
+            .all(|(from, to, _)| exists(*from) && exists(*to))
+        {
+            return Err(AddEdgeError::MissingVertex);
+        }
+
+        let mut counts = vec![0; self.graph.edges.len()];
+        for (_, _, edge) in &edges {
+            counts[edge.label().ordinal()] += 1;
+        }
+        for (labelled_edges, count) in self.graph.edges.iter_mut().zip(counts) {
+            labelled_edges.reserve(count);
+        }
+        self.adjacencies.reserve(edges.len() * 2);
+
+        Ok(edges
+            .into_iter()
+            .map(|(from, to, edge)| {
+                let label_idx = edge.label().ordinal();
+                let edge_idx = self.graph.edges[label_idx].add(edge);
+                let edge_id = EdgeId::new(label_idx as u16, edge_idx, from, to);
+                self.adjacencies.push((from, Adjacency::outgoing(&edge_id)));
+                self.adjacencies.push((to, Adjacency::incoming(&edge_id)));
+                edge_id
+            })
+            .collect())
+    }
+
+    /// Builds the adjacency lists and indexes, returning the loaded graph.
+    pub fn finish(mut self) -> SimpleGraph<Vertex, Edge> {
+        let mut graph = self.graph;
+
+        // Sorting groups the adjacencies of each vertex together, in the order that the
+        // adjacency list keeps them.
+        self.adjacencies
+            .sort_unstable_by(|(a, a_adjacency), (b, b_adjacency)| {
+                (a.label(), a.vertex(), a_adjacency).cmp(&(b.label(), b.vertex(), b_adjacency))
+            });
+        for group in self.adjacencies.chunk_by(|(a, _), (b, _)| a == b) {
+            let vertex = group[0].0;
+            graph.vertices[vertex.label() as usize].extend_adjacency(
+                vertex.vertex(),
+                group.iter().map(|(_, adjacency)| adjacency.clone()),
+            );
+        }
+
+        for labelled_vertices in &graph.vertices {
+            for vertex_idx in labelled_vertices.iter() {
+                labelled_vertices.add_to_indexes(vertex_idx, &mut graph.indexes);
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SimpleGraph, VertexId};
+    use graph_api_lib::{AddEdgeError, EdgeSearch, Graph};
+    use graph_api_test::{Edge, Project, Vertex};
+    use uuid::Uuid;
+
+    fn person(name: &str, age: u64) -> Vertex {
+        Vertex::Person {
+            name: name.to_string(),
+            age,
+            unique_id: Uuid::new_v4(),
+            username: name.to_lowercase(),
+            biography: format!("{name} likes graphs"),
+        }
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        let mut loader = SimpleGraph::bulk_load();
+        let people = loader.add_vertices(vec![person("Bryn", 45), person("Julia", 48)]);
+        let projects = loader.add_vertices(vec![Vertex::Project(Project {
+            name: "GraphApi".to_string(),
+        })]);
+        loader
+            .add_edges(vec![
+                (people[0], people[1], Edge::Knows { since: 1999 }),
+                (people[1], people[0], Edge::Knows { since: 1999 }),
+            ])
+            .expect("vertices must exist");
+        loader
+            .add_edges(vec![(people[0], projects[0], Edge::Created)])
+            .expect("vertices must exist");
+        let graph = loader.finish();
+
+        assert_eq!(
+            graph
+                .walk()
+                .vertices(Vertex::person_by_name("Julia"))
+                .collect::<Vec<_>>(),
+            vec![people[1]]
+        );
+        assert_eq!(
+            graph
+                .walk()
+                .vertices(Vertex::person_by_age_range(40..46))
+                .collect::<Vec<_>>(),
+            vec![people[0]]
+        );
+        assert_eq!(
+            graph
+                .walk()
+                .vertices(Vertex::person_by_biography("likes"))
+                .count(),
+            2
+        );
+        assert_eq!(graph.edges(people[0], &EdgeSearch::scan()).count(), 3);
+        assert_eq!(
+            graph
+                .walk()
+                .vertices_by_id(vec![people[0]])
+                .edges(EdgeSearch::scan().outgoing())
+                .head()
+                .collect::<Vec<_>>(),
+            vec![people[1], projects[0]]
+        );
+    }
+
+    #[test]
+    fn test_bulk_load_missing_vertex() {
+        let mut loader = SimpleGraph::bulk_load();
+        let people = loader.add_vertices(vec![person("Bryn", 45)]);
+        let missing = VertexId::new(people[0].label(), people[0].vertex() + 1);
+        assert_eq!(
+            loader.add_edges(vec![
+                (people[0], people[0], Edge::Knows { since: 1999 }),
+                (people[0], missing, Edge::Knows { since: 1999 }),
+            ]),
+            Err(AddEdgeError::MissingVertex)
+        );
+        let graph = loader.finish();
+
+        assert_eq!(graph.edges(people[0], &EdgeSearch::scan()).count(), 0);
+    }
+
+    #[test]
+    fn test_bulk_load_then_add() {
+        let mut loader = SimpleGraph::bulk_load();
+        let people = loader.add_vertices(vec![person("Bryn", 45)]);
+        let mut graph = loader.finish();
+
+        let julia = graph.add_vertex(person("Julia", 48));
+        graph.add_edge(people[0], julia, Edge::Knows { since: 1999 });
+
+        assert_eq!(
+            graph
+                .walk()
+                .vertices(Vertex::person_by_name("Bryn"))
+                .edges(EdgeSearch::scan().outgoing())
+                .head()
+                .collect::<Vec<_>>(),
+            vec![julia]
+        );
+    }
+}
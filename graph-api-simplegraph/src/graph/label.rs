@@ -4,7 +4,7 @@ use crate::{EdgeId, VertexId};
 use graph_api_lib::{Direction, Element, Index, Label};
 use std::collections::BTreeSet;
 use std::marker::PhantomData;
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 
 #[derive(Default)]
 pub(crate) struct LabelledVertices<Vertex, Edge> {
@@ -120,21 +120,34 @@ where
     }
 
     pub(crate) fn add(&mut self, vertex: Vertex, indexes: &mut [VertexIndexStorage]) -> u32 {
-        let label = vertex.label();
-        let vertex_id = self.vertices.push(VertexStorage::new(vertex)) as u32;
+        let vertex_id = self.add_unindexed(vertex);
+        self.add_to_indexes(vertex_id, indexes);
+        vertex_id
+    }
+
+    /// Adds a vertex without adding it to the indexes. `add_to_indexes` must be called for the
+    /// vertex before it can be found by an index search.
+    pub(crate) fn add_unindexed(&mut self, vertex: Vertex) -> u32 {
+        self.vertices.push(VertexStorage::new(vertex)) as u32
+    }
+
+    pub(crate) fn add_to_indexes(&self, vertex_id: u32, indexes: &mut [VertexIndexStorage]) {
         let storage = self
             .vertices
             .get(vertex_id as usize)
-            .expect("we just inserted the vertex, qed");
+            .expect("vertex must exist to be indexed");
         let weight = &storage.weight;
 
-        for index in label.indexes() {
+        for index in weight.label().indexes() {
             if let Some(value) = weight.value(index) {
                 let index_storage = &mut indexes[index.ordinal()];
                 index_storage.insert(value, vertex_id, index);
             }
         }
-        vertex_id
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.vertices.reserve(additional);
     }
 
     pub(crate) fn add_adjacency(&mut self, vertex_id: u32, adjacency: Adjacency) {
@@ -143,6 +156,21 @@ where
         }
     }
 
+    pub(crate) fn extend_adjacency(
+        &mut self,
+        vertex_id: u32,
+        adjacencies: impl IntoIterator<Item = Adjacency>,
+    ) {
+        if let Some(vertex) = self.vertices.get_mut(vertex_id as usize) {
+            if vertex.adjacency_list.is_empty() {
+                // Collecting builds the tree in bulk rather than inserting one at a time
+                vertex.adjacency_list = adjacencies.into_iter().collect();
+            } else {
+                vertex.adjacency_list.extend(adjacencies);
+            }
+        }
+    }
+
     pub(crate) fn remove(
         &mut self,
         vertex_id: u32,
@@ -175,6 +203,14 @@ where
         self.vertices.index_iter().map(|idx| idx as u32)
     }
 
+    pub(crate) fn slots(&self) -> usize {
+        self.vertices.slots()
+    }
+
+    pub(crate) fn iter_range(&self, range: Range<usize>) -> impl Iterator<Item = u32> + '_ {
+        self.vertices.index_range_iter(range).map(|idx| idx as u32)
+    }
+
     pub(crate) fn clear(&mut self) {
         self.vertices.clear();
     }
@@ -214,6 +250,10 @@ where
         self.edges.push(edge) as u32
     }
 
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
     pub(crate) fn remove(&mut self, edge_id: u32) -> Option<Edge> {
         self.edges.remove(edge_id as usize)
     }
@@ -1,3 +1,4 @@
+mod bulk;
 mod debug;
 mod iter;
 mod label;
@@ -7,12 +8,13 @@ use crate::graph::iter::RangeOrNoneIterator;
 use crate::graph::label::{Adjacency, LabelledEdges, LabelledVertices, VertexStorage};
 use crate::id::VertexId;
 use crate::index::VertexIndexStorage;
+pub use bulk::BulkLoader;
 use graph_api_lib::{
     Direction, EdgeSearch, Element, ElementId, Graph, Index, Label, Project, ProjectMut,
     SupportsClear, SupportsEdgeAdjacentLabelIndex, SupportsEdgeHashIndex, SupportsEdgeLabelIndex,
-    SupportsEdgeRangeIndex, SupportsElementRemoval, SupportsVertexFullTextIndex,
-    SupportsVertexHashIndex, SupportsVertexLabelIndex, SupportsVertexRangeIndex, Value,
-    VertexSearch,
+    SupportsEdgeRangeIndex, SupportsElementRemoval, SupportsParallelScan,
+    SupportsVertexFullTextIndex, SupportsVertexHashIndex, SupportsVertexLabelIndex,
+    SupportsVertexRangeIndex, Value, VertexPartition, VertexSearch,
 };
 use smallbox::space::S8;
 use smallbox::{SmallBox, smallbox};
@@ -363,39 +365,39 @@ where
                     return None;
                 }
             }
-            if let Some(iter) = &mut self.current_iter {
-                if let Some(adjacency) = iter.next() {
-                    self.count += 1;
-                    match adjacency.direction {
-                        Direction::Outgoing => {
-                            return Some(EdgeReference {
-                                id: EdgeId::new(
-                                    adjacency.edge_label,
-                                    adjacency.edge_id,
-                                    self.vertex,
-                                    VertexId::new(adjacency.vertex_label, adjacency.vertex_id),
-                                ),
-                                weight: &self.edges[adjacency.edge_label as usize].edges
-                                    [adjacency.edge_id as usize],
-                            });
-                        }
-                        Direction::Incoming => {
-                            return Some(EdgeReference {
-                                id: EdgeId::new(
-                                    adjacency.edge_label,
-                                    adjacency.edge_id,
-                                    VertexId::new(adjacency.vertex_label, adjacency.vertex_id),
-                                    self.vertex,
-                                ),
-                                weight: &self.edges[adjacency.edge_label as usize].edges
-                                    [adjacency.edge_id as usize],
-                            });
-                        }
-                        _ => {
-                            unreachable!()
-                        }
-                    };
-                }
+            if let Some(iter) = &mut self.current_iter
+                && let Some(adjacency) = iter.next()
+            {
+                self.count += 1;
+                match adjacency.direction {
+                    Direction::Outgoing => {
+                        return Some(EdgeReference {
+                            id: EdgeId::new(
+                                adjacency.edge_label,
+                                adjacency.edge_id,
+                                self.vertex,
+                                VertexId::new(adjacency.vertex_label, adjacency.vertex_id),
+                            ),
+                            weight: &self.edges[adjacency.edge_label as usize].edges
+                                [adjacency.edge_id as usize],
+                        });
+                    }
+                    Direction::Incoming => {
+                        return Some(EdgeReference {
+                            id: EdgeId::new(
+                                adjacency.edge_label,
+                                adjacency.edge_id,
+                                VertexId::new(adjacency.vertex_label, adjacency.vertex_id),
+                                self.vertex,
+                            ),
+                            weight: &self.edges[adjacency.edge_label as usize].edges
+                                [adjacency.edge_id as usize],
+                        });
+                    }
+                    _ => {
+                        unreachable!()
+                    }
+                };
             }
             self.current_iter = None;
         }
@@ -749,6 +751,51 @@ where
     }
 }
 
+impl<Vertex, Edge> SupportsParallelScan for SimpleGraph<Vertex, Edge>
+where
+    Vertex: Element + Sync,
+    Edge: Element + Sync,
+{
+    fn vertex_partitions<'graph>(
+        &'graph self,
+        search: &VertexSearch<'_, Self>,
+        partitions: usize,
+    ) -> Vec<VertexPartition<'graph, Self>> {
+        let labels: Vec<usize> = match search {
+            VertexSearch::Scan { limit: None } => (0..self.vertices.len()).collect(),
+            VertexSearch::Label { label, limit: None } => vec![label.ordinal()],
+            _ => {
+                // Index lookups are already narrow and limits must be applied in order, so these stay whole
+                let ids: Vec<VertexId> = self
+                    .vertices(search)
+                    .map(|v| graph_api_lib::VertexReference::id(&v))
+                    .collect();
+                return vec![Box::new(ids.into_iter()) as VertexPartition<'graph, Self>];
+            }
+        };
+
+        // Split each label partition into ranges of slots of roughly equal size
+        let slots: usize = labels
+            .iter()
+            .map(|label| self.vertices[*label].slots())
+            .sum();
+        let size = slots.div_ceil(partitions.max(1)).max(1);
+        let mut result: Vec<VertexPartition<'graph, Self>> = Vec::new();
+        for label in labels {
+            let vertices = &self.vertices[label];
+            for start in (0..vertices.slots()).step_by(size) {
+                let end = (start + size).min(vertices.slots());
+                result.push(Box::new(
+                    vertices
+                        .iter_range(start..end)
+                        .map(move |idx| VertexId::new(label as u16, idx)),
+                ));
+            }
+        }
+        result
+    }
+}
+
 impl<Vertex, Edge> SupportsElementRemoval for SimpleGraph<Vertex, Edge>
 where
     Vertex: Element,
@@ -3,6 +3,7 @@ mod id;
 mod index;
 mod tombstone_vec;
 
+pub use graph::BulkLoader;
 pub use graph::SimpleGraph;
 pub use id::EdgeId;
 pub use id::VertexId;
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 /// A vector implementation that uses tombstone markers for efficient deletions
 ///
 /// Instead of removing elements and shifting the remaining ones, this vector marks deleted elements
@@ -81,6 +83,28 @@ impl<T> TombstoneVec<T> {
         self.len() == 0
     }
 
+    /// Reserves capacity for at least `additional` more elements, taking into account the
+    /// tombstone slots that will be reused first
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.data
+            .reserve(additional.saturating_sub(self.tombstones.len()));
+    }
+
+    /// Returns the number of slots, including tombstones
+    pub(crate) fn slots(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns an iterator over the indices of elements within a range of slots
+    pub(crate) fn index_range_iter(&self, range: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        let start = range.start;
+        self.data[range]
+            .iter()
+            .enumerate()
+            .filter(|(_, data)| data.is_some())
+            .map(move |(offset, _)| start + offset)
+    }
+
     /// Returns an iterator over the valid elements
     pub(crate) fn index_iter(&self) -> TombstoneVecIter<'_, T> {
         TombstoneVecIter {
@@ -176,6 +200,18 @@ mod tests {
         assert_eq!(collected, vec![0, 2]);
     }
 
+    #[test]
+    fn test_reserve() {
+        let mut vec = TombstoneVec::new();
+        vec.push(1);
+        vec.push(2);
+        vec.remove(0);
+        vec.reserve(10);
+        assert!(vec.data.capacity() >= 11);
+        assert_eq!(vec.push(3), 0);
+        assert_eq!(vec.push(4), 2);
+    }
+
     #[test]
     fn test_get_mut() {
         let mut vec = TombstoneVec::new();
@@ -0,0 +1,98 @@
+use crate::{Edge, EdgeLabel, Vertex, VertexLabel, populate_graph};
+use graph_api_lib::algorithms::{
+    PageRank, approximate_betweenness_centrality, approximate_closeness_centrality,
+    betweenness_centrality, closeness_centrality, degree_centrality, page_rank,
+};
+use graph_api_lib::{EdgeSearch, Graph, VertexSearch};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {expected} but got {actual}"
+    );
+}
+
+pub fn test_page_rank<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let ranks = page_rank(
+        graph,
+        &VertexSearch::scan(),
+        &EdgeSearch::scan().outgoing(),
+        PageRank::new().iterations(100),
+    );
+    assert_eq!(ranks.len(), 4);
+    assert_close(ranks.values().sum(), 1.0);
+    assert_close(ranks[&refs.bryn], 0.2824);
+    assert_close(ranks[&refs.julia], 0.2176);
+    assert_close(ranks[&refs.graph_api], 0.2176);
+    assert_close(ranks[&refs.rust], 0.2824);
+}
+
+pub fn test_degree_centrality<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let degrees = degree_centrality(graph, &VertexSearch::scan(), &EdgeSearch::scan());
+    assert_close(degrees[&refs.bryn], 1.0);
+    assert_close(degrees[&refs.julia], 2.0 / 3.0);
+    assert_close(degrees[&refs.graph_api], 2.0 / 3.0);
+    assert_close(degrees[&refs.rust], 1.0 / 3.0);
+
+    let people = VertexSearch::Label {
+        label: VertexLabel::Person,
+        limit: None,
+    };
+    let degrees = degree_centrality(graph, &people, &EdgeSearch::scan().outgoing());
+    assert_eq!(degrees.len(), 2);
+    assert_close(degrees[&refs.bryn], 1.0);
+    assert_close(degrees[&refs.julia], 1.0);
+}
+
+pub fn test_betweenness_centrality<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let search = EdgeSearch::scan().outgoing();
+    let betweenness = betweenness_centrality(graph, &VertexSearch::scan(), &search);
+    assert_close(betweenness[&refs.bryn], 2.0);
+    assert_close(betweenness[&refs.julia], 0.0);
+    assert_close(betweenness[&refs.graph_api], 2.0);
+    assert_close(betweenness[&refs.rust], 0.0);
+
+    // Sampling every vertex gives the exact result
+    let approximate =
+        approximate_betweenness_centrality(graph, &VertexSearch::scan(), &search, 4, 42);
+    assert_eq!(approximate, betweenness);
+
+    let knows = betweenness_centrality(
+        graph,
+        &VertexSearch::scan(),
+        &EdgeSearch::label(EdgeLabel::Knows),
+    );
+    assert_close(knows[&refs.bryn], 0.0);
+}
+
+pub fn test_closeness_centrality<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let search = EdgeSearch::scan().outgoing();
+    let closeness = closeness_centrality(graph, &VertexSearch::scan(), &search);
+    assert_close(closeness[&refs.bryn], 0.75);
+    assert_close(closeness[&refs.julia], 0.5);
+    assert_close(closeness[&refs.graph_api], 1.0 / 3.0);
+    assert_close(closeness[&refs.rust], 0.0);
+
+    // Sampling every vertex gives the exact result
+    let approximate =
+        approximate_closeness_centrality(graph, &VertexSearch::scan(), &search, 4, 42);
+    for (id, score) in closeness {
+        assert_close(approximate[&id], score);
+    }
+}
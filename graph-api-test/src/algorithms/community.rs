@@ -0,0 +1,75 @@
+use crate::{Edge, Vertex, populate_graph};
+use graph_api_lib::algorithms::{
+    global_clustering_coefficient, label_propagation, local_clustering_coefficient, louvain,
+    triangle_count,
+};
+use graph_api_lib::{EdgeSearch, Graph, VertexSearch};
+
+/// Two triangles, optionally joined by a single edge.
+fn triangles<T>(graph: &mut T, bridged: bool) -> Vec<T::VertexId>
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let vertices: Vec<T::VertexId> = (0..6).map(|_| graph.add_vertex(Vertex::Rust)).collect();
+    for (tail, head) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+        graph.add_edge(vertices[tail], vertices[head], Edge::Created);
+    }
+    if bridged {
+        graph.add_edge(vertices[2], vertices[3], Edge::Created);
+    }
+    vertices
+}
+
+pub fn test_label_propagation<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let vertices = triangles(graph, false);
+    let communities = label_propagation(graph, &VertexSearch::scan(), &EdgeSearch::scan(), 7);
+    assert_eq!(communities[&vertices[0]], communities[&vertices[1]]);
+    assert_eq!(communities[&vertices[0]], communities[&vertices[2]]);
+    assert_eq!(communities[&vertices[3]], communities[&vertices[4]]);
+    assert_eq!(communities[&vertices[3]], communities[&vertices[5]]);
+    assert_ne!(communities[&vertices[0]], communities[&vertices[3]]);
+
+    let again = label_propagation(graph, &VertexSearch::scan(), &EdgeSearch::scan(), 7);
+    assert_eq!(communities, again);
+}
+
+pub fn test_louvain<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let vertices = triangles(graph, true);
+    let communities = louvain(graph, &VertexSearch::scan(), &EdgeSearch::scan().outgoing());
+    assert_eq!(communities[&vertices[0]], communities[&vertices[1]]);
+    assert_eq!(communities[&vertices[0]], communities[&vertices[2]]);
+    assert_eq!(communities[&vertices[3]], communities[&vertices[4]]);
+    assert_eq!(communities[&vertices[3]], communities[&vertices[5]]);
+    assert_ne!(communities[&vertices[0]], communities[&vertices[3]]);
+}
+
+pub fn test_clustering<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let triangles = triangle_count(graph, &VertexSearch::scan(), &EdgeSearch::scan());
+    assert!(triangles.values().all(|count| *count == 0));
+
+    graph.add_edge(refs.julia, refs.graph_api, Edge::Created);
+    let triangles = triangle_count(graph, &VertexSearch::scan(), &EdgeSearch::scan());
+    assert_eq!(triangles[&refs.bryn], 1);
+    assert_eq!(triangles[&refs.julia], 1);
+    assert_eq!(triangles[&refs.graph_api], 1);
+    assert_eq!(triangles[&refs.rust], 0);
+
+    let local = local_clustering_coefficient(graph, &VertexSearch::scan(), &EdgeSearch::scan());
+    assert_eq!(local[&refs.bryn], 1.0);
+    assert_eq!(local[&refs.julia], 1.0);
+    assert_eq!(local[&refs.graph_api], 1.0 / 3.0);
+    assert_eq!(local[&refs.rust], 0.0);
+
+    let global = global_clustering_coefficient(graph, &VertexSearch::scan(), &EdgeSearch::scan());
+    assert_eq!(global, 0.6);
+}
@@ -0,0 +1,73 @@
+use crate::{Edge, EdgeLabel, Vertex, assert_elements_eq, populate_graph};
+use graph_api_lib::algorithms::{
+    find_cycle, is_cyclic, strongly_connected_components, topological_sort,
+    weakly_connected_components,
+};
+use graph_api_lib::{EdgeSearch, Graph};
+
+pub fn test_weakly_connected_components<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let components = weakly_connected_components(graph, &EdgeSearch::scan().outgoing());
+    assert_eq!(components.len(), 1);
+    assert_elements_eq!(
+        graph,
+        components[0].clone(),
+        vec![refs.bryn, refs.julia, refs.graph_api, refs.rust]
+    );
+
+    let mut components = weakly_connected_components(graph, &EdgeSearch::label(EdgeLabel::Knows));
+    components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+    assert_eq!(components.len(), 3);
+    assert_elements_eq!(graph, components[0].clone(), vec![refs.bryn, refs.julia]);
+}
+
+pub fn test_strongly_connected_components<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let components = strongly_connected_components(graph, &EdgeSearch::scan().outgoing());
+    assert_eq!(components.len(), 3);
+
+    // Components come back in reverse topological order
+    assert_elements_eq!(graph, components[0].clone(), vec![refs.rust]);
+    assert_elements_eq!(graph, components[1].clone(), vec![refs.graph_api]);
+    assert_elements_eq!(graph, components[2].clone(), vec![refs.bryn, refs.julia]);
+}
+
+pub fn test_cycles<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    assert!(is_cyclic(graph, &EdgeSearch::scan().outgoing()));
+    assert!(!is_cyclic(
+        graph,
+        &EdgeSearch::label(EdgeLabel::Created).outgoing()
+    ));
+
+    let cycle = find_cycle(graph, &EdgeSearch::label(EdgeLabel::Knows).incoming())
+        .expect("knows edges form a cycle");
+    assert_elements_eq!(graph, cycle, vec![refs.bryn, refs.julia]);
+}
+
+pub fn test_topological_sort<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let error = topological_sort(graph, &EdgeSearch::scan().outgoing())
+        .expect_err("knows edges form a cycle");
+    assert_elements_eq!(graph, error.into_cycle(), vec![refs.bryn, refs.julia]);
+
+    graph.add_edge(refs.julia, refs.graph_api, Edge::Created);
+    let order = topological_sort(graph, &EdgeSearch::label(EdgeLabel::Created).outgoing())
+        .expect("created edges are acyclic");
+    assert_eq!(order.len(), 4);
+    let position = |id| order.iter().position(|v| *v == id).expect("vertex sorted");
+    assert!(position(refs.bryn) < position(refs.graph_api));
+    assert!(position(refs.julia) < position(refs.graph_api));
+}
@@ -0,0 +1,3 @@
+pub mod centrality;
+pub mod community;
+pub mod connectivity;
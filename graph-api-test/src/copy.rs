@@ -0,0 +1,82 @@
+use crate::{Edge, Vertex, populate_graph};
+use graph_api_lib::copy::{copy_graph, copy_graph_with};
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference, VertexSearch};
+
+pub fn test_copy_graph<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + Default,
+{
+    let refs = populate_graph(graph);
+    let mut copy = T::default();
+    let ids = copy_graph(graph, &mut copy);
+
+    assert_eq!(ids.vertex_count(), 4);
+    assert_eq!(ids.edge_count(), 4);
+    assert_eq!(copy.vertices(&VertexSearch::scan()).count(), 4);
+    let bryn = ids.vertex(refs.bryn).expect("bryn must be copied");
+    let julia = ids.vertex(refs.julia).expect("julia must be copied");
+    for (source, target) in ids.edges() {
+        let source = graph.edge(source).expect("source edge must exist");
+        let target = copy.edge(target).expect("copied edge must exist");
+        assert_eq!(ids.vertex(source.tail()), Some(target.tail()));
+        assert_eq!(ids.vertex(source.head()), Some(target.head()));
+    }
+    let knows = copy
+        .edge(
+            ids.edge(refs.julia_knows_bryn)
+                .expect("julia knows bryn must be copied"),
+        )
+        .expect("copy must exist");
+    assert_eq!(knows.tail(), julia);
+    assert_eq!(knows.head(), bryn);
+}
+
+pub fn test_copy_graph_with<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + Default,
+{
+    let refs = populate_graph(graph);
+    let mut copy = T::default();
+    let ids = copy_graph_with(
+        graph,
+        &mut copy,
+        |vertex| match vertex {
+            Vertex::Person {
+                name,
+                unique_id,
+                username,
+                biography,
+                ..
+            } => Vertex::Person {
+                name: name.clone(),
+                age: 0,
+                unique_id: *unique_id,
+                username: username.clone(),
+                biography: biography.clone(),
+            },
+            vertex => vertex.clone(),
+        },
+        |edge| match edge {
+            Edge::Knows { .. } => Edge::Knows { since: 2000 },
+            edge => edge.clone(),
+        },
+    );
+
+    let bryn = ids.vertex(refs.bryn).expect("bryn must be copied");
+    assert!(matches!(
+        copy.vertex(bryn).expect("copy must exist").weight(),
+        Vertex::Person { age: 0, .. }
+    ));
+    assert!(
+        copy.edges(bryn, &EdgeSearch::scan())
+            .filter(|edge| matches!(edge.weight(), Edge::Knows { .. }))
+            .all(|edge| matches!(edge.weight(), Edge::Knows { since: 2000 }))
+    );
+    assert!(matches!(
+        graph
+            .edge(refs.bryn_knows_julia)
+            .expect("edge must exist")
+            .weight(),
+        Edge::Knows { since: 1999 }
+    ));
+}
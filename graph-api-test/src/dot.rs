@@ -0,0 +1,97 @@
+use crate::{Edge, EdgeLabel, Vertex, VertexLabel, populate_graph};
+use graph_api_lib::dot::{Dot, Format};
+use graph_api_lib::pattern::Pattern;
+use graph_api_lib::{EdgeSearch, Graph, VertexSearch};
+
+pub fn test_dot_graph<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let dot = Dot::new(graph).to_string();
+
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert_eq!(dot.matches("[label=").count(), 4 + 4);
+    assert!(dot.contains("Person\\nname: Bryn\\nage: 45"));
+    assert!(dot.contains("[label=\"Knows\\nsince: 1999\"]"));
+    assert!(dot.contains("[label=\"Created\"]"));
+    assert!(!dot.contains("fillcolor"));
+}
+
+pub fn test_dot_format<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+
+    let dot = Dot::new(graph).format(Format::Name).to_string();
+    assert!(dot.contains("[label=\"Person\"]"));
+    assert!(!dot.contains("name: Bryn"));
+
+    let dot = Dot::new(graph).format(Format::Debug).to_string();
+    assert!(dot.contains("Person {"));
+    assert!(dot.contains("name: \\\"Bryn\\\""));
+
+    let dot = Dot::new(graph)
+        .vertex_label(|_| "vertex".to_string())
+        .edge_label(|_| "edge".to_string())
+        .to_string();
+    assert_eq!(dot.matches("[label=\"vertex\"]").count(), 4);
+    assert_eq!(dot.matches("[label=\"edge\"]").count(), 4);
+}
+
+pub fn test_dot_walker<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    let dot = graph.walk().vertices_by_id([refs.bryn]).dot().to_string();
+    assert_eq!(dot.matches("fillcolor=gold").count(), 1);
+    assert_eq!(dot.matches(" -> ").count(), 4);
+
+    let dot = graph
+        .walk()
+        .vertices_by_id([refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .dot()
+        .only_highlighted()
+        .to_string();
+    assert_eq!(dot.matches("color=red").count(), 2);
+    assert_eq!(dot.matches(" -> ").count(), 2);
+    assert!(!dot.contains("Rust"));
+    assert!(!dot.contains("fillcolor"));
+}
+
+pub fn test_dot_match<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let mut pattern = Pattern::new();
+    let project = pattern.vertex(VertexSearch::Label {
+        label: VertexLabel::Project,
+        limit: None,
+    });
+    let language = pattern.vertex(VertexSearch::Label {
+        label: VertexLabel::Rust,
+        limit: None,
+    });
+    pattern.edge(
+        project,
+        language,
+        EdgeSearch::label(EdgeLabel::Language).outgoing(),
+    );
+    let matched = pattern.matches(graph).next().expect("graph_api uses rust");
+
+    let dot = Dot::new(graph)
+        .format(Format::Name)
+        .highlight_match(&matched)
+        .only_highlighted()
+        .to_string();
+    assert_eq!(
+        dot,
+        "digraph {\n    v0 [label=\"Project\", style=filled, fillcolor=gold];\n    v1 [label=\"Rust\", style=filled, fillcolor=gold];\n    v0 -> v1 [label=\"Language\", color=red, penwidth=2];\n}\n"
+    );
+}
@@ -1,7 +1,9 @@
 use crate::{Edge, Knows, KnowsMut, Person, PersonMut, Vertex, populate_graph};
 #[cfg(feature = "element-removal")]
 use graph_api_lib::SupportsElementRemoval;
-use graph_api_lib::{EdgeReference, EdgeReferenceMut, VertexReference, VertexReferenceMut};
+use graph_api_lib::{
+    AddEdgeError, EdgeReference, EdgeReferenceMut, VertexReference, VertexReferenceMut,
+};
 use uuid::Uuid;
 
 pub fn test_add_vertex<Graph>(graph: &mut Graph)
@@ -185,3 +187,88 @@ where
     let knows = edge.project::<Knows<_>>().expect("expected person");
     assert_eq!(knows.since(), 2000);
 }
+
+pub fn test_ensure_edge<Graph>(graph: &mut Graph)
+where
+    Graph: graph_api_lib::Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let (edge, created) = graph.ensure_edge(refs.bryn, refs.julia, Edge::Knows { since: 2000 });
+    assert!(!created);
+    assert_eq!(edge, refs.bryn_knows_julia);
+    {
+        let knows = graph.edge(edge).expect("expected edge");
+        let knows = knows.project::<Knows<_>>().expect("expected knows");
+        assert_eq!(knows.since(), 1999);
+    }
+
+    let (edge, created) = graph.ensure_edge(refs.julia, refs.graph_api, Edge::Created);
+    assert!(created);
+    let (again, created) = graph.ensure_edge(refs.julia, refs.graph_api, Edge::Created);
+    assert!(!created);
+    assert_eq!(edge, again);
+}
+
+pub fn test_try_add_edge<Graph>(graph: &mut Graph)
+where
+    Graph: graph_api_lib::Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let created = graph
+        .try_add_edge(refs.julia, refs.graph_api, Edge::Created)
+        .expect("a person can create a project");
+    assert!(graph.edge(created).is_some());
+
+    assert_eq!(
+        graph.try_add_edge(refs.rust, refs.bryn, Edge::Created),
+        Err(AddEdgeError::InvalidEndpoints {
+            edge: "Created",
+            from: "Rust",
+            to: "Person"
+        })
+    );
+    assert_eq!(
+        graph.try_add_edge(refs.bryn, refs.graph_api, Edge::Knows { since: 2000 }),
+        Err(AddEdgeError::InvalidEndpoints {
+            edge: "Knows",
+            from: "Person",
+            to: "Project"
+        })
+    );
+}
+
+pub fn test_typed_add_edge<Graph>(graph: &mut Graph)
+where
+    Graph: graph_api_lib::Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    // Ids are only typed if the vertex has the label
+    assert!(Vertex::person_id(graph, refs.rust).is_none());
+    let julia = Vertex::person_id(graph, refs.julia).expect("julia is a person");
+    let graph_api = Vertex::project_id(graph, refs.graph_api).expect("graph api is a project");
+    let rust = Vertex::rust_id(graph, refs.rust).expect("rust is rust");
+
+    let created = Edge::add_created(graph, julia, graph_api);
+    let knows = Edge::add_knows(graph, julia, julia, 2020);
+    let language = Edge::add_language(
+        graph,
+        graph_api,
+        rust,
+        crate::Language {
+            name: "Rust".to_string(),
+        },
+    );
+
+    let created = graph.edge(created).expect("edge must exist");
+    assert_eq!(created.tail(), refs.julia);
+    assert_eq!(created.head(), refs.graph_api);
+    assert!(matches!(
+        graph.edge(knows).expect("edge must exist").weight(),
+        Edge::Knows { since: 2020 }
+    ));
+    assert_eq!(
+        graph.edge(language).expect("edge must exist").head(),
+        refs.rust
+    );
+}
@@ -1,7 +1,7 @@
 #[cfg(feature = "vertex-hash-index")]
 use crate::{PersonMut, assert_elements_eq, populate_graph};
 #[cfg(feature = "vertex-hash-index")]
-use graph_api_lib::VertexReferenceMut;
+use graph_api_lib::{VertexReference, VertexReferenceMut};
 
 use crate::{Edge, Vertex};
 use graph_api_lib::Graph;
@@ -96,3 +96,64 @@ where
     T: Graph<Vertex = Vertex, Edge = Edge>,
 {
 }
+
+#[cfg(feature = "vertex-hash-index")]
+pub fn test_upsert_vertex<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsVertexHashIndex,
+{
+    let refs = populate_graph(graph);
+    let new_person = |name: &str| Vertex::Person {
+        name: name.to_string(),
+        age: 0,
+        unique_id: Default::default(),
+        username: name.to_lowercase(),
+        biography: "".to_string(),
+    };
+
+    let (bryn, created) = graph.upsert_vertex::<PersonMut<_, _>>(
+        crate::VertexIndex::PersonName,
+        "Bryn",
+        || new_person("Bryn"),
+        |bryn| bryn.set_age(46),
+    );
+    assert!(!created);
+    assert_eq!(bryn, refs.bryn);
+    {
+        let person = graph.vertex(refs.bryn).expect("bryn must exist");
+        let person = person
+            .project::<crate::Person<_>>()
+            .expect("must be a person");
+        assert_eq!(person.age(), 46);
+    }
+
+    let (alice, created) = graph.upsert_vertex::<PersonMut<_, _>>(
+        crate::VertexIndex::PersonName,
+        "Alice",
+        || new_person("Alice"),
+        |_| {},
+    );
+    assert!(created);
+    let (again, created) = graph.upsert_vertex::<PersonMut<_, _>>(
+        crate::VertexIndex::PersonName,
+        "Alice",
+        || new_person("Alice"),
+        |_| {},
+    );
+    assert!(!created);
+    assert_eq!(alice, again);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices(Vertex::person_by_name("Alice"))
+            .count(),
+        1
+    );
+}
+
+#[cfg(not(feature = "vertex-hash-index"))]
+pub fn test_upsert_vertex<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
@@ -1,8 +1,15 @@
 pub extern crate proptest;
 pub use proptest::*;
+pub mod algorithms;
+pub mod copy;
+pub mod dot;
 pub mod fuzz;
 pub mod graph;
 pub mod index;
+pub mod pattern;
+pub mod plan;
+pub mod query;
+pub mod schema;
 pub mod steps;
 
 use graph_api_derive::{EdgeExt, VertexExt};
@@ -43,8 +50,11 @@ pub struct Project {
 
 #[derive(Debug, Clone, EdgeExt)]
 pub enum Edge {
+    #[edge(from = VertexLabel::Person, to = VertexLabel::Person)]
     Knows { since: i32 },
+    #[edge(from = VertexLabel::Person, to = VertexLabel::Project)]
     Created,
+    #[edge(from = VertexLabel::Project, to = VertexLabel::Rust)]
     Language(Language),
 }
 #[derive(Debug, Clone)]
@@ -147,6 +157,45 @@ macro_rules! test_suite {
         $crate::general_test!{$setup, graph_test_mutate_edge, $crate::graph::test_mutate_edge}
         $crate::general_test!{$setup, graph_test_remove_edge, $crate::graph::test_remove_edge}
         $crate::general_test!{$setup, graph_test_remove_vertex_with_edges, $crate::graph::test_remove_vertex_with_edges}
+        $crate::general_test!{$setup, graph_test_ensure_edge, $crate::graph::test_ensure_edge}
+        $crate::general_test!{$setup, graph_test_try_add_edge, $crate::graph::test_try_add_edge}
+        $crate::general_test!{$setup, graph_test_typed_add_edge, $crate::graph::test_typed_add_edge}
+        $crate::general_test!{$setup, algorithms_test_page_rank, $crate::algorithms::centrality::test_page_rank}
+        $crate::general_test!{$setup, algorithms_test_degree_centrality, $crate::algorithms::centrality::test_degree_centrality}
+        $crate::general_test!{$setup, algorithms_test_betweenness_centrality, $crate::algorithms::centrality::test_betweenness_centrality}
+        $crate::general_test!{$setup, algorithms_test_closeness_centrality, $crate::algorithms::centrality::test_closeness_centrality}
+        $crate::general_test!{$setup, algorithms_test_label_propagation, $crate::algorithms::community::test_label_propagation}
+        $crate::general_test!{$setup, algorithms_test_louvain, $crate::algorithms::community::test_louvain}
+        $crate::general_test!{$setup, algorithms_test_clustering, $crate::algorithms::community::test_clustering}
+        $crate::general_test!{$setup, algorithms_test_weakly_connected_components, $crate::algorithms::connectivity::test_weakly_connected_components}
+        $crate::general_test!{$setup, algorithms_test_strongly_connected_components, $crate::algorithms::connectivity::test_strongly_connected_components}
+        $crate::general_test!{$setup, algorithms_test_cycles, $crate::algorithms::connectivity::test_cycles}
+        $crate::general_test!{$setup, algorithms_test_topological_sort, $crate::algorithms::connectivity::test_topological_sort}
+        $crate::general_test!{$setup, pattern_test_path, $crate::pattern::test_pattern_path}
+        $crate::general_test!{$setup, pattern_test_incoming, $crate::pattern::test_pattern_incoming}
+        $crate::general_test!{$setup, pattern_test_isomorphism, $crate::pattern::test_pattern_isomorphism}
+        $crate::general_test!{$setup, pattern_test_cycle, $crate::pattern::test_pattern_cycle}
+        $crate::general_test!{$setup, pattern_test_predicates, $crate::pattern::test_pattern_predicates}
+        $crate::general_test!{$setup, pattern_test_disconnected, $crate::pattern::test_pattern_disconnected}
+        $crate::general_test!{$setup, pattern_test_index_anchor, $crate::pattern::test_pattern_index_anchor}
+        $crate::general_test!{$setup, plan_test_execute, $crate::plan::test_plan_execute}
+        $crate::general_test!{$setup, plan_test_optimize_advice, $crate::plan::test_plan_optimize_advice}
+        $crate::general_test!{$setup, plan_test_optimize_take, $crate::plan::test_plan_optimize_take}
+        $crate::general_test!{$setup, plan_test_optimize_index, $crate::plan::test_plan_optimize_index}
+        $crate::general_test!{$setup, plan_test_optimize_range, $crate::plan::test_plan_optimize_range}
+        $crate::general_test!{$setup, query_test_execute, $crate::query::test_query_execute}
+        $crate::general_test!{$setup, query_test_errors, $crate::query::test_query_errors}
+        $crate::general_test!{$setup, query_test_start, $crate::query::test_query_start}
+        $crate::general_test!{$setup, query_test_serde, $crate::query::test_query_serde}
+        $crate::general_test!{$setup, schema_test_schema, $crate::schema::test_schema}
+        $crate::general_test!{$setup, schema_test_json, $crate::schema::test_schema_json}
+        $crate::general_test!{$setup, schema_test_mermaid, $crate::schema::test_schema_mermaid}
+        $crate::general_test!{$setup, copy_test_copy_graph, $crate::copy::test_copy_graph}
+        $crate::general_test!{$setup, copy_test_copy_graph_with, $crate::copy::test_copy_graph_with}
+        $crate::general_test!{$setup, dot_test_graph, $crate::dot::test_dot_graph}
+        $crate::general_test!{$setup, dot_test_format, $crate::dot::test_dot_format}
+        $crate::general_test!{$setup, dot_test_walker, $crate::dot::test_dot_walker}
+        $crate::general_test!{$setup, dot_test_match, $crate::dot::test_dot_match}
         $crate::general_test!{$setup, boxed_test_simple, $crate::steps::boxed::test_boxed_simple}
         $crate::general_test!{$setup, boxed_test_complex_traversal, $crate::steps::boxed::test_boxed_complex_traversal}
         $crate::general_test!{$setup, boxed_test_ultra_long_traversal, $crate::steps::boxed::test_boxed_ultra_long_traversal}
@@ -158,6 +207,9 @@ macro_rules! test_suite {
         $crate::general_test!{$setup, filter_test_edges_filter, $crate::steps::filter::test_edges_filter}
         $crate::general_test!{$setup, vertices_test_vertices_collect, $crate::steps::collect::test_vertices_collect}
         $crate::general_test!{$setup, vertices_test_edges_collect, $crate::steps::collect::test_edges_collect}
+        $crate::general_test!{$setup, collect_subgraph_test_vertices_collect_subgraph, $crate::steps::collect_subgraph::test_vertices_collect_subgraph}
+        $crate::general_test!{$setup, collect_subgraph_test_vertices_collect_induced_subgraph, $crate::steps::collect_subgraph::test_vertices_collect_induced_subgraph}
+        $crate::general_test!{$setup, collect_subgraph_test_edges_collect_subgraph, $crate::steps::collect_subgraph::test_edges_collect_subgraph}
         $crate::general_test!{$setup, edges_test_out_edges, $crate::steps::edges::test_out_edges}
         $crate::general_test!{$setup, edges_test_out_edges_limit, $crate::steps::edges::test_out_edges_limit}
         $crate::general_test!{$setup, edges_test_in_edges, $crate::steps::edges::test_in_edges}
@@ -176,12 +228,37 @@ macro_rules! test_suite {
         $crate::general_test!{$setup, vertices_test_tail, $crate::steps::vertices::test_tail}
         $crate::general_test!{$setup, mutate_context_vertex, $crate::steps::mutate_context::test_vertex_mutate_context}
         $crate::general_test!{$setup, mutate_context_edge, $crate::steps::mutate_context::test_edge_mutate_context}
+        $crate::general_test!{$setup, mutate_each_vertex, $crate::steps::mutate_each::test_vertex_mutate_each}
+        $crate::general_test!{$setup, mutate_each_edge, $crate::steps::mutate_each::test_edge_mutate_each}
         $crate::general_test!{$setup, mutation_test_mutation, $crate::steps::mutation::test_mutation}
         $crate::general_test!{$setup, mutation_test_edge_mutation, $crate::steps::mutation::test_edge_mutation}
+        $crate::general_test!{$setup, mutation_test_mutation_after_steps, $crate::steps::mutation::test_mutation_after_steps}
+        $crate::general_test!{$setup, mutation_test_edge_mutation_after_steps, $crate::steps::mutation::test_edge_mutation_after_steps}
+        $crate::general_test!{$setup, mutation_test_batched_mutation, $crate::steps::mutation::test_batched_mutation}
+        $crate::general_test!{$setup, mutation_test_batched_mutation_removal, $crate::steps::mutation::test_batched_mutation_removal}
+        $crate::general_test!{$setup, create_test_add_edge_to, $crate::steps::create::test_add_edge_to}
+        $crate::general_test!{$setup, create_test_add_vertex_linked, $crate::steps::create::test_add_vertex_linked}
+        $crate::general_test!{$setup, drop_test_vertex_drop, $crate::steps::drop::test_vertex_drop}
+        $crate::general_test!{$setup, drop_test_edge_drop, $crate::steps::drop::test_edge_drop}
         $crate::general_test!{$setup, count_test_vertices_count, $crate::steps::count::test_vertices_count}
         $crate::general_test!{$setup, count_test_edges_count, $crate::steps::count::test_edges_count}
         $crate::general_test!{$setup, take_test_vertices_take, $crate::steps::take::test_vertices_take}
         $crate::general_test!{$setup, take_test_edges_take, $crate::steps::take::test_edges_take}
+        $crate::general_test!{$setup, random_walk_test_random_walk, $crate::steps::random_walk::test_random_walk}
+        $crate::general_test!{$setup, random_walk_test_random_walk_dead_end, $crate::steps::random_walk::test_random_walk_dead_end}
+        $crate::general_test!{$setup, random_walk_test_random_walk_seed, $crate::steps::random_walk::test_random_walk_seed}
+        $crate::general_test!{$setup, random_walk_test_weighted_random_walk, $crate::steps::random_walk::test_weighted_random_walk}
+        $crate::general_test!{$setup, random_walk_test_node2vec_random_walk, $crate::steps::random_walk::test_node2vec_random_walk}
+        $crate::general_test!{$setup, sample_test_vertices_sample, $crate::steps::sample::test_vertices_sample}
+        $crate::general_test!{$setup, sample_test_vertices_sample_context, $crate::steps::sample::test_vertices_sample_context}
+        $crate::general_test!{$setup, sample_test_edges_sample, $crate::steps::sample::test_edges_sample}
+        $crate::general_test!{$setup, parallel_test_vertex_partitions, $crate::steps::parallel::test_vertex_partitions}
+        $crate::general_test!{$setup, parallel_test_par_vertices, $crate::steps::parallel::test_par_vertices}
+        $crate::general_test!{$setup, profile_test_explain, $crate::steps::profile::test_explain}
+        $crate::general_test!{$setup, profile_test_explain_index, $crate::steps::profile::test_explain_index}
+        $crate::general_test!{$setup, profile_test_profile, $crate::steps::profile::test_profile}
+        $crate::general_test!{$setup, profile_test_profile_detour, $crate::steps::profile::test_profile_detour}
+        $crate::general_test!{$setup, profile_test_profile_boxed, $crate::steps::profile::test_profile_boxed}
         $crate::general_test!{$setup, first_test_vertices_first, $crate::steps::first::test_vertices_first}
         $crate::general_test!{$setup, first_test_edges_first, $crate::steps::first::test_edges_first}
         $crate::general_test!{$setup, fold_test_vertices_fold, $crate::steps::fold::test_vertices_fold}
@@ -202,6 +279,7 @@ macro_rules! test_suite {
         $crate::general_test!{$setup, index_vertex_hash_test_index, $crate::index::vertex_hash::test_index}
         $crate::general_test!{$setup, index_vertex_hash_test_index_remove, $crate::index::vertex_hash::test_index_remove}
         $crate::general_test!{$setup, index_vertex_hash_test_index_update, $crate::index::vertex_hash::test_index_update}
+        $crate::general_test!{$setup, index_vertex_hash_test_index_upsert_vertex, $crate::index::vertex_hash::test_upsert_vertex}
         $crate::general_test!{$setup, index_vertex_full_text_test_index, $crate::index::vertex_full_text::test_index}
         $crate::general_test!{$setup, index_vertex_full_text_test_index_remove, $crate::index::vertex_full_text::test_index_remove}
         $crate::general_test!{$setup, index_vertex_full_text_test_index_update, $crate::index::vertex_full_text::test_index_update}
@@ -0,0 +1,190 @@
+use crate::{Edge, EdgeLabel, Vertex, VertexLabel, populate_graph};
+use graph_api_lib::pattern::{EdgePattern, Pattern, VertexPattern};
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference, VertexSearch};
+
+fn person<'a, T>() -> VertexSearch<'a, T>
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    VertexSearch::Label {
+        label: VertexLabel::Person,
+        limit: None,
+    }
+}
+
+pub fn test_pattern_path<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let mut pattern = Pattern::new();
+    let friend = pattern.vertex(person());
+    let person = pattern.vertex(person());
+    let project = pattern.vertex(VertexSearch::Label {
+        label: VertexLabel::Project,
+        limit: None,
+    });
+    let knows = pattern.edge(
+        person,
+        friend,
+        EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+    );
+    let created = pattern.edge(
+        friend,
+        project,
+        EdgeSearch::label(EdgeLabel::Created).outgoing(),
+    );
+
+    let matches = pattern.matches(graph).collect::<Vec<_>>();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].vertex(person), refs.julia);
+    assert_eq!(matches[0].vertex(friend), refs.bryn);
+    assert_eq!(matches[0].vertex(project), refs.graph_api);
+    assert_eq!(matches[0].edge(knows), refs.julia_knows_bryn);
+    assert_eq!(matches[0].edge(created), refs.bryn_created_graph_api);
+}
+
+pub fn test_pattern_incoming<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    // Project <-Created- Person
+    let mut pattern = Pattern::new();
+    let project = pattern.vertex(VertexSearch::scan());
+    let creator = pattern.vertex(person());
+    pattern.edge(
+        project,
+        creator,
+        EdgeSearch::label(EdgeLabel::Created).incoming(),
+    );
+
+    let matches = pattern.matches(graph).collect::<Vec<_>>();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].vertex(project), refs.graph_api);
+    assert_eq!(matches[0].vertex(creator), refs.bryn);
+}
+
+pub fn test_pattern_isomorphism<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let build = |pattern: &mut Pattern<T>| {
+        let first = pattern.vertex(person());
+        let second = pattern.vertex(person());
+        let third = pattern.vertex(person());
+        pattern.edge(
+            first,
+            second,
+            EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+        );
+        pattern.edge(
+            second,
+            third,
+            EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+        );
+    };
+
+    // Bryn and Julia know each other, so a homomorphism can revisit the first person
+    let mut homomorphism = Pattern::new();
+    build(&mut homomorphism);
+    assert_eq!(homomorphism.matches(graph).count(), 2);
+
+    let mut isomorphism = Pattern::new().isomorphic();
+    build(&mut isomorphism);
+    assert_eq!(isomorphism.matches(graph).count(), 0);
+}
+
+pub fn test_pattern_cycle<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let mut pattern = Pattern::new().isomorphic();
+    let first = pattern.vertex(person());
+    let second = pattern.vertex(person());
+    pattern.edge(
+        first,
+        second,
+        EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+    );
+    pattern.edge(
+        second,
+        first,
+        EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+    );
+
+    let mut pairs = pattern
+        .matches(graph)
+        .map(|m| (m.vertex(first), m.vertex(second)))
+        .collect::<Vec<_>>();
+    pairs.sort_by_key(|(first, _)| *first == refs.julia);
+    assert_eq!(
+        pairs,
+        vec![(refs.bryn, refs.julia), (refs.julia, refs.bryn)]
+    );
+}
+
+pub fn test_pattern_predicates<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let mut pattern = Pattern::new();
+    let older = pattern.vertex(
+        VertexPattern::new(person::<T>())
+            .filter(|vertex| matches!(vertex.weight(), Vertex::Person { age, .. } if *age > 46)),
+    );
+    let younger = pattern.vertex(person());
+    pattern.edge(
+        older,
+        younger,
+        EdgePattern::<T>::new(EdgeSearch::scan().outgoing())
+            .filter(|edge| matches!(edge.weight(), Edge::Knows { since } if *since < 2000)),
+    );
+
+    let matches = pattern.matches(graph).collect::<Vec<_>>();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].vertex(older), refs.julia);
+    assert_eq!(matches[0].vertex(younger), refs.bryn);
+}
+
+pub fn test_pattern_disconnected<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let mut pattern = Pattern::new().isomorphic();
+    pattern.vertex(person());
+    pattern.vertex(VertexSearch::Label {
+        label: VertexLabel::Rust,
+        limit: None,
+    });
+    assert_eq!(pattern.matches(graph).count(), 2);
+    assert_eq!(Pattern::<T>::new().matches(graph).count(), 0);
+}
+
+#[cfg(feature = "vertex-hash-index")]
+pub fn test_pattern_index_anchor<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsVertexHashIndex,
+{
+    let refs = populate_graph(graph);
+    let mut pattern = Pattern::new();
+    let known = pattern.vertex(person());
+    let bryn = pattern.vertex(Vertex::person_by_name("Bryn"));
+    pattern.edge(bryn, known, EdgeSearch::label(EdgeLabel::Knows).outgoing());
+
+    let matches = pattern.matches(graph).collect::<Vec<_>>();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].vertex(bryn), refs.bryn);
+    assert_eq!(matches[0].vertex(known), refs.julia);
+}
+
+#[cfg(not(feature = "vertex-hash-index"))]
+pub fn test_pattern_index_anchor<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
@@ -0,0 +1,215 @@
+use crate::{Edge, EdgeLabel, Vertex, VertexIndex, VertexLabel, populate_graph};
+use graph_api_lib::plan::{Advice, Capabilities, Plan, Predicate, Start};
+use graph_api_lib::{EdgeSearch, ElementId, Graph, IndexType, VertexSearch};
+use std::collections::HashSet;
+
+fn vertex_ids<T>(elements: Vec<ElementId<T>>) -> HashSet<T::VertexId>
+where
+    T: Graph,
+{
+    elements
+        .into_iter()
+        .filter_map(|element| match element {
+            ElementId::Vertex(id) => Some(id),
+            ElementId::Edge(_) => None,
+        })
+        .collect()
+}
+
+pub fn test_plan_execute<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_label(VertexLabel::Person)
+        .edges(EdgeSearch::scan().outgoing())
+        .head()
+        .dedup();
+
+    let result = plan.execute(graph);
+    assert_eq!(result.len(), 3);
+    assert_eq!(
+        vertex_ids(result),
+        HashSet::from([refs.bryn, refs.julia, refs.graph_api])
+    );
+
+    let plan = Plan::vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_edge_label(EdgeLabel::Created)
+        .head()
+        .filter_property(VertexIndex::PersonAge, Predicate::Gt(40.into()));
+    assert!(plan.execute(graph).is_empty());
+
+    let plan = Plan::vertices(VertexSearch::scan()).filter_property(
+        VertexIndex::PersonAge,
+        Predicate::Range(46.into()..50.into()),
+    );
+    assert_eq!(plan.execute(graph), vec![ElementId::Vertex(refs.julia)]);
+}
+
+pub fn test_plan_optimize_advice<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_label(VertexLabel::Person)
+        .filter_property(VertexIndex::PersonName, Predicate::Eq("Bryn".into()));
+
+    // Graphs without the indexes scan instead, so overstating support still gives the same result
+    let optimized = plan.clone().optimize(&Capabilities::all());
+    assert_eq!(optimized.plan.execute(graph), plan.execute(graph));
+
+    // Nothing can be rewritten, but the plan is unchanged and still runs
+    let optimized = plan.optimize(&Capabilities::none());
+    assert!(matches!(
+        optimized.plan.start(),
+        Start::Vertices(VertexSearch::Scan { limit: None })
+    ));
+    assert_eq!(optimized.plan.steps().len(), 2);
+    assert_eq!(
+        optimized.advice,
+        vec![
+            Advice::UnsupportedIndex {
+                label: "Person",
+                property: "name",
+                index_type: IndexType::Hash,
+            },
+            Advice::LabelIndex { label: "Person" },
+        ]
+    );
+    assert_eq!(
+        optimized.plan.execute(graph),
+        vec![ElementId::Vertex(refs.bryn)]
+    );
+
+    // The biography is only full text indexed
+    let optimized = Plan::<T>::vertices(VertexSearch::scan())
+        .filter_property(
+            VertexIndex::PersonBiography,
+            Predicate::Eq("Did some graph stuff".into()),
+        )
+        .optimize(&Capabilities::all());
+    assert_eq!(
+        optimized.advice,
+        vec![Advice::IndexType {
+            label: "Person",
+            property: "biography",
+            needed: IndexType::Hash,
+            actual: IndexType::FullText,
+        }]
+    );
+}
+
+pub fn test_plan_optimize_take<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let plan = Plan::vertices(VertexSearch::scan())
+        .take(1)
+        .edges(EdgeSearch::scan())
+        .take(2);
+    let optimized = plan.clone().optimize(&Capabilities::none());
+
+    assert!(matches!(
+        optimized.plan.start(),
+        Start::Vertices(VertexSearch::Scan { limit: Some(1) })
+    ));
+    let explain = optimized.plan.explain();
+    assert_eq!(explain.steps.len(), 3);
+    assert_eq!(explain.steps[1].limit, Some(2));
+    assert_eq!(
+        optimized.plan.execute(graph).len(),
+        plan.execute(graph).len()
+    );
+
+    // Only vertex searches have a limit to push a take into
+    let optimized = Plan::vertices_by_id(vec![refs.bryn, refs.julia])
+        .take(1)
+        .optimize(&Capabilities::all());
+    assert_eq!(optimized.plan.steps().len(), 1);
+    assert_eq!(
+        optimized.plan.execute(graph),
+        vec![ElementId::Vertex(refs.bryn)]
+    );
+}
+
+#[cfg(feature = "vertex-hash-index")]
+pub fn test_plan_optimize_index<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsVertexHashIndex,
+{
+    let refs = populate_graph(graph);
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_label(VertexLabel::Person)
+        .filter_property(VertexIndex::PersonName, Predicate::Eq("Bryn".into()))
+        .edges(EdgeSearch::label(EdgeLabel::Knows).outgoing())
+        .head();
+    let optimized = plan.clone().optimize(&Capabilities::all());
+
+    assert!(optimized.advice.is_empty());
+    assert!(matches!(
+        optimized.plan.start(),
+        Start::Vertices(VertexSearch::Index {
+            index: VertexIndex::PersonName,
+            ..
+        })
+    ));
+    // The filters stay to check the results of the lookup
+    assert_eq!(optimized.plan.steps().len(), 4);
+    assert_eq!(optimized.plan.execute(graph), plan.execute(graph));
+    assert_eq!(
+        optimized.plan.execute(graph),
+        vec![ElementId::Vertex(refs.julia)]
+    );
+}
+
+#[cfg(not(feature = "vertex-hash-index"))]
+pub fn test_plan_optimize_index<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
+
+#[cfg(feature = "vertex-range-index")]
+pub fn test_plan_optimize_range<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsVertexRangeIndex,
+{
+    let refs = populate_graph(graph);
+    let plan = Plan::vertices(VertexSearch::scan())
+        .filter_property(VertexIndex::PersonAge, Predicate::Le(45i32.into()));
+    let optimized = plan.clone().optimize(&Capabilities::all());
+
+    assert!(optimized.advice.is_empty());
+    assert!(matches!(
+        optimized.plan.start(),
+        Start::Vertices(VertexSearch::Range {
+            index: VertexIndex::PersonAge,
+            ..
+        })
+    ));
+    assert_eq!(
+        optimized.plan.execute(graph),
+        vec![ElementId::Vertex(refs.bryn)]
+    );
+    assert_eq!(optimized.plan.execute(graph), plan.execute(graph));
+
+    // Lower bounds alone can't be expressed as a range search
+    let optimized = Plan::<T>::vertices(VertexSearch::scan())
+        .filter_property(VertexIndex::PersonAge, Predicate::Gt(45.into()))
+        .optimize(&Capabilities::all());
+    assert!(matches!(
+        optimized.plan.start(),
+        Start::Vertices(VertexSearch::Scan { .. })
+    ));
+}
+
+#[cfg(not(feature = "vertex-range-index"))]
+pub fn test_plan_optimize_range<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
@@ -0,0 +1,300 @@
+use crate::{Edge, Vertex, populate_graph};
+use graph_api_lib::plan::Capabilities;
+use graph_api_lib::query::{
+    Comparison, Condition, Operand, Order, Output, Predicate, Query, QueryError, Start, Step,
+    Terminal,
+};
+use graph_api_lib::{Direction, Graph, Value};
+
+fn rows<T>(graph: &T, query: &Query, capabilities: &Capabilities) -> Vec<Vec<String>>
+where
+    T: Graph,
+{
+    let Ok(Output::Rows(rows)) = query.execute(graph, capabilities) else {
+        panic!("expected rows");
+    };
+    let mut result = Vec::new();
+    rows.for_each(|row| {
+        result.push(
+            row.iter()
+                .map(|value| match value {
+                    Some(Value::Str(value)) => value.to_string(),
+                    Some(value) => format!("{:?}", value),
+                    None => "null".to_string(),
+                })
+                .collect(),
+        )
+    });
+    result
+}
+
+fn count<T>(graph: &T, query: &Query) -> usize
+where
+    T: Graph,
+{
+    match query.execute(graph, &Capabilities::none()) {
+        Ok(Output::Count(count)) => count,
+        _ => panic!("expected a count"),
+    }
+}
+
+pub fn test_query_execute<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    let query = Query::new(Start::Label("Person".to_string()))
+        .step(Step::Edges {
+            label: Some("Knows".to_string()),
+            direction: Direction::Outgoing,
+        })
+        .step(Step::Head)
+        .step(Step::Dedup)
+        .terminal(Terminal::Count);
+    assert_eq!(count(graph, &query), 2);
+
+    let query = Query::new(Start::Edges)
+        .step(Step::FilterLabel("Created".to_string()))
+        .step(Step::Tail);
+    assert!(matches!(
+        query.execute(graph, &Capabilities::none()),
+        Ok(Output::Vertices(vertices)) if vertices == vec![refs.bryn]
+    ));
+
+    // Bryn is outside the age filter, and properties that a vertex doesn't have are null
+    let query = Query::new(Start::Scan)
+        .step(Step::Filter {
+            property: "name".to_string(),
+            predicate: Predicate::Exists,
+        })
+        .step(Step::Filter {
+            property: "age".to_string(),
+            predicate: Predicate::Not(Box::new(Predicate::Range {
+                start: 40.into(),
+                end: 46.into(),
+            })),
+        })
+        .terminal(Terminal::Values(vec![
+            "name".to_string(),
+            "unknown".to_string(),
+        ]));
+    assert_eq!(
+        rows(graph, &query, &Capabilities::none()),
+        vec![vec!["Julia".to_string(), "null".to_string()]]
+    );
+
+    let query = Query::new(Start::Scan)
+        .step(Step::Edges {
+            label: None,
+            direction: Direction::Outgoing,
+        })
+        .step(Step::Filter {
+            property: "since".to_string(),
+            predicate: Predicate::Or(vec![Predicate::Eq(1999.into()), Predicate::Gt(2020.into())]),
+        })
+        .step(Step::Take(1))
+        .terminal(Terminal::Count);
+    assert_eq!(count(graph, &query), 1);
+
+    // Named elements along the path, compared with each other and returned as columns
+    let property = |variable: &str, property: &str| Operand::Property {
+        variable: variable.to_string(),
+        property: property.to_string(),
+    };
+    let query = Query::new(Start::Label("Person".to_string()))
+        .step(Step::As("a".to_string()))
+        .step(Step::Edges {
+            label: Some("Knows".to_string()),
+            direction: Direction::Outgoing,
+        })
+        .step(Step::As("k".to_string()))
+        .step(Step::Head)
+        .step(Step::As("b".to_string()))
+        .step(Step::Where(Condition::Compare(
+            property("a", "age"),
+            Comparison::Lt,
+            property("b", "age"),
+        )))
+        .terminal(Terminal::Select {
+            columns: vec![
+                ("a".to_string(), property("a", "name")),
+                ("since".to_string(), property("k", "since")),
+                ("b".to_string(), Operand::Label("b".to_string())),
+            ],
+            order: Vec::new(),
+            skip: 0,
+            limit: None,
+        });
+    assert_eq!(
+        rows(graph, &query, &Capabilities::none()),
+        vec![vec![
+            "Bryn".to_string(),
+            "I32(1999)".to_string(),
+            "Person".to_string()
+        ]]
+    );
+
+    // A name that is used again must be on the same element, so this finds people who know
+    // each other
+    let query = Query::new(Start::Label("Person".to_string()))
+        .step(Step::As("a".to_string()))
+        .step(Step::Edges {
+            label: Some("Knows".to_string()),
+            direction: Direction::Outgoing,
+        })
+        .step(Step::Head)
+        .step(Step::Edges {
+            label: Some("Knows".to_string()),
+            direction: Direction::Outgoing,
+        })
+        .step(Step::Head)
+        .step(Step::As("a".to_string()))
+        .terminal(Terminal::Select {
+            columns: vec![("name".to_string(), property("a", "name"))],
+            order: vec![Order {
+                operand: property("a", "name"),
+                descending: true,
+            }],
+            skip: 1,
+            limit: Some(1),
+        });
+    assert_eq!(
+        rows(graph, &query, &Capabilities::none()),
+        vec![vec!["Bryn".to_string()]]
+    );
+}
+
+pub fn test_query_errors<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let execute = |query: Query| {
+        query
+            .execute(graph, &Capabilities::none())
+            .map(|_| ())
+            .unwrap_err()
+    };
+
+    assert_eq!(
+        execute(Query::new(Start::Label("Robot".to_string()))),
+        QueryError::UnknownLabel("Robot".to_string())
+    );
+    // Edge labels are not vertex labels
+    assert_eq!(
+        execute(Query::new(Start::Scan).step(Step::FilterLabel("Knows".to_string()))),
+        QueryError::UnknownLabel("Knows".to_string())
+    );
+    assert_eq!(
+        execute(Query::new(Start::Index {
+            label: "Person".to_string(),
+            property: "nickname".to_string(),
+            value: "B".into(),
+        })),
+        QueryError::NotIndexed {
+            label: "Person".to_string(),
+            property: "nickname".to_string(),
+        }
+    );
+    assert_eq!(
+        execute(Query::new(Start::Scan).step(Step::Take(1)).step(Step::Head)),
+        QueryError::Misplaced { step: 1 }
+    );
+    assert_eq!(
+        execute(
+            Query::new(Start::Scan)
+                .step(Step::Where(Condition::Is(Operand::Label("a".to_string()))))
+        ),
+        QueryError::UnknownVariable("a".to_string())
+    );
+    // A name given to a vertex can't be given to an edge
+    assert_eq!(
+        execute(
+            Query::new(Start::Scan)
+                .step(Step::As("a".to_string()))
+                .step(Step::Edges {
+                    label: None,
+                    direction: Direction::Outgoing,
+                })
+                .step(Step::As("a".to_string()))
+        ),
+        QueryError::Misplaced { step: 2 }
+    );
+}
+
+pub fn test_query_start<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let index = Query::new(Start::Index {
+        label: "Person".to_string(),
+        property: "name".to_string(),
+        value: "Bryn".into(),
+    })
+    .terminal(Terminal::Values(vec!["username".to_string()]));
+    let range = Query::new(Start::Range {
+        label: "Person".to_string(),
+        property: "age".to_string(),
+        start: 46.into(),
+        end: 50.into(),
+    })
+    .terminal(Terminal::Values(vec!["username".to_string()]));
+
+    // Graphs that can't look the start up scan instead, with the same result
+    for capabilities in [Capabilities::none(), supported()] {
+        assert_eq!(
+            rows(graph, &index, &capabilities),
+            vec![vec!["bryn".to_string()]]
+        );
+        assert_eq!(
+            rows(graph, &range, &capabilities),
+            vec![vec!["julia".to_string()]]
+        );
+    }
+}
+
+/// The capabilities that the graph under test was built with.
+fn supported() -> Capabilities {
+    Capabilities {
+        vertex_label_index: cfg!(feature = "vertex-label-index"),
+        vertex_hash_index: cfg!(feature = "vertex-hash-index"),
+        vertex_range_index: cfg!(feature = "vertex-range-index"),
+    }
+}
+
+#[cfg(feature = "serde")]
+pub fn test_query_serde<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let query = Query::new(Start::Label("Person".to_string()))
+        .step(Step::Filter {
+            property: "age".to_string(),
+            predicate: Predicate::Ge(46.into()),
+        })
+        .step(Step::Edges {
+            label: Some("Knows".to_string()),
+            direction: Direction::Outgoing,
+        })
+        .step(Step::Head)
+        .terminal(Terminal::Values(vec!["name".to_string()]));
+
+    // A stored query replays with the same result
+    let json = serde_json::to_string(&query).expect("query must serialize");
+    let replayed: Query = serde_json::from_str(&json).expect("query must deserialize");
+    assert_eq!(replayed, query);
+    assert_eq!(
+        rows(graph, &replayed, &Capabilities::none()),
+        vec![vec!["Bryn".to_string()]]
+    );
+}
+
+#[cfg(not(feature = "serde"))]
+pub fn test_query_serde<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
@@ -0,0 +1,96 @@
+use crate::{Edge, Vertex, VertexLabel};
+use graph_api_lib::schema::Schema;
+use graph_api_lib::{Endpoints, Graph, IndexType};
+
+pub fn test_schema<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let schema = Schema::of::<T>();
+    assert_eq!(
+        schema
+            .vertices
+            .iter()
+            .map(|label| label.name)
+            .collect::<Vec<_>>(),
+        vec!["Person", "Project", "Rust"]
+    );
+    assert_eq!(
+        schema
+            .edges
+            .iter()
+            .map(|label| label.name)
+            .collect::<Vec<_>>(),
+        vec!["Knows", "Created", "Language"]
+    );
+
+    let person = schema.label("Person").expect("person");
+    assert_eq!(
+        person
+            .fields
+            .iter()
+            .map(|field| (field.name, field.ty, field.index))
+            .collect::<Vec<_>>(),
+        vec![
+            ("name", "String", Some(IndexType::Hash)),
+            ("age", "u64", Some(IndexType::Range)),
+            ("unique_id", "Uuid", Some(IndexType::Hash)),
+            ("username", "String", Some(IndexType::Range)),
+            ("biography", "String", Some(IndexType::FullText)),
+        ]
+    );
+    assert!(person.endpoints.is_empty());
+    assert!(schema.label("Project").expect("project").fields.is_empty());
+
+    let created = schema.label("Created").expect("created");
+    assert_eq!(
+        created.endpoints,
+        &[Endpoints {
+            from: "Person",
+            to: "Project",
+            from_ordinal: VertexLabel::Person as usize,
+            to_ordinal: VertexLabel::Project as usize,
+        }]
+    );
+    assert!(schema.label("Unknown").is_none());
+}
+
+pub fn test_schema_json<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let json = Schema::of::<T>().to_json();
+    assert!(json.starts_with(
+        r#"{"vertices":[{"name":"Person","fields":[{"name":"name","type":"String","index":"hash"},"#
+    ));
+    assert!(json.contains(r#"{"name":"Rust","fields":[],"endpoints":[]}"#));
+    assert!(json.contains(
+        r#"{"name":"Knows","fields":[{"name":"since","type":"i32","index":null}],"endpoints":[{"from":"Person","to":"Person"}]}"#
+    ));
+    assert!(json.ends_with(
+        r#"{"name":"Language","fields":[],"endpoints":[{"from":"Project","to":"Rust"}]}]}"#
+    ));
+}
+
+pub fn test_schema_mermaid<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    assert_eq!(
+        Schema::of::<T>().to_mermaid(),
+        r#"erDiagram
+    Person {
+        String name "hash index"
+        u64 age "range index"
+        Uuid unique_id "hash index"
+        String username "range index"
+        String biography "full text index"
+    }
+    Project
+    Rust
+    Person }o--o{ Person : Knows
+    Person }o--o{ Project : Created
+    Project }o--o{ Rust : Language
+"#
+    );
+}
@@ -0,0 +1,85 @@
+use crate::{Edge, Vertex, populate_graph};
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference, VertexSearch};
+
+pub fn test_vertices_collect_subgraph<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + Default,
+{
+    let refs = populate_graph(graph);
+    let (subgraph, ids) = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn, refs.julia, refs.bryn])
+        .collect_subgraph::<T>();
+
+    assert_eq!(ids.vertex_count(), 2);
+    assert_eq!(ids.edge_count(), 0);
+    assert_eq!(subgraph.vertices(&VertexSearch::scan()).count(), 2);
+    let bryn = ids.vertex(refs.bryn).expect("bryn must be copied");
+    assert!(matches!(
+        subgraph.vertex(bryn).expect("copy must exist").weight(),
+        Vertex::Person { name, .. } if name == "Bryn"
+    ));
+    assert!(ids.vertex(refs.graph_api).is_none());
+    assert_eq!(
+        subgraph.edges(bryn, &EdgeSearch::scan().outgoing()).count(),
+        0
+    );
+}
+
+pub fn test_vertices_collect_induced_subgraph<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + Default,
+{
+    let refs = populate_graph(graph);
+    let (subgraph, ids) = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .collect_induced_subgraph::<T>();
+
+    assert_eq!(ids.vertex_count(), 2);
+    assert_eq!(ids.edge_count(), 2);
+    assert!(ids.edge(refs.bryn_created_graph_api).is_none());
+    let bryn = ids.vertex(refs.bryn).expect("bryn must be copied");
+    let julia = ids.vertex(refs.julia).expect("julia must be copied");
+    let knows = ids
+        .edge(refs.bryn_knows_julia)
+        .expect("bryn knows julia must be copied");
+    let edge = subgraph.edge(knows).expect("copy must exist");
+    assert_eq!(edge.tail(), bryn);
+    assert_eq!(edge.head(), julia);
+    assert!(matches!(edge.weight(), Edge::Knows { since: 1999 }));
+}
+
+pub fn test_edges_collect_subgraph<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + Default,
+{
+    let refs = populate_graph(graph);
+    let (subgraph, ids) = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .collect_subgraph::<T>();
+
+    assert_eq!(ids.edge_count(), 2);
+    assert_eq!(ids.vertex_count(), 3);
+    assert!(ids.edge(refs.julia_knows_bryn).is_none());
+    assert!(ids.vertex(refs.rust).is_none());
+    let bryn = ids.vertex(refs.bryn).expect("bryn must be copied");
+    let mut heads = subgraph
+        .edges(bryn, &EdgeSearch::scan().outgoing())
+        .map(|edge| edge.head())
+        .collect::<Vec<_>>();
+    let mut expected = vec![
+        ids.vertex(refs.julia).expect("julia must be copied"),
+        ids.vertex(refs.graph_api)
+            .expect("graph_api must be copied"),
+    ];
+    heads.sort_by_key(|id| format!("{:?}", id));
+    expected.sort_by_key(|id| format!("{:?}", id));
+    assert_eq!(heads, expected);
+    assert_eq!(
+        subgraph.edges(bryn, &EdgeSearch::scan().incoming()).count(),
+        0
+    );
+}
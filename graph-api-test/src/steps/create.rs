@@ -0,0 +1,88 @@
+use crate::{Edge, Project, Vertex, populate_graph};
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, VertexReference};
+
+pub fn test_add_edge_to<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    // Each person is linked to a different target
+    let created: Vec<_> = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .push_context(|vertex, _| {
+            if vertex.id() == refs.bryn {
+                refs.rust
+            } else {
+                refs.graph_api
+            }
+        })
+        .add_edge_to(|target| **target, |_| Edge::Created)
+        .collect();
+    assert_eq!(created.len(), 2);
+
+    {
+        let bryn_created = graph.edge(created[0]).expect("edge must exist");
+        assert_eq!(bryn_created.tail(), refs.bryn);
+        assert_eq!(bryn_created.head(), refs.rust);
+        let julia_created = graph.edge(created[1]).expect("edge must exist");
+        assert_eq!(julia_created.tail(), refs.julia);
+        assert_eq!(julia_created.head(), refs.graph_api);
+    }
+
+    // The traversal continues from the new edges
+    let heads: Vec<_> = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.julia])
+        .add_edge_to(|_| refs.bryn, |_| Edge::Knows { since: 2024 })
+        .head()
+        .collect();
+    assert_eq!(heads, vec![refs.bryn]);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices_by_id(vec![refs.julia])
+            .edges(EdgeSearch::scan().outgoing())
+            .count(),
+        3
+    );
+}
+
+pub fn test_add_vertex_linked<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    let created: Vec<_> = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .push_context(|vertex, _| vertex.id())
+        .add_vertex_linked(
+            |_| {
+                Vertex::Project(Project {
+                    name: "Linked".to_string(),
+                })
+            },
+            |_| Edge::Created,
+        )
+        .map(|vertex, ctx| (vertex.id(), *ctx))
+        .collect();
+    assert_eq!(created.len(), 2);
+
+    // Each new vertex carries the context of the vertex it was linked from
+    for (project, person) in created {
+        assert!(matches!(
+            graph.vertex(project).expect("vertex must exist").weight(),
+            Vertex::Project(Project { name }) if name == "Linked"
+        ));
+        let linked: Vec<_> = graph
+            .walk()
+            .vertices_by_id(vec![project])
+            .edges(EdgeSearch::scan().incoming())
+            .tail()
+            .collect();
+        assert_eq!(linked, vec![person]);
+    }
+}
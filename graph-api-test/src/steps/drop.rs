@@ -0,0 +1,89 @@
+#[cfg(feature = "element-removal")]
+use crate::{Edge, Vertex, populate_graph};
+#[cfg(feature = "element-removal")]
+use graph_api_lib::{Dropped, EdgeSearch, Graph, SupportsElementRemoval, VertexSearch};
+
+#[cfg(feature = "element-removal")]
+pub fn test_vertex_drop<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + SupportsElementRemoval,
+{
+    let refs = populate_graph(graph);
+
+    // Bryn is visited twice but only removed once
+    let dropped = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn, refs.bryn])
+        .drop();
+    assert_eq!(
+        dropped,
+        Dropped {
+            vertices: 1,
+            edges: 3
+        }
+    );
+
+    assert!(graph.vertex(refs.bryn).is_none());
+    assert!(graph.edge(refs.bryn_knows_julia).is_none());
+    assert!(graph.edge(refs.julia_knows_bryn).is_none());
+    assert!(graph.edge(refs.bryn_created_graph_api).is_none());
+    assert!(graph.edge(refs.graph_api_language_rust).is_some());
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 3);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices_by_id(vec![refs.julia])
+            .edges(EdgeSearch::scan())
+            .count(),
+        0
+    );
+}
+
+#[cfg(not(feature = "element-removal"))]
+pub fn test_vertex_drop<T>(_graph: &mut T)
+where
+    T: graph_api_lib::Graph<Vertex = crate::Vertex, Edge = crate::Edge>,
+{
+}
+
+#[cfg(feature = "element-removal")]
+pub fn test_edge_drop<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + SupportsElementRemoval,
+{
+    let refs = populate_graph(graph);
+
+    // The knows edges are reached from both ends
+    let dropped = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .edges(EdgeSearch::scan())
+        .drop();
+    assert_eq!(
+        dropped,
+        Dropped {
+            vertices: 0,
+            edges: 3
+        }
+    );
+
+    assert!(graph.vertex(refs.bryn).is_some());
+    assert!(graph.vertex(refs.julia).is_some());
+    assert!(graph.edge(refs.bryn_created_graph_api).is_none());
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 4);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices(VertexSearch::scan())
+            .edges(EdgeSearch::scan().outgoing())
+            .collect::<Vec<_>>(),
+        vec![refs.graph_api_language_rust]
+    );
+}
+
+#[cfg(not(feature = "element-removal"))]
+pub fn test_edge_drop<T>(_graph: &mut T)
+where
+    T: graph_api_lib::Graph<Vertex = crate::Vertex, Edge = crate::Edge>,
+{
+}
@@ -1,17 +1,25 @@
 pub mod boxed;
 pub mod collect;
+pub mod collect_subgraph;
 pub mod context;
 pub mod control_flow;
 pub mod count;
+pub mod create;
 pub mod detour;
+pub mod drop;
 pub mod edges;
 pub mod filter;
 pub mod filter_derive;
 pub mod first;
 pub mod fold;
 pub mod mutate_context;
+pub mod mutate_each;
 pub mod mutation;
+pub mod parallel;
 pub mod probe;
+pub mod profile;
+pub mod random_walk;
 pub mod reduce;
+pub mod sample;
 pub mod take;
 pub mod vertices;
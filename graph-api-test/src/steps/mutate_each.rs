@@ -0,0 +1,65 @@
+use crate::{Edge, EdgeExt, Vertex, populate_graph};
+use graph_api_lib::{EdgeReference, EdgeReferenceMut, EdgeSearch, Graph, VertexReference};
+
+pub fn test_vertex_mutate_each<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    // The traversal continues on the same vertices with the same contexts
+    let visited: Vec<_> = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.julia, refs.bryn])
+        .push_context(|vertex, _| vertex.id())
+        .mutate_each(|graph, vertex_id, _| {
+            graph.add_edge(vertex_id, refs.rust, Edge::Created);
+        })
+        .map(|vertex, ctx| (vertex.id(), *ctx))
+        .collect();
+    assert_eq!(
+        visited,
+        vec![(refs.julia, refs.julia), (refs.bryn, refs.bryn)]
+    );
+
+    // Later steps see the changes
+    let created = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.julia])
+        .mutate_each(|graph, vertex_id, _| {
+            graph.add_edge(vertex_id, refs.graph_api, Edge::Created);
+        })
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_created()
+        .head()
+        .collect::<Vec<_>>();
+    assert_eq!(created.len(), 2);
+    assert!(created.contains(&refs.rust));
+    assert!(created.contains(&refs.graph_api));
+}
+
+pub fn test_edge_mutate_each<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    let since: Vec<_> = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_knows()
+        .mutate_each(|graph, edge_id, _| {
+            if let Some(mut edge) = graph.edge_mut(edge_id)
+                && let Edge::Knows { since } = edge.weight_mut()
+            {
+                *since += 1;
+            }
+        })
+        .map(|edge, _| (edge.id(), edge.weight().clone()))
+        .collect();
+
+    assert_eq!(since.len(), 1);
+    assert_eq!(since[0].0, refs.bryn_knows_julia);
+    assert!(matches!(since[0].1, Edge::Knows { since: 2000 }));
+}
@@ -1,5 +1,6 @@
-use crate::{Edge, EdgeExt, Vertex, assert_elements_eq, populate_graph};
-use graph_api_lib::{EdgeReference, EdgeSearch, Graph};
+use crate::{Edge, EdgeExt, EdgeLabel, Project, Vertex, assert_elements_eq, populate_graph};
+use graph_api_lib::{EdgeReference, EdgeSearch, Graph, RandomWalk, VertexSearch};
+use std::ops::ControlFlow;
 
 pub fn test_mutation<T>(graph: &mut T)
 where
@@ -83,3 +84,162 @@ where
 
     assert_eq!(bryn_language_edges, 2, "Expected to find 2 language edges");
 }
+
+pub fn test_mutation_after_steps<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    // Every step can come before a mutation
+    let mutations = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .probe(|_, _| {})
+        .push_context(|_, _| 0)
+        .mutate_context(|_, ctx| **ctx += 1)
+        .detour(|waypoint| waypoint.edges(EdgeSearch::label(EdgeLabel::Knows).outgoing()))
+        .control_flow(|vertex, _| ControlFlow::Continue(Some(vertex)))
+        .boxed()
+        .sample(2, 0)
+        .mutate(|graph, vertex_id, ctx| {
+            assert_eq!(**ctx, 1);
+            graph.add_edge(vertex_id, refs.rust, Edge::Created);
+        });
+    assert_eq!(mutations, 2);
+
+    let mutations = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn])
+        .random_walk(
+            EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+            RandomWalk::new(2),
+        )
+        .reduce(|acc, _, _| acc)
+        .mutate(|graph, vertex_id, _| {
+            graph.add_edge(vertex_id, refs.graph_api, Edge::Created);
+        });
+    assert_eq!(mutations, 1);
+
+    let created = graph
+        .walk()
+        .vertices_by_id(vec![refs.julia])
+        .edges(EdgeSearch::scan().outgoing())
+        .filter_created()
+        .head()
+        .collect::<Vec<_>>();
+    assert!(created.contains(&refs.rust));
+    assert!(created.contains(&refs.graph_api));
+}
+
+pub fn test_edge_mutation_after_steps<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    let mutations = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .probe(|_, _| {})
+        .push_context(|_, _| 0)
+        .mutate_context(|_, ctx| **ctx += 1)
+        .control_flow(|edge, _| ControlFlow::Continue(Some(edge)))
+        .boxed()
+        .sample(2, 0)
+        .reduce(|acc, _, _| acc)
+        .mutate(|graph, edge_id, ctx| {
+            assert_eq!(**ctx, 1);
+            let head = graph.edge(edge_id).unwrap().head();
+            graph.add_edge(refs.bryn, head, Edge::Created);
+        });
+    assert_eq!(mutations, 1);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices_by_id(vec![refs.bryn])
+            .edges(EdgeSearch::scan().outgoing())
+            .count(),
+        3
+    );
+}
+
+pub fn test_batched_mutation<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+
+    // Vertices added by the callback are not visited, so the scan terminates
+    let mutations = graph
+        .walk_mut()
+        .vertices(VertexSearch::scan())
+        .mutate_batched(1, |graph, vertex_id, _| {
+            let project = graph.add_vertex(Vertex::Project(Project {
+                name: "Batched".to_string(),
+            }));
+            graph.add_edge(vertex_id, project, Edge::Created);
+        });
+    assert_eq!(mutations, 4);
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 8);
+
+    // Edges added to a vertex while its edges are being walked are not visited either
+    let mutations = graph
+        .walk_mut()
+        .vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan().outgoing())
+        .mutate_batched(2, |graph, edge_id, _| {
+            let head = graph.edge(edge_id).unwrap().head();
+            graph.add_edge(refs.bryn, head, Edge::Created);
+        });
+    assert_eq!(mutations, 3);
+    assert_eq!(
+        graph
+            .walk()
+            .vertices_by_id(vec![refs.bryn])
+            .edges(EdgeSearch::scan().outgoing())
+            .count(),
+        6
+    );
+}
+
+#[cfg(feature = "element-removal")]
+pub fn test_batched_mutation_removal<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsElementRemoval,
+{
+    let refs = populate_graph(graph);
+
+    // The first vertex removes the rest, which are then skipped
+    let mutations = graph
+        .walk_mut()
+        .vertices(VertexSearch::scan())
+        .mutate_batched(1, |graph, _, _| {
+            for vertex_id in [refs.bryn, refs.julia, refs.graph_api, refs.rust] {
+                graph.remove_vertex(vertex_id);
+            }
+        });
+    assert_eq!(mutations, 1);
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 0);
+
+    // Vertices removed part way through a batch are skipped by the rest of that batch
+    let refs = populate_graph(graph);
+    let mutations = graph
+        .walk_mut()
+        .vertices(VertexSearch::scan())
+        .mutate_batched(10, |graph, _, _| {
+            for vertex_id in [refs.bryn, refs.julia, refs.graph_api, refs.rust] {
+                graph.remove_vertex(vertex_id);
+            }
+        });
+    assert_eq!(mutations, 1);
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 0);
+}
+
+#[cfg(not(feature = "element-removal"))]
+pub fn test_batched_mutation_removal<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
@@ -0,0 +1,73 @@
+use crate::{Edge, Vertex, VertexLabel, populate_graph};
+use graph_api_lib::{Graph, SupportsParallelScan, VertexSearch};
+use std::collections::HashSet;
+
+pub fn test_vertex_partitions<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + SupportsParallelScan,
+    T::VertexId: Send,
+{
+    let refs = populate_graph(graph);
+    for partitions in [1, 3, 10] {
+        let ids = graph
+            .vertex_partitions(&VertexSearch::scan(), partitions)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(ids.len(), 4, "partitions: {}", partitions);
+        assert_eq!(
+            ids.into_iter().collect::<HashSet<_>>(),
+            HashSet::from([refs.bryn, refs.julia, refs.graph_api, refs.rust])
+        );
+    }
+
+    let people = graph
+        .vertex_partitions(
+            &VertexSearch::Label {
+                label: VertexLabel::Person,
+                limit: None,
+            },
+            2,
+        )
+        .into_iter()
+        .flatten()
+        .collect::<HashSet<_>>();
+    assert_eq!(people, HashSet::from([refs.bryn, refs.julia]));
+}
+
+#[cfg(feature = "parallel")]
+pub fn test_par_vertices<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + SupportsParallelScan,
+    T::VertexId: Send,
+{
+    use graph_api_lib::rayon::prelude::*;
+    use graph_api_lib::{EdgeSearch, VertexReference};
+
+    populate_graph(graph);
+    let people: usize = graph
+        .walk()
+        .par_vertices(VertexSearch::scan())
+        .partitions(3)
+        .map_partitions(|partition| {
+            partition
+                .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+                .count()
+        })
+        .sum();
+    assert_eq!(people, 2);
+
+    let edges: usize = graph
+        .walk()
+        .par_vertices(VertexSearch::scan())
+        .map_partitions(|partition| partition.edges(EdgeSearch::scan().outgoing()).count())
+        .sum();
+    assert_eq!(edges, 4);
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn test_par_vertices<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
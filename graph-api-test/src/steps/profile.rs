@@ -0,0 +1,168 @@
+use crate::{Edge, EdgeLabel, Vertex, populate_graph};
+use graph_api_lib::{Access, Direction, EdgeSearch, Graph, VertexReference, VertexSearch};
+
+pub fn test_explain<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let explain = graph
+        .walk()
+        .vertices(VertexSearch::scan().with_limit(2))
+        .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+        .edges(EdgeSearch::label(EdgeLabel::Knows).outgoing())
+        .head()
+        .explain();
+    let names = explain
+        .steps
+        .iter()
+        .map(|step| step.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["vertices", "filter", "edges", "head"]);
+    assert_eq!(explain.steps[0].access, Some(Access::VertexScan));
+    assert_eq!(explain.steps[0].limit, Some(2));
+    assert_eq!(explain.steps[1].access, None);
+    assert_eq!(
+        explain.steps[2].access,
+        Some(Access::Edges {
+            label: Some("Knows"),
+            adjacent_label: None,
+            direction: Direction::Outgoing,
+        })
+    );
+    assert_eq!(explain.steps[2].limit, None);
+
+    // Boxing is transparent and detours are a single step
+    let explain = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn])
+        .boxed()
+        .detour(|waypoint| waypoint.edges(EdgeSearch::scan()).take(1))
+        .take(1)
+        .explain();
+    let names = explain
+        .steps
+        .iter()
+        .map(|step| step.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["vertices_by_id", "detour", "take"]);
+    assert_eq!(explain.steps[0].access, Some(Access::VertexIds));
+}
+
+#[cfg(feature = "vertex-hash-index")]
+pub fn test_explain_index<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge> + graph_api_lib::SupportsVertexHashIndex,
+{
+    populate_graph(graph);
+    let explain = graph
+        .walk()
+        .vertices(Vertex::person_by_name("Bryn"))
+        .explain();
+    assert_eq!(
+        explain.steps[0].access,
+        Some(Access::VertexIndex {
+            index: "PersonName".to_string()
+        })
+    );
+}
+
+#[cfg(not(feature = "vertex-hash-index"))]
+pub fn test_explain_index<T>(_graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+}
+
+pub fn test_profile<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let profile = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+        .edges(EdgeSearch::scan().outgoing())
+        .head()
+        .profile();
+    let counts = profile
+        .steps
+        .iter()
+        .map(|step| (step.step.name, step.elements_in, step.elements_out))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        counts,
+        vec![
+            ("vertices", 0, 4),
+            ("filter", 4, 2),
+            ("edges", 2, 3),
+            ("head", 3, 3)
+        ]
+    );
+    assert_eq!(profile.steps[0].step.access, Some(Access::VertexScan));
+    assert_eq!(
+        profile.elapsed(),
+        profile
+            .steps
+            .iter()
+            .map(|step| step.elapsed)
+            .sum::<std::time::Duration>()
+    );
+
+    // Profiling does not leak into traversals that follow
+    assert_eq!(graph.walk().vertices(VertexSearch::scan()).count(), 4);
+}
+
+pub fn test_profile_detour<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let profile = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn, refs.julia])
+        .detour(|waypoint| waypoint.edges(EdgeSearch::scan().outgoing()))
+        .take(2)
+        .profile();
+    let counts = profile
+        .steps
+        .iter()
+        .map(|step| (step.step.name, step.elements_in, step.elements_out))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        counts,
+        vec![("vertices_by_id", 0, 1), ("detour", 1, 2), ("take", 2, 2)]
+    );
+}
+
+pub fn test_profile_boxed<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+
+    // The steps behind a boxed walker are counted as their own steps
+    let profile = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .filter(|vertex, _| matches!(vertex.weight(), Vertex::Person { .. }))
+        .boxed()
+        .edges(EdgeSearch::scan().outgoing())
+        .take(2)
+        .profile();
+    let counts = profile
+        .steps
+        .iter()
+        .map(|step| (step.step.name, step.elements_in, step.elements_out))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        counts,
+        vec![
+            ("vertices", 0, 1),
+            ("filter", 1, 1),
+            ("edges", 1, 2),
+            ("take", 2, 2)
+        ]
+    );
+}
@@ -0,0 +1,95 @@
+use crate::{Edge, EdgeLabel, Vertex, populate_graph};
+use graph_api_lib::{Direction, EdgeReference, EdgeSearch, Graph, RandomWalk};
+
+pub fn test_random_walk<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    // Bryn and Julia only know each other so the walk must alternate between them
+    let walk = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn])
+        .random_walk(
+            EdgeSearch::label(EdgeLabel::Knows).outgoing(),
+            RandomWalk::new(4).seed(7),
+        )
+        .collect::<Vec<_>>();
+    assert_eq!(walk, vec![refs.julia, refs.bryn, refs.julia, refs.bryn]);
+}
+
+pub fn test_random_walk_dead_end<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    // Rust has no outgoing edges so the walk stops there
+    let walk = graph
+        .walk()
+        .vertices_by_id(vec![refs.graph_api])
+        .random_walk(EdgeSearch::scan().outgoing(), RandomWalk::new(5))
+        .collect::<Vec<_>>();
+    assert_eq!(walk, vec![refs.rust]);
+}
+
+pub fn test_random_walk_seed<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let walk = |seed| {
+        graph
+            .walk()
+            .vertices_by_id(vec![refs.bryn, refs.julia])
+            .random_walk(
+                EdgeSearch::scan().direction(Direction::All),
+                RandomWalk::new(10).seed(seed),
+            )
+            .collect::<Vec<_>>()
+    };
+    let first = walk(3);
+    assert_eq!(first.len(), 20);
+    assert_eq!(first, walk(3));
+}
+
+pub fn test_weighted_random_walk<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    // Created edges have no weight so they are never followed
+    for seed in 0..10 {
+        let walk = graph
+            .walk()
+            .vertices_by_id(vec![refs.bryn])
+            .weighted_random_walk(
+                EdgeSearch::scan().outgoing(),
+                RandomWalk::new(1).seed(seed),
+                |edge| match edge.weight() {
+                    Edge::Created => 0.0,
+                    _ => 1.0,
+                },
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(walk, vec![refs.julia]);
+    }
+}
+
+pub fn test_node2vec_random_walk<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    // A huge return parameter means the walk never steps straight back to Julia
+    for seed in 0..10 {
+        let walk = graph
+            .walk()
+            .vertices_by_id(vec![refs.julia])
+            .random_walk(
+                EdgeSearch::scan().direction(Direction::All),
+                RandomWalk::new(2).seed(seed).return_parameter(1e9),
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(walk, vec![refs.bryn, refs.graph_api]);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Edge, Vertex, populate_graph};
+use graph_api_lib::{EdgeSearch, Graph, VertexReference, VertexSearch};
+use std::collections::HashSet;
+
+pub fn test_vertices_sample<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let sampled = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .sample(2, 42)
+        .collect::<Vec<_>>();
+    assert_eq!(sampled.len(), 2);
+    assert_ne!(sampled[0], sampled[1]);
+    assert_eq!(
+        sampled,
+        graph
+            .walk()
+            .vertices(VertexSearch::scan())
+            .sample(2, 42)
+            .collect::<Vec<_>>()
+    );
+
+    // Asking for more than there are returns everything
+    let all = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .sample(10, 42)
+        .collect::<HashSet<_>>();
+    assert_eq!(all.len(), 4);
+}
+
+pub fn test_vertices_sample_context<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    populate_graph(graph);
+    let matching = graph
+        .walk()
+        .vertices(VertexSearch::scan())
+        .push_context(|vertex, _ctx| vertex.id())
+        .sample(3, 1)
+        .map(|vertex, ctx| vertex.id() == *ctx)
+        .collect::<Vec<_>>();
+    assert_eq!(matching, vec![true, true, true]);
+}
+
+pub fn test_edges_sample<T>(graph: &mut T)
+where
+    T: Graph<Vertex = Vertex, Edge = Edge>,
+{
+    let refs = populate_graph(graph);
+    let sampled = graph
+        .walk()
+        .vertices_by_id(vec![refs.bryn])
+        .edges(EdgeSearch::scan())
+        .sample(2, 42)
+        .collect::<HashSet<_>>();
+    assert_eq!(sampled.len(), 2);
+    assert!(sampled.is_subset(&HashSet::from([
+        refs.bryn_knows_julia,
+        refs.julia_knows_bryn,
+        refs.bryn_created_graph_api,
+    ])));
+}